@@ -1,10 +1,17 @@
+use crate::dnsimple::account_client::AccountClient;
 use crate::dnsimple::accounts::Accounts;
+use crate::dnsimple::billing::Billing;
 use crate::dnsimple::certificates::Certificates;
+use crate::dnsimple::client_builder::{ClientBuilder, Environment};
 use crate::dnsimple::contacts::Contacts;
 use crate::dnsimple::domains::Domains;
 use crate::dnsimple::identity::Identity;
+use crate::dnsimple::ids::AccountId;
+use crate::dnsimple::middleware::{Middleware, Middlewares, RequestInfo};
 use crate::dnsimple::oauth::OAuth;
 use crate::dnsimple::registrar::Registrar;
+use crate::dnsimple::retry::RetryPolicy;
+use crate::dnsimple::secondary_dns::SecondaryDns;
 use crate::dnsimple::services::Services;
 use crate::dnsimple::templates::Templates;
 use crate::dnsimple::tlds::Tlds;
@@ -12,14 +19,28 @@ use crate::dnsimple::vanity_name_servers::VanityNameServers;
 use crate::dnsimple::webhooks::Webhooks;
 use crate::dnsimple::zones::Zones;
 use crate::errors::DNSimpleError;
+use futures_util::Stream;
+use futures_util::stream;
+use reqwest::Method;
+use reqwest::header::HeaderMap;
 use serde;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
+pub mod account_client;
 pub mod accounts;
+pub mod acme;
+pub mod billing;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod certificates;
+pub mod certificates_letsencrypt;
+pub mod certificates_renewal;
+pub mod client_builder;
 pub mod contacts;
 pub mod domains;
 pub mod domains_dnssec;
@@ -28,6 +49,11 @@ pub mod domains_push;
 pub mod domains_research;
 pub mod domains_signer_records;
 pub mod identity;
+pub mod ids;
+#[cfg(feature = "tracing")]
+mod instrumentation;
+pub mod list_options;
+pub mod middleware;
 pub mod oauth;
 pub mod registrar;
 pub mod registrar_auto_renewal;
@@ -35,12 +61,19 @@ pub mod registrar_name_servers;
 pub mod registrar_registrant_changes;
 pub mod registrar_transfer_lock;
 pub mod registrar_whois_privacy;
+pub mod retry;
+pub mod secondary_dns;
 pub mod services;
 pub mod templates;
 pub mod tlds;
 pub mod vanity_name_servers;
 pub mod webhooks;
+pub mod webhooks_events;
+pub mod zone_sync;
+pub mod zonefile;
 pub mod zones;
+pub mod zones_analytics;
+pub mod zones_distribution;
 pub mod zones_records;
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -50,12 +83,19 @@ const API_VERSION: &str = "v2";
 const DEFAULT_BASE_URL: &str = "https://api.dnsimple.com";
 const DEFAULT_SANDBOX_URL: &str = "https://api.sandbox.dnsimple.com";
 
+/// The page size used by the paginated streams when no `Paginate` option is given.
+const DEFAULT_STREAM_PER_PAGE: u32 = 100;
+
 /// Represents the Rust client for the DNSimple API V2
 ///
 /// The client is your entrypoint to the DNSimple API. Using it
 /// you will be able to call all the endpoints of the DNSimple API
 /// and their respective functions.
 ///
+/// The client is cheap to clone: the clones share the same configuration and
+/// connection pool. The client and its services (`client.zones()`, `client.domains()`, ...)
+/// are `Send + Sync + 'static`, so they can be moved into spawned tasks.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -70,13 +110,24 @@ const DEFAULT_SANDBOX_URL: &str = "https://api.sandbox.dnsimple.com";
 /// }
 /// ```
 ///
+#[derive(Clone)]
 pub struct Client {
+    inner: Arc<ClientInner>,
+}
+
+/// The configuration of a `Client`, shared between its clones.
+#[derive(Clone)]
+struct ClientInner {
     base_url: String,
     user_agent: String,
     auth_token: String,
     client: reqwest::Client,
+    default_headers: HeaderMap,
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    middlewares: Middlewares,
+    last_rate_limit: Arc<Mutex<Option<RateLimit>>>,
 }
-
 /// Defines the Endpoint trait for the different API endpoints
 pub trait Endpoint {
     type Output: DeserializeOwned;
@@ -85,12 +136,8 @@ pub trait Endpoint {
 /// Represents the response from an API call
 #[derive(Debug)]
 pub struct DNSimpleResponse<T> {
-    /// The maximum number of requests you can perform per hour.
-    pub rate_limit: String,
-    /// The number of requests remaining in the current rate limit window.
-    pub rate_limit_remaining: String,
-    /// The time at which the current rate limit window in [Unix time](https://en.wikipedia.org/wiki/Unix_time) format.
-    pub rate_limit_reset: String,
+    /// The rate limit information (`None` if the rate limit headers are missing or malformed).
+    pub rate_limit: Option<RateLimit>,
     /// The HTTP Status Code
     pub status: u16,
     /// The object or a `Vec<T>` of objects (the type `T` will depend on the endpoint).
@@ -101,6 +148,44 @@ pub struct DNSimpleResponse<T> {
     pub body: Option<Value>,
 }
 
+/// The rate limit information sent by the DNSimple API in the `X-RateLimit-*` headers
+///
+/// See [API Documentation: rate limiting](https://developer.dnsimple.com/v2/#rate-limiting)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    /// The maximum number of requests you can perform per hour.
+    pub limit: u64,
+    /// The number of requests remaining in the current rate limit window.
+    pub remaining: u64,
+    /// The time at which the current rate limit window resets in [Unix time](https://en.wikipedia.org/wiki/Unix_time) format.
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// Extracts the rate limit from the response headers, if they are all present and valid.
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Option<RateLimit> {
+        let header = |name: &str| headers.get(name)?.to_str().ok()?.trim().parse::<u64>().ok();
+
+        Some(RateLimit {
+            limit: header("X-RateLimit-Limit")?,
+            remaining: header("X-RateLimit-Remaining")?,
+            reset: header("X-RateLimit-Reset")?,
+        })
+    }
+
+    /// The time at which the current rate limit window resets.
+    pub fn reset_at(&self) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(self.reset)
+    }
+
+    /// The time remaining until the current rate limit window resets (zero if it already did).
+    pub fn reset_in(&self) -> Duration {
+        self.reset_at()
+            .duration_since(SystemTime::now())
+            .unwrap_or_default()
+    }
+}
+
 /// Any API endpoint that returns a list of items requires pagination.
 /// By default we will return 30 records from any listing endpoint. If an API endpoint returns
 /// a list of items, then it will include a pagination object that contains pagination
@@ -118,6 +203,7 @@ pub struct Pagination {
 }
 
 /// When you can send some options into the request (i.e. for pagination).
+#[derive(Clone, Debug)]
 pub struct RequestOptions {
     /// Filtering makes it possible to ask only for the exact subset of data that you you're looking for.
     pub filters: Option<Filters>,
@@ -130,12 +216,8 @@ pub struct RequestOptions {
 /// Represents an empty response from the DNSimple API
 /// (_these type of responses happen when issuing DELETE commands for example_)
 pub struct DNSimpleEmptyResponse {
-    /// The maximum number of requests you can perform per hour.
-    pub rate_limit: String,
-    /// The number of requests remaining in the current rate limit window.
-    pub rate_limit_remaining: String,
-    /// The time at which the current rate limit window in [Unix time](https://en.wikipedia.org/wiki/Unix_time) format.
-    pub rate_limit_reset: String,
+    /// The rate limit information (`None` if the rate limit headers are missing or malformed).
+    pub rate_limit: Option<RateLimit>,
     /// The HTTP Status Code
     pub status: u16,
 }
@@ -144,7 +226,7 @@ pub struct DNSimpleEmptyResponse {
 //
 // With potential hundreds of result entries, it's convenient to apply a filter and receive only the
 // interesting data.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Filters {
     pub filters: HashMap<String, String>,
 }
@@ -163,7 +245,7 @@ impl Filters {
 // order criteria (asc for ascending and desc for descending).
 //
 // The order of fields is relevant, as it will determine the priority of the sorting policies.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Sort {
     pub sort_by: String,
 }
@@ -175,6 +257,7 @@ impl Sort {
 }
 
 /// The pagination instructions for the request
+#[derive(Clone, Debug)]
 pub struct Paginate {
     /// The number of items you want
     pub per_page: u32,
@@ -199,87 +282,166 @@ pub struct Paginate {
 /// `sandbox`: `true` if you want to run in the sandbox environment, otherwise `false`
 /// `token`: the bearer authentication token
 pub fn new_client(sandbox: bool, token: String) -> Result<Client, DNSimpleError> {
-    let mut url = DEFAULT_BASE_URL;
-    if sandbox {
-        url = DEFAULT_SANDBOX_URL;
-    }
-
-    let client = reqwest::Client::builder()
-        .build()
-        .map_err(DNSimpleError::from_reqwest)?;
+    let environment = match sandbox {
+        true => Environment::Sandbox,
+        false => Environment::Production,
+    };
 
-    Ok(Client {
-        base_url: String::from(url),
-        user_agent: DEFAULT_USER_AGENT.to_owned() + VERSION,
-        auth_token: token,
-        client,
-    })
+    ClientBuilder::new(token).environment(environment).build()
 }
 
 impl Client {
+    /// Returns a `ClientBuilder` to create a client with a custom configuration
+    /// (environment, timeouts, proxy, root certificates, ...).
+    ///
+    /// # Arguments
+    ///
+    /// `token`: the bearer authentication token
+    pub fn builder(token: String) -> ClientBuilder {
+        ClientBuilder::new(token)
+    }
+
+    /// Returns a client with the given account bound to all its services
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    pub fn account(&self, account_id: impl Into<AccountId>) -> AccountClient {
+        AccountClient::new(self.clone(), account_id.into())
+    }
+
+    /// Returns a client with the account of the access token bound to all its services
+    ///
+    /// The account is resolved using `Identity::whoami`, so the token must be an account
+    /// token (a user token has access to several accounts, use `account` instead).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let account = client.auto_account().await.unwrap();
+    ///
+    ///     let zones = account.zones().list_zones(None).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    pub async fn auto_account(&self) -> Result<AccountClient, DNSimpleError> {
+        let whoami = self.identity().whoami().await?;
+
+        match whoami.data.and_then(|data| data.account) {
+            Some(account) => Ok(AccountClient::new(self.clone(), account.id)),
+            None => Err(DNSimpleError::Configuration(String::from(
+                "The access token is not an account token",
+            ))),
+        }
+    }
+
     ///Returns the `accounts` service attached to this client
-    pub fn accounts(&self) -> Accounts<'_> {
-        Accounts { client: self }
+    pub fn accounts(&self) -> Accounts {
+        Accounts {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `billing` service attached to this client
+    pub fn billing(&self) -> Billing {
+        Billing {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `contacts` service attached to this client
-    pub fn contacts(&self) -> Contacts<'_> {
-        Contacts { client: self }
+    pub fn contacts(&self) -> Contacts {
+        Contacts {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `certificates` service attached to this client
-    pub fn certificates(&self) -> Certificates<'_> {
-        Certificates { client: self }
+    pub fn certificates(&self) -> Certificates {
+        Certificates {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `domains` service attached to this client
-    pub fn domains(&self) -> Domains<'_> {
-        Domains { client: self }
+    pub fn domains(&self) -> Domains {
+        Domains {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `identity` service attached to this client
-    pub fn identity(&self) -> Identity<'_> {
-        Identity { client: self }
+    pub fn identity(&self) -> Identity {
+        Identity {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `oauth` service attached to this client
-    pub fn oauth(&self) -> OAuth<'_> {
-        OAuth { client: self }
+    pub fn oauth(&self) -> OAuth {
+        OAuth {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `registrar` service attached to this client
-    pub fn registrar(&self) -> Registrar<'_> {
-        Registrar { client: self }
+    pub fn registrar(&self) -> Registrar {
+        Registrar {
+            client: self.clone(),
+        }
+    }
+
+    /// Returns the `secondary_dns` service attached to this client
+    pub fn secondary_dns(&self) -> SecondaryDns {
+        SecondaryDns {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `services` service attached to this client
-    pub fn services(&self) -> Services<'_> {
-        Services { client: self }
+    pub fn services(&self) -> Services {
+        Services {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `templates` service attached to this client
-    pub fn templates(&self) -> Templates<'_> {
-        Templates { client: self }
+    pub fn templates(&self) -> Templates {
+        Templates {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `tlds` service attached to this endpoint
-    pub fn tlds(&self) -> Tlds<'_> {
-        Tlds { client: self }
+    pub fn tlds(&self) -> Tlds {
+        Tlds {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `vanity_name_servers` service attached to this endpoint
-    pub fn vanity_name_servers(&self) -> VanityNameServers<'_> {
-        VanityNameServers { client: self }
+    pub fn vanity_name_servers(&self) -> VanityNameServers {
+        VanityNameServers {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `webhooks` service attached to this endpoint
-    pub fn webhooks(&self) -> Webhooks<'_> {
-        Webhooks { client: self }
+    pub fn webhooks(&self) -> Webhooks {
+        Webhooks {
+            client: self.clone(),
+        }
     }
 
     /// Returns the `zones` service attached to this endpoint
-    pub fn zones(&self) -> Zones<'_> {
-        Zones { client: self }
+    pub fn zones(&self) -> Zones {
+        Zones {
+            client: self.clone(),
+        }
     }
 
     /// Convenience function to change the base url in runtime (used internally for
@@ -297,7 +459,7 @@ impl Client {
     ///
     /// `url`: The url we want to change the base url to.
     pub fn set_base_url(&mut self, url: &str) {
-        self.base_url = String::from(url);
+        Arc::make_mut(&mut self.inner).base_url = String::from(url);
     }
 
     /// Sets a custom token to be prepended to the default `User-Agent` header
@@ -323,21 +485,89 @@ impl Client {
         let custom_user_agent = custom_user_agent.trim();
         let default_user_agent = DEFAULT_USER_AGENT.to_owned() + VERSION;
 
-        self.user_agent = if custom_user_agent.is_empty() {
+        Arc::make_mut(&mut self.inner).user_agent = if custom_user_agent.is_empty() {
             default_user_agent
         } else {
             format!("{} {}", custom_user_agent, default_user_agent)
         };
     }
 
+    /// Sets the policy used to retry the requests failing with a transient error
+    /// (rate limited, bad gateway, service unavailable, gateway timeout or network errors).
+    ///
+    /// Retries are disabled by default.
+    ///
+    /// Note that if you want to do this you will have to declare your client mutable.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::{Client, new_client};
+    /// use dnsimple::dnsimple::retry::RetryPolicy;
+    /// let mut client = new_client(true, String::from("ACCESS_TOKEN")).unwrap();
+    /// client.set_retry_policy(RetryPolicy::default());
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `policy`: The `RetryPolicy` to use for every request.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        Arc::make_mut(&mut self.inner).retry_policy = Some(policy);
+    }
+
+    /// Registers a middleware called around every request sent by this client.
+    ///
+    /// The middlewares are called in the order they were registered. See `Middleware`.
+    ///
+    /// # Arguments
+    ///
+    /// `middleware`: The `Middleware` to register.
+    pub fn add_middleware(&mut self, middleware: impl Middleware + 'static) {
+        Arc::make_mut(&mut self.inner)
+            .middlewares
+            .push(Arc::new(middleware));
+    }
+
     /// Returns the current url (including the `API_VERSION` as part of the path).
     pub fn versioned_url(&self) -> String {
-        let mut url = String::from(&self.base_url);
+        let mut url = String::from(&self.inner.base_url);
         url.push('/');
         url.push_str(API_VERSION);
         url
     }
 
+    /// Returns the rate limit of the last response received from the DNSimple API
+    /// (`None` if no request was performed yet, or if the rate limit headers were missing).
+    ///
+    /// It can be used to throttle the requests before the rate limit is exceeded.
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     client.identity().whoami().await.unwrap();
+    ///
+    ///     if let Some(rate_limit) = client.last_rate_limit() {
+    ///         println!("{} requests remaining", rate_limit.remaining);
+    ///     }
+    /// }
+    /// ```
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        match self.inner.last_rate_limit.lock() {
+            Ok(rate_limit) => *rate_limit,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
+    }
+
+    fn record_rate_limit(&self, response: &reqwest::Response) {
+        if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+            match self.inner.last_rate_limit.lock() {
+                Ok(mut last) => *last = Some(rate_limit),
+                Err(poisoned) => *poisoned.into_inner() = Some(rate_limit),
+            }
+        }
+    }
+
     /// Sends a GET request to the DNSimple API
     ///
     /// # Arguments
@@ -353,6 +583,75 @@ impl Client {
         self.call::<E>(request).await
     }
 
+    /// Returns a stream of every item of a paginated list endpoint
+    ///
+    /// The pages are requested lazily using `get`: the next page is only fetched
+    /// once all the items of the current one have been consumed, and the stream
+    /// ends after the last page. If a request fails, the error is yielded and the
+    /// stream ends.
+    ///
+    /// The stream starts at the page given in `options.paginate` (using its
+    /// `per_page`), or at the first page with 100 items per page otherwise.
+    ///
+    /// The stream holds a clone of the client, so it doesn't borrow it.
+    ///
+    /// # Arguments
+    ///
+    /// `path`: the path to the endpoint
+    /// `options`: optionally a `RequestOptions` with things like pagination, filtering and sorting
+    pub fn paginate<E, T>(
+        &self,
+        path: String,
+        options: Option<RequestOptions>,
+    ) -> impl Stream<Item = Result<T, DNSimpleError>> + use<E, T>
+    where
+        E: Endpoint<Output = Vec<T>>,
+    {
+        let options = options.unwrap_or(RequestOptions {
+            filters: None,
+            sort: None,
+            paginate: None,
+        });
+        let paginate = options.paginate.clone().unwrap_or(Paginate {
+            per_page: DEFAULT_STREAM_PER_PAGE,
+            page: 1,
+        });
+        let state = PaginationState {
+            client: self.clone(),
+            path,
+            options,
+            per_page: paginate.per_page,
+            next_page: Some(paginate.page),
+            items: VecDeque::new(),
+        };
+
+        stream::try_unfold(state, move |mut state| async move {
+            loop {
+                if let Some(item) = state.items.pop_front() {
+                    return Ok(Some((item, state)));
+                }
+                let Some(page) = state.next_page else {
+                    return Ok(None);
+                };
+
+                let mut options = state.options.clone();
+                options.paginate = Some(Paginate {
+                    per_page: state.per_page,
+                    page,
+                });
+                let response = state.client.get::<E>(&state.path, Some(options)).await?;
+
+                state.next_page = match response.pagination {
+                    Some(pagination) if pagination.current_page < pagination.total_pages => {
+                        Some(page + 1)
+                    }
+                    _ => None,
+                };
+                state.items.extend(response.data.unwrap_or_default());
+            }
+        })
+    }
+
     /// Sends a POST request to the DNSimple API
     ///
     /// # Arguments
@@ -446,7 +745,7 @@ impl Client {
         request: reqwest::RequestBuilder,
         data: Value,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        self.process_response::<E>(request.json(&data).send().await)
+        self.process_response::<E>(self.send(request.json(&data)).await)
             .await
     }
 
@@ -454,7 +753,75 @@ impl Client {
         &self,
         request: reqwest::RequestBuilder,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        self.process_response::<E>(request.send().await).await
+        self.process_response::<E>(self.send(request).await).await
+    }
+
+    /// Sends the request, retrying it according to the retry policy of the client.
+    pub(crate) async fn send(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let request = request.build()?;
+        #[cfg(feature = "tracing")]
+        let span = instrumentation::request_span(&request);
+
+        let result = self.send_with_retries(request);
+        #[cfg(feature = "tracing")]
+        let result = instrumentation::in_span(span, result);
+        result.await
+    }
+
+    async fn send_with_retries(
+        &self,
+        request: reqwest::Request,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let policy = match &self.inner.retry_policy {
+            Some(policy) if policy.can_retry(request.method()) => policy,
+            _ => return self.execute(request, 1).await,
+        };
+
+        let mut attempt = 1;
+        loop {
+            let Some(attempt_request) = request.try_clone() else {
+                return self.execute(request, attempt).await;
+            };
+            let result = self.execute(attempt_request, attempt).await;
+
+            match policy.delay_for(attempt, &result) {
+                Some(delay) => {
+                    drop(result);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                None => return result,
+            }
+        }
+    }
+
+    async fn execute(
+        &self,
+        mut request: reqwest::Request,
+        attempt: u32,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let middlewares = &self.inner.middlewares;
+        middlewares.on_request(&mut request);
+        let info = RequestInfo {
+            method: request.method().clone(),
+            url: request.url().clone(),
+            attempt,
+        };
+
+        let start = Instant::now();
+        let result = self.inner.client.execute(request).await;
+        let elapsed = start.elapsed();
+
+        if let Ok(response) = &result {
+            self.record_rate_limit(response);
+        }
+        #[cfg(feature = "tracing")]
+        instrumentation::record_attempt(attempt, &result);
+        middlewares.on_response(&info, result.as_ref(), elapsed);
+        result
     }
 
     async fn process_response<E: Endpoint>(
@@ -504,7 +871,7 @@ impl Client {
         &self,
         request: reqwest::RequestBuilder,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        match request.send().await {
+        match self.send(request).await {
             Ok(response) => {
                 let status = response.status().as_u16();
 
@@ -523,9 +890,7 @@ impl Client {
     async fn build_dnsimple_response<E: Endpoint>(
         resp: reqwest::Response,
     ) -> Result<DNSimpleResponse<E::Output>, DNSimpleError> {
-        let rate_limit = Self::extract_rate_limit_limit_header(&resp)?;
-        let rate_limit_remaining = Self::extract_rate_limit_remaining_header(&resp)?;
-        let rate_limit_reset = Self::extract_rate_limit_reset_header(&resp)?;
+        let rate_limit = RateLimit::from_headers(resp.headers());
 
         let status = resp.status().as_u16();
 
@@ -533,8 +898,6 @@ impl Client {
         if status == 204 {
             return Ok(DNSimpleResponse {
                 rate_limit,
-                rate_limit_remaining,
-                rate_limit_reset,
                 status,
                 data: None,
                 pagination: None,
@@ -545,7 +908,7 @@ impl Client {
         let json = resp
             .json::<Value>()
             .await
-            .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+            .map_err(DNSimpleError::from_reqwest)?;
         let data = json
             .get("data")
             .cloned()
@@ -562,8 +925,6 @@ impl Client {
 
         Ok(DNSimpleResponse {
             rate_limit,
-            rate_limit_remaining,
-            rate_limit_reset,
             status,
             data,
             pagination,
@@ -571,54 +932,11 @@ impl Client {
         })
     }
 
-    fn extract_rate_limit_reset_header(resp: &reqwest::Response) -> Result<String, DNSimpleError> {
-        match resp.headers().get("X-RateLimit-Reset") {
-            Some(header) => header.to_str().map(|s| s.to_string()).map_err(|_| {
-                DNSimpleError::Deserialization(String::from(
-                    "Cannot parse the X-RateLimit-Reset header",
-                ))
-            }),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Reset header",
-            ))),
-        }
-    }
-
-    fn extract_rate_limit_remaining_header(
-        resp: &reqwest::Response,
-    ) -> Result<String, DNSimpleError> {
-        match resp.headers().get("X-RateLimit-Remaining") {
-            Some(header) => header.to_str().map(|s| s.to_string()).map_err(|_| {
-                DNSimpleError::Deserialization(String::from(
-                    "Cannot parse the X-RateLimit-Remaining header",
-                ))
-            }),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Remaining header",
-            ))),
-        }
-    }
-
-    fn extract_rate_limit_limit_header(resp: &reqwest::Response) -> Result<String, DNSimpleError> {
-        match resp.headers().get("X-RateLimit-Limit") {
-            Some(header) => header.to_str().map(|s| s.to_string()).map_err(|_| {
-                DNSimpleError::Deserialization(String::from(
-                    "Cannot parse the X-RateLimit-Limit header",
-                ))
-            }),
-            None => Err(DNSimpleError::Deserialization(String::from(
-                "Cannot parse the X-RateLimit-Limit header",
-            ))),
-        }
-    }
-
     async fn build_empty_dnsimple_response(
         response: reqwest::Response,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
         Ok(DNSimpleEmptyResponse {
-            rate_limit: Self::extract_rate_limit_limit_header(&response)?,
-            rate_limit_remaining: Self::extract_rate_limit_remaining_header(&response)?,
-            rate_limit_reset: Self::extract_rate_limit_reset_header(&response)?,
+            rate_limit: RateLimit::from_headers(response.headers()),
             status: response.status().as_u16(),
         })
     }
@@ -647,54 +965,41 @@ impl Client {
             }
         }
 
-        let request = self
-            .client
-            .get(self.url(path))
-            .header("User-Agent", &self.user_agent)
-            .header("Accept", "application/json")
-            .query(&query_params);
-
-        self.add_headers_to_request(request)
+        self.build_request(Method::GET, path).query(&query_params)
     }
 
     pub fn build_post_request(&self, path: &str) -> reqwest::RequestBuilder {
-        let request = self
-            .client
-            .post(self.url(path))
-            .header("User-Agent", &self.user_agent)
-            .header("Accept", "application/json");
-        self.add_headers_to_request(request)
+        self.build_request(Method::POST, path)
     }
 
     pub fn build_put_request(&self, path: &str) -> reqwest::RequestBuilder {
-        let request = self
-            .client
-            .put(self.url(path))
-            .header("User-Agent", &self.user_agent)
-            .header("Accept", "application/json");
-        self.add_headers_to_request(request)
+        self.build_request(Method::PUT, path)
     }
 
     pub fn build_patch_request(&self, path: &str) -> reqwest::RequestBuilder {
-        let request = self
-            .client
-            .patch(self.url(path))
-            .header("User-Agent", &self.user_agent)
-            .header("Accept", "application/json");
-        self.add_headers_to_request(request)
+        self.build_request(Method::PATCH, path)
     }
 
     fn build_delete_request(&self, path: &str) -> reqwest::RequestBuilder {
-        let request = self
+        self.build_request(Method::DELETE, path)
+    }
+
+    fn build_request(&self, method: Method, path: &str) -> reqwest::RequestBuilder {
+        let mut request = self
+            .inner
             .client
-            .delete(self.url(path))
-            .header("User-Agent", &self.user_agent)
+            .request(method, self.url(path))
+            .headers(self.inner.default_headers.clone())
+            .header("User-Agent", &self.inner.user_agent)
             .header("Accept", "application/json");
+        if let Some(timeout) = self.inner.timeout {
+            request = request.timeout(timeout);
+        }
         self.add_headers_to_request(request)
     }
 
     fn add_headers_to_request(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
-        let auth_token = format!("Bearer {}", self.auth_token);
+        let auth_token = format!("Bearer {}", self.inner.auth_token);
         request.header("Authorization", auth_token.as_str())
     }
 
@@ -705,6 +1010,15 @@ impl Client {
     }
 }
 
+struct PaginationState<T> {
+    client: Client,
+    path: String,
+    options: RequestOptions,
+    per_page: u32,
+    next_page: Option<u32>,
+    items: VecDeque<T>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::dnsimple::{DEFAULT_SANDBOX_URL, DEFAULT_USER_AGENT, VERSION, new_client};
@@ -715,9 +1029,12 @@ mod tests {
         let token = "some-auth-token";
         let client = new_client(true, String::from(token))?;
 
-        assert_eq!(client.base_url, DEFAULT_SANDBOX_URL);
-        assert_eq!(client.user_agent, DEFAULT_USER_AGENT.to_owned() + VERSION);
-        assert_eq!(client.auth_token, token);
+        assert_eq!(client.inner.base_url, DEFAULT_SANDBOX_URL);
+        assert_eq!(
+            client.inner.user_agent,
+            DEFAULT_USER_AGENT.to_owned() + VERSION
+        );
+        assert_eq!(client.inner.auth_token, token);
         Ok(())
     }
 
@@ -736,7 +1053,7 @@ mod tests {
         client.set_user_agent("my-app/1.0");
 
         assert_eq!(
-            client.user_agent,
+            client.inner.user_agent,
             format!("my-app/1.0 {}{}", DEFAULT_USER_AGENT, VERSION)
         );
         Ok(())
@@ -746,7 +1063,10 @@ mod tests {
     fn default_user_agent_is_unchanged_when_not_customized() -> Result<(), DNSimpleError> {
         let client = new_client(true, String::from("token"))?;
 
-        assert_eq!(client.user_agent, DEFAULT_USER_AGENT.to_owned() + VERSION);
+        assert_eq!(
+            client.inner.user_agent,
+            DEFAULT_USER_AGENT.to_owned() + VERSION
+        );
         Ok(())
     }
 
@@ -755,7 +1075,10 @@ mod tests {
         let mut client = new_client(true, String::from("token"))?;
         client.set_user_agent("  ");
 
-        assert_eq!(client.user_agent, DEFAULT_USER_AGENT.to_owned() + VERSION);
+        assert_eq!(
+            client.inner.user_agent,
+            DEFAULT_USER_AGENT.to_owned() + VERSION
+        );
         Ok(())
     }
 }
@@ -0,0 +1,676 @@
+use crate::dnsimple::Client;
+use crate::dnsimple::billing::{Billing, Charge};
+use crate::dnsimple::certificates::{
+    Certificate, CertificateBundle, CertificatePrivateKey, Certificates, LetsEncryptPurchase,
+    LetsEncryptPurchasePayload, LetsEncryptPurchaseRenewal, LetsEncryptPurchaseRenewalPayload,
+};
+use crate::dnsimple::certificates_letsencrypt::{CertificateMaterial, IssuancePolling};
+use crate::dnsimple::certificates_renewal::RenewalManager;
+use crate::dnsimple::contacts::{Contact, ContactPayload, Contacts};
+use crate::dnsimple::domains::{Domain, Domains};
+use crate::dnsimple::domains_dnssec::Dnssec;
+use crate::dnsimple::domains_email_forwards::{
+    EmailForward, EmailForwardPayload, EmailForwardsInList,
+};
+use crate::dnsimple::domains_push::{DomainPush, InitiatePushPayload};
+use crate::dnsimple::domains_research::DomainResearchStatus;
+use crate::dnsimple::domains_signer_records::{
+    DelegationSignerRecord, DelegationSignerRecordPayload,
+};
+use crate::dnsimple::ids::{
+    AccountId, CertificateId, CertificateRenewalId, ContactId, DelegationSignerRecordId,
+    DomainPushId, DomainRef, DomainRegistrationId, DomainRenewalId, DomainRestoreId,
+    DomainTransferId, EmailForwardId, PrimaryServerId, RegistrantChangeId, TemplateRecordId,
+    WebhookId, ZoneRecordId,
+};
+use crate::dnsimple::registrar::{
+    DomainCheck, DomainPrice, DomainRegistration, DomainRegistrationPayload, DomainRenewal,
+    DomainRenewalPayload, DomainRestore, DomainRestorePayload, DomainTransfer,
+    DomainTransferPayload, Registrar,
+};
+use crate::dnsimple::registrar_name_servers::VanityNameServer;
+use crate::dnsimple::registrar_registrant_changes::{
+    RegistrantChange, RegistrantChangeCheck, RegistrantChangeCheckPayload, RegistrantChangePayload,
+};
+use crate::dnsimple::registrar_transfer_lock::TransferLock;
+use crate::dnsimple::registrar_whois_privacy::WhoisPrivacy;
+use crate::dnsimple::secondary_dns::{PrimaryServer, PrimaryServerPayload, SecondaryDns};
+use crate::dnsimple::services::{Service, Services};
+use crate::dnsimple::templates::{
+    Template, TemplatePayload, TemplateRecord, TemplateRecordPayload, Templates,
+};
+use crate::dnsimple::vanity_name_servers::VanityNameServers;
+use crate::dnsimple::webhooks::{Webhook, Webhooks};
+use crate::dnsimple::zone_sync::ZoneSync;
+use crate::dnsimple::zones::{Zone, ZoneDistribution, ZoneFile, ZoneNsRecordsPayload, Zones};
+use crate::dnsimple::zones_analytics::{DnsAnalyticsData, DnsAnalyticsOptions};
+use crate::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+use crate::dnsimple::zones_records::{
+    ZoneRecord, ZoneRecordBatchChange, ZoneRecordBatchPayload, ZoneRecordPayload,
+    ZoneRecordUpdatePayload,
+};
+use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, RequestOptions};
+use crate::errors::DNSimpleError;
+use futures_util::stream::BoxStream;
+
+/// Defines a service with the account bound: a wrapper around the service delegating
+/// to its methods, passing the bound account ID as their first argument.
+macro_rules! account_service {
+    ($(#[$meta:meta])* $bound:ident => $service:ident { $($methods:tt)* }) => {
+        $(#[$meta])*
+        pub struct $bound {
+            service: $service,
+            account_id: AccountId,
+        }
+
+        impl $bound {
+            /// Returns the account ID bound to this service.
+            pub fn account_id(&self) -> AccountId {
+                self.account_id
+            }
+
+            account_service!(@methods $service; $($methods)*);
+        }
+    };
+    (@methods $service:ident;) => {};
+    (
+        @methods $service:ident;
+        async fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("See `", stringify!($service), "::", stringify!($method), "`.")]
+        pub async fn $method(&self, $($arg: $ty),*) -> $ret {
+            self.service.$method(self.account_id, $($arg),*).await
+        }
+
+        account_service!(@methods $service; $($rest)*);
+    };
+    (
+        @methods $service:ident;
+        fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> Stream<$item:ty>;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("See `", stringify!($service), "::", stringify!($method), "`.")]
+        pub fn $method(
+            &self,
+            $($arg: $ty),*
+        ) -> BoxStream<'static, Result<$item, DNSimpleError>> {
+            self.service.$method(self.account_id, $($arg),*)
+        }
+
+        account_service!(@methods $service; $($rest)*);
+    };
+    (
+        @methods $service:ident;
+        fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("See `", stringify!($service), "::", stringify!($method), "`.")]
+        pub fn $method(&self, $($arg: $ty),*) -> $ret {
+            self.service.$method(self.account_id, $($arg),*)
+        }
+
+        account_service!(@methods $service; $($rest)*);
+    };
+}
+
+/// A client with an account bound
+///
+/// The services returned by this client are the same as the ones returned by `Client`,
+/// without the `account_id` argument.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+///     let account = client.account(1234);
+///
+///     let domains = account.domains().list_domains(None).await.unwrap().data.unwrap();
+///     let zone = account.zones().get_zone("example.com").await.unwrap().data.unwrap();
+/// }
+/// ```
+#[derive(Clone)]
+pub struct AccountClient {
+    client: Client,
+    account_id: AccountId,
+}
+
+impl AccountClient {
+    pub(crate) fn new(client: Client, account_id: AccountId) -> AccountClient {
+        AccountClient { client, account_id }
+    }
+
+    /// Returns the account ID bound to this client.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the underlying `Client`.
+    pub fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Returns the `billing` service with the account bound
+    pub fn billing(&self) -> AccountBilling {
+        AccountBilling {
+            service: self.client.billing(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `certificates` service with the account bound
+    pub fn certificates(&self) -> AccountCertificates {
+        AccountCertificates {
+            service: self.client.certificates(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `contacts` service with the account bound
+    pub fn contacts(&self) -> AccountContacts {
+        AccountContacts {
+            service: self.client.contacts(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `domains` service with the account bound
+    pub fn domains(&self) -> AccountDomains {
+        AccountDomains {
+            service: self.client.domains(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `registrar` service with the account bound
+    pub fn registrar(&self) -> AccountRegistrar {
+        AccountRegistrar {
+            service: self.client.registrar(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `secondary_dns` service with the account bound
+    pub fn secondary_dns(&self) -> AccountSecondaryDns {
+        AccountSecondaryDns {
+            service: self.client.secondary_dns(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `services` service with the account bound
+    pub fn services(&self) -> AccountServices {
+        AccountServices {
+            service: self.client.services(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `templates` service with the account bound
+    pub fn templates(&self) -> AccountTemplates {
+        AccountTemplates {
+            service: self.client.templates(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `vanity_name_servers` service with the account bound
+    pub fn vanity_name_servers(&self) -> AccountVanityNameServers {
+        AccountVanityNameServers {
+            service: self.client.vanity_name_servers(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `webhooks` service with the account bound
+    pub fn webhooks(&self) -> AccountWebhooks {
+        AccountWebhooks {
+            service: self.client.webhooks(),
+            account_id: self.account_id,
+        }
+    }
+
+    /// Returns the `zones` service with the account bound
+    pub fn zones(&self) -> AccountZones {
+        AccountZones {
+            service: self.client.zones(),
+            account_id: self.account_id,
+        }
+    }
+}
+
+account_service! {
+    /// The `Billing` service with the account bound.
+    AccountBilling => Billing {
+        async fn list_charges(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Charge>>, DNSimpleError>;
+        fn list_charges_stream(options: Option<RequestOptions>) -> Stream<Charge>;
+    }
+}
+
+account_service! {
+    /// The `Certificates` service with the account bound.
+    AccountCertificates => Certificates {
+        async fn list_certificates(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Certificate>>, DNSimpleError>;
+        fn list_certificates_stream(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Stream<Certificate>;
+        async fn get_certificate(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        async fn download_certificate(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<CertificateBundle>, DNSimpleError>;
+        async fn get_certificate_private_key(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<CertificatePrivateKey>, DNSimpleError>;
+        async fn purchase_letsencrypt_certificate(
+            domain: impl Into<DomainRef>,
+            payload: LetsEncryptPurchasePayload,
+        ) -> Result<DNSimpleResponse<LetsEncryptPurchase>, DNSimpleError>;
+        async fn issue_letsencrypt_certificate(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        async fn purchase_letsencrypt_certificate_renewal(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+            payload: LetsEncryptPurchaseRenewalPayload,
+        ) -> Result<DNSimpleResponse<LetsEncryptPurchaseRenewal>, DNSimpleError>;
+        async fn issue_letsencrypt_certificate_renewal(
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+            certificate_renewal_id: impl Into<CertificateRenewalId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        async fn obtain_letsencrypt_certificate(
+            domain: impl Into<DomainRef>,
+            payload: LetsEncryptPurchasePayload,
+            polling: &IssuancePolling,
+        ) -> Result<CertificateMaterial, DNSimpleError>;
+        fn renewal_manager() -> RenewalManager;
+    }
+}
+
+account_service! {
+    /// The `Contacts` service with the account bound.
+    AccountContacts => Contacts {
+        async fn list_contacts(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Contact>>, DNSimpleError>;
+        fn list_contacts_stream(options: Option<RequestOptions>) -> Stream<Contact>;
+        async fn create_contact(
+            payload: ContactPayload,
+        ) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        async fn get_contact(contact: impl Into<ContactId>) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        async fn update_contact(
+            contact: impl Into<ContactId>,
+            payload: ContactPayload,
+        ) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        async fn delete_contact(contact: impl Into<ContactId>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Domains` service with the account bound.
+    AccountDomains => Domains {
+        async fn list_domains(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Domain>>, DNSimpleError>;
+        fn list_domains_stream(options: Option<RequestOptions>) -> Stream<Domain>;
+        async fn create_domain(name: String) -> Result<DNSimpleResponse<Domain>, DNSimpleError>;
+        async fn get_domain(domain: impl Into<DomainRef>) -> Result<DNSimpleResponse<Domain>, DNSimpleError>;
+        async fn delete_domain(domain: impl Into<DomainRef>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn enable_dnssec(domain: impl Into<DomainRef>) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError>;
+        async fn disable_dnssec(domain: impl Into<DomainRef>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn get_dnssec(domain: impl Into<DomainRef>) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError>;
+        async fn list_email_forwards(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<EmailForwardsInList>>, DNSimpleError>;
+        fn list_email_forwards_stream(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Stream<EmailForwardsInList>;
+        async fn create_email_forward(
+            domain: impl Into<DomainRef>,
+            payload: EmailForwardPayload,
+        ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError>;
+        async fn get_email_forward(
+            domain: impl Into<DomainRef>,
+            email_forward: impl Into<EmailForwardId>,
+        ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError>;
+        async fn delete_email_forward(
+            domain: impl Into<DomainRef>,
+            email_forward: impl Into<EmailForwardId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn initiate_push(
+            domain: impl Into<DomainRef>,
+            payload: InitiatePushPayload,
+        ) -> Result<DNSimpleResponse<DomainPush>, DNSimpleError>;
+        async fn list_pushes(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<DomainPush>>, DNSimpleError>;
+        fn list_pushes_stream(options: Option<RequestOptions>) -> Stream<DomainPush>;
+        async fn accept_push(push_id: impl Into<DomainPushId>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn reject_push(push_id: impl Into<DomainPushId>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn get_domain_research_status(
+            domain: String,
+        ) -> Result<DNSimpleResponse<DomainResearchStatus>, DNSimpleError>;
+        async fn list_delegation_signer_records(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<DelegationSignerRecord>>, DNSimpleError>;
+        fn list_delegation_signer_records_stream(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Stream<DelegationSignerRecord>;
+        async fn create_delegation_signer_record(
+            domain: impl Into<DomainRef>,
+            payload: DelegationSignerRecordPayload,
+        ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError>;
+        async fn get_delegation_signer_record(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError>;
+        async fn delete_delegation_signer_record(
+            domain: impl Into<DomainRef>,
+            delegation_signer_record_id: impl Into<DelegationSignerRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Registrar` service with the account bound.
+    AccountRegistrar => Registrar {
+        async fn check_domain(domain: &str) -> Result<DNSimpleResponse<DomainCheck>, DNSimpleError>;
+        async fn get_domain_prices(
+            domain: &str,
+        ) -> Result<DNSimpleResponse<DomainPrice>, DNSimpleError>;
+        async fn get_domain_registration(
+            domain: impl Into<DomainRef>,
+            domain_registration_id: impl Into<DomainRegistrationId>,
+        ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError>;
+        async fn get_domain_renewal(
+            domain: impl Into<DomainRef>,
+            domain_renewal_id: impl Into<DomainRenewalId>,
+        ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError>;
+        async fn register_domain(
+            domain: &str,
+            payload: DomainRegistrationPayload,
+        ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError>;
+        async fn transfer_domain(
+            domain: &str,
+            payload: DomainTransferPayload,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        async fn get_domain_transfer(
+            domain: impl Into<DomainRef>,
+            domain_transfer: impl Into<DomainTransferId>,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        async fn cancel_domain_transfer(
+            domain: impl Into<DomainRef>,
+            domain_transfer: impl Into<DomainTransferId>,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        async fn renew_domain(
+            domain: impl Into<DomainRef>,
+            payload: DomainRenewalPayload,
+        ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError>;
+        async fn restore_domain(
+            domain: impl Into<DomainRef>,
+            payload: DomainRestorePayload,
+        ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError>;
+        async fn get_domain_restore(
+            domain: impl Into<DomainRef>,
+            domain_restore_id: impl Into<DomainRestoreId>,
+        ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError>;
+        async fn transfer_domain_out(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn enable_domain_auto_renewal(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn disable_domain_auto_renewal(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn get_domain_delegation(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError>;
+        async fn change_domain_delegation(
+            domain: impl Into<DomainRef>,
+            server_names: Vec<&str>,
+        ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError>;
+        async fn change_domain_delegation_to_vanity(
+            domain: impl Into<DomainRef>,
+            server_names: Vec<&str>,
+        ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError>;
+        async fn change_domain_delegation_from_vanity(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn get_registrant_change(
+            registrant_change_id: impl Into<RegistrantChangeId>,
+        ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError>;
+        async fn check_registrant_change(
+            payload: RegistrantChangeCheckPayload,
+        ) -> Result<DNSimpleResponse<RegistrantChangeCheck>, DNSimpleError>;
+        async fn create_registrant_change(
+            payload: RegistrantChangePayload,
+        ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError>;
+        async fn list_registrant_changes(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<RegistrantChange>>, DNSimpleError>;
+        fn list_registrant_changes_stream(
+            options: Option<RequestOptions>,
+        ) -> Stream<RegistrantChange>;
+        async fn delete_registrant_change(
+            registrant_change_id: impl Into<RegistrantChangeId>,
+        ) -> Result<DNSimpleResponse<Option<RegistrantChange>>, DNSimpleError>;
+        async fn enable_domain_transfer_lock(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        async fn disable_domain_transfer_lock(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        async fn get_domain_transfer_lock(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        async fn enable_whois_privacy(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError>;
+        async fn disable_whois_privacy(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `SecondaryDns` service with the account bound.
+    AccountSecondaryDns => SecondaryDns {
+        async fn list_primary_servers(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<PrimaryServer>>, DNSimpleError>;
+        fn list_primary_servers_stream(options: Option<RequestOptions>) -> Stream<PrimaryServer>;
+        async fn create_primary_server(
+            payload: PrimaryServerPayload,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        async fn get_primary_server(
+            primary_server: impl Into<PrimaryServerId>,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        async fn delete_primary_server(
+            primary_server: impl Into<PrimaryServerId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn link_primary_server(
+            primary_server: impl Into<PrimaryServerId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        async fn unlink_primary_server(
+            primary_server: impl Into<PrimaryServerId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        async fn create_secondary_zone(name: &str) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Services` service with the account bound.
+    AccountServices => Services {
+        async fn applied_services(
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError>;
+        async fn apply_service(
+            domain: impl Into<DomainRef>,
+            service: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn unapply_service(
+            domain: impl Into<DomainRef>,
+            service: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Templates` service with the account bound.
+    AccountTemplates => Templates {
+        async fn list_templates(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Template>>, DNSimpleError>;
+        fn list_templates_stream(options: Option<RequestOptions>) -> Stream<Template>;
+        async fn create_template(
+            payload: TemplatePayload,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        async fn get_template(
+            template: String,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        async fn update_template(
+            template: String,
+            payload: TemplatePayload,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        async fn delete_template(template: String) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn list_template_records(
+            template: String,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<TemplateRecord>>, DNSimpleError>;
+        fn list_template_records_stream(
+            template: String,
+            options: Option<RequestOptions>,
+        ) -> Stream<TemplateRecord>;
+        async fn create_template_record(
+            template: String,
+            payload: TemplateRecordPayload,
+        ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError>;
+        async fn get_template_record(
+            template: String,
+            record: impl Into<TemplateRecordId>,
+        ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError>;
+        async fn delete_template_record(
+            template: String,
+            record: impl Into<TemplateRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn apply_template(
+            domain: impl Into<DomainRef>,
+            template: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `VanityNameServers` service with the account bound.
+    AccountVanityNameServers => VanityNameServers {
+        async fn enable_vanity_name_servers(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError>;
+        async fn disable_vanity_name_servers(
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Webhooks` service with the account bound.
+    AccountWebhooks => Webhooks {
+        async fn list_webhooks(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Webhook>>, DNSimpleError>;
+        fn list_webhooks_stream(options: Option<RequestOptions>) -> Stream<Webhook>;
+        async fn create_webhook(url: String) -> Result<DNSimpleResponse<Webhook>, DNSimpleError>;
+        async fn get_webhook(webhook: impl Into<WebhookId>) -> Result<DNSimpleResponse<Webhook>, DNSimpleError>;
+        async fn delete_webhook(webhook: impl Into<WebhookId>) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+account_service! {
+    /// The `Zones` service with the account bound.
+    AccountZones => Zones {
+        async fn activate_dns(zone_name: &str) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        async fn deactivate_dns(zone_name: &str) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        async fn list_zones(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Zone>>, DNSimpleError>;
+        fn list_zones_stream(options: Option<RequestOptions>) -> Stream<Zone>;
+        async fn get_zone(zone: &str) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        async fn get_zone_file(zone: &str) -> Result<DNSimpleResponse<ZoneFile>, DNSimpleError>;
+        async fn check_zone_distribution(
+            zone: &str,
+        ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>;
+        async fn update_zone_ns_records(
+            zone: &str,
+            payload: ZoneNsRecordsPayload,
+        ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError>;
+        async fn dns_analytics(
+            options: Option<DnsAnalyticsOptions>,
+        ) -> Result<DNSimpleResponse<DnsAnalyticsData>, DNSimpleError>;
+        async fn list_zone_records(
+            zone: &str,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError>;
+        fn list_zone_records_stream(
+            zone: &str,
+            options: Option<RequestOptions>,
+        ) -> Stream<ZoneRecord>;
+        async fn create_zone_record(
+            zone: &str,
+            payload: ZoneRecordPayload,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        async fn get_zone_record(
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        async fn update_zone_record(
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+            payload: ZoneRecordUpdatePayload,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        async fn delete_zone_record(
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        async fn check_zone_record_distribution(
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>;
+        async fn batch_change_zone_records(
+            zone: &str,
+            payload: ZoneRecordBatchPayload,
+        ) -> Result<DNSimpleResponse<ZoneRecordBatchChange>, DNSimpleError>;
+        async fn wait_for_zone_distribution(
+            zone: &str,
+            polling: &DistributionPolling,
+        ) -> DistributionOutcome;
+        async fn wait_for_record_distribution(
+            zone: &str,
+            records: impl IntoIterator<Item = impl Into<ZoneRecordId>>,
+            polling: &DistributionPolling,
+        ) -> DistributionOutcome;
+        fn zone_sync(zone: &str) -> ZoneSync;
+    }
+}
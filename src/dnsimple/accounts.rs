@@ -11,11 +11,11 @@ impl Endpoint for AccountsEndpoint {
 /// The Accounts Service handles the accounts endpoint of the DNSimple API.
 ///
 /// See [API Documentation: accounts](https://developer.dnsimple.com/v2/accounts/)
-pub struct Accounts<'a> {
-    pub client: &'a Client,
+pub struct Accounts {
+    pub client: Client,
 }
 
-impl Accounts<'_> {
+impl Accounts {
     /// Lists the accounts the current authenticated entity has access to.
     ///
     /// # Examples
@@ -0,0 +1,298 @@
+//! DNS-01 challenges of the ACME protocol (RFC 8555), solved with the zone records.
+//!
+//! The `Dns01Provider` trait publishes and removes the `_acme-challenge` TXT records proving
+//! the control of a domain, independently of the ACME library used to talk to the certificate
+//! authority (i.e. `instant-acme`). `Dns01Solver` implements it with the DNSimple zones, and
+//! `with_dns01_challenge` makes sure the record is removed once the challenge is validated,
+//! whether the validation succeeded or not.
+
+use crate::dnsimple::Client;
+use crate::dnsimple::ids::{AccountId, ZoneRecordId};
+use crate::dnsimple::zonefile::relative_name;
+use crate::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+use crate::dnsimple::zones_records::{RecordType, ZoneRecordPayload};
+use crate::errors::DNSimpleError;
+use futures_util::{FutureExt, TryStreamExt, future};
+use std::panic::{self, AssertUnwindSafe};
+
+/// The label prepended to the domain name to form the name of the challenge record.
+pub const CHALLENGE_LABEL: &str = "_acme-challenge";
+
+/// Publishes and removes the TXT records of the DNS-01 challenges
+///
+/// Implement it to plug a DNS provider into an ACME client, or use `Dns01Solver`.
+pub trait Dns01Provider {
+    /// What `present` returns to identify the published record.
+    type Record: Send;
+    /// The error of the provider.
+    type Error: Send;
+
+    /// Publishes the TXT record of the challenge and waits until it is visible.
+    ///
+    /// # Arguments
+    ///
+    /// `identifier`: The domain name being validated (i.e. `www.example.com` or `*.example.com`)
+    /// `value`: The TXT record value of the challenge (the digest of the key authorization)
+    fn present(
+        &self,
+        identifier: &str,
+        value: &str,
+    ) -> impl Future<Output = Result<Self::Record, Self::Error>> + Send;
+
+    /// Removes the TXT record published by `present`.
+    ///
+    /// `with_dns01_challenge` can't call it if its future is dropped before completing: the
+    /// record is then left in place.
+    ///
+    /// # Arguments
+    ///
+    /// `record`: The record returned by `present`
+    fn cleanup(
+        &self,
+        record: &Self::Record,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+}
+
+/// A challenge TXT record created by `Dns01Solver`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ChallengeRecord {
+    /// The name of the zone holding the record.
+    pub zone: String,
+    /// The ID of the record.
+    pub id: ZoneRecordId,
+    /// The name of the record, relative to the zone (i.e. `_acme-challenge.www`).
+    pub name: String,
+    /// The value of the record.
+    pub value: String,
+}
+
+/// Solves the DNS-01 challenges with TXT records in the zones of a DNSimple account
+///
+/// The challenge record is created in the zone of the account owning the name being
+/// validated (the zone with the longest matching name), and `present` waits until the
+/// record is distributed to all the DNSimple name servers.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::acme::{Dns01Solver, with_dns01_challenge};
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::errors::DNSimpleError;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+///     let solver = Dns01Solver::new(client, 1234);
+///
+///     // The value is provided by the ACME client, i.e. `key_authorization().dns_value()`
+///     let result = with_dns01_challenge(&solver, "www.example.com", "<value>", || async {
+///         // Tell the ACME server the challenge is ready and wait for the validation
+///         Ok::<_, DNSimpleError>(())
+///     })
+///     .await;
+/// }
+/// ```
+#[derive(Clone)]
+pub struct Dns01Solver {
+    client: Client,
+    account_id: AccountId,
+    ttl: u64,
+    polling: DistributionPolling,
+}
+
+impl Dns01Solver {
+    /// Creates the solver for the zones of the account, with a TTL of 60 seconds for the
+    /// challenge records.
+    ///
+    /// # Arguments
+    ///
+    /// `client`: The client to call the API with
+    /// `account_id`: The account ID
+    pub fn new(client: Client, account_id: impl Into<AccountId>) -> Self {
+        Dns01Solver {
+            client,
+            account_id: account_id.into(),
+            ttl: 60,
+            polling: DistributionPolling::default(),
+        }
+    }
+
+    /// Sets the TTL of the challenge records.
+    pub fn ttl(mut self, ttl: u64) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// Sets how to wait for the distribution of the challenge records.
+    pub fn polling(mut self, polling: DistributionPolling) -> Self {
+        self.polling = polling;
+        self
+    }
+
+    /// Returns the zone of the account owning the name, and the name relative to the zone.
+    async fn find_zone(&self, name: &str) -> Result<(String, String), DNSimpleError> {
+        let owner = self
+            .client
+            .zones()
+            .list_zones_stream(self.account_id, None)
+            .try_filter(|zone| future::ready(relative_name(name, &zone.name).is_some()))
+            .try_fold(None, |owner: Option<String>, zone| {
+                future::ready(Ok(match owner {
+                    Some(owner) if owner.len() >= zone.name.len() => Some(owner),
+                    _ => Some(zone.name),
+                }))
+            })
+            .await?;
+
+        match owner {
+            Some(zone) => {
+                let relative = relative_name(name, &zone).unwrap_or_default();
+                Ok((zone, relative))
+            }
+            None => Err(DNSimpleError::Dns01Challenge(format!(
+                "no zone of the account owns {}",
+                name
+            ))),
+        }
+    }
+
+    /// Deletes the record, considering an already deleted record as deleted.
+    async fn delete(&self, record: &ChallengeRecord) -> Result<(), DNSimpleError> {
+        match self
+            .client
+            .zones()
+            .delete_zone_record(self.account_id, &record.zone, record.id)
+            .await
+        {
+            Ok(_) | Err(DNSimpleError::NotFound(_)) => Ok(()),
+            Err(error) => Err(error),
+        }
+    }
+}
+
+impl Dns01Provider for Dns01Solver {
+    type Record = ChallengeRecord;
+    type Error = DNSimpleError;
+
+    /// Creates the `_acme-challenge` TXT record in the zone owning the identifier and waits
+    /// until it is distributed. The record is deleted if the distribution fails or times out.
+    fn present(
+        &self,
+        identifier: &str,
+        value: &str,
+    ) -> impl Future<Output = Result<ChallengeRecord, DNSimpleError>> + Send {
+        let name = challenge_name(identifier);
+        let value = String::from(value);
+
+        async move {
+            let (zone, name) = self.find_zone(&name).await?;
+            let zones = self.client.zones();
+            let payload = ZoneRecordPayload {
+                name,
+                record_type: RecordType::Txt,
+                content: value.clone(),
+                ttl: Some(self.ttl),
+                priority: None,
+                regions: None,
+            };
+            let created = zones
+                .create_zone_record(self.account_id, &zone, payload)
+                .await?
+                .data
+                .ok_or_else(|| {
+                    DNSimpleError::Deserialization(String::from(
+                        "Missing zone record in the response",
+                    ))
+                })?;
+            let record = ChallengeRecord {
+                zone,
+                id: created.id,
+                name: created.name,
+                value,
+            };
+
+            let outcome = zones
+                .wait_for_record_distribution(
+                    self.account_id,
+                    &record.zone,
+                    [record.id],
+                    &self.polling,
+                )
+                .await;
+            let error = match outcome {
+                DistributionOutcome::Distributed { .. } => return Ok(record),
+                DistributionOutcome::TimedOut { elapsed, .. } => {
+                    DNSimpleError::Dns01Challenge(format!(
+                        "the record {} of {} is not distributed after {}s",
+                        record.id,
+                        record.zone,
+                        elapsed.as_secs()
+                    ))
+                }
+                DistributionOutcome::Failed(error) => error,
+            };
+            // The error of the distribution is more relevant than the one of the deletion.
+            let _ = self.delete(&record).await;
+            Err(error)
+        }
+    }
+
+    /// Deletes the TXT record. A record already deleted is not an error.
+    fn cleanup(
+        &self,
+        record: &ChallengeRecord,
+    ) -> impl Future<Output = Result<(), DNSimpleError>> + Send {
+        self.delete(record)
+    }
+}
+
+/// Publishes the challenge record, runs `validate` and removes the record
+///
+/// The record is removed whether `validate` succeeds, fails or panics (the panic is resumed
+/// once the record is removed). When both `validate` and the removal fail, the error of
+/// `validate` is returned.
+///
+/// The record is not removed if the returned future is dropped before completing (i.e. when
+/// it is cancelled by a timeout or a `select!`), as the removal can't run without being
+/// polled. To bound the time of the validation, apply the timeout inside `validate`, or call
+/// `Dns01Provider::present` and `Dns01Provider::cleanup` directly.
+///
+/// # Arguments
+///
+/// `provider`: The provider publishing the record
+/// `identifier`: The domain name being validated
+/// `value`: The TXT record value of the challenge
+/// `validate`: Asks the ACME server to validate the challenge and waits for the result
+pub async fn with_dns01_challenge<P, F, Fut, T, E>(
+    provider: &P,
+    identifier: &str,
+    value: &str,
+    validate: F,
+) -> Result<T, E>
+where
+    P: Dns01Provider,
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+    E: From<P::Error>,
+{
+    let record = provider.present(identifier, value).await?;
+    let result = AssertUnwindSafe(async move { validate().await })
+        .catch_unwind()
+        .await;
+    let cleanup = provider.cleanup(&record).await;
+
+    let value = match result {
+        Ok(result) => result?,
+        Err(panic) => panic::resume_unwind(panic),
+    };
+    cleanup?;
+    Ok(value)
+}
+
+/// Returns the absolute name of the challenge record of the identifier, without the trailing
+/// dot (the wildcard label of a wildcard identifier is removed, as per RFC 8555).
+pub fn challenge_name(identifier: &str) -> String {
+    let identifier = identifier.trim_end_matches('.');
+    let identifier = identifier.strip_prefix("*.").unwrap_or(identifier);
+    format!("{}.{}", CHALLENGE_LABEL, identifier.to_ascii_lowercase())
+}
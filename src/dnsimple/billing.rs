@@ -0,0 +1,132 @@
+use crate::dnsimple::ids::AccountId;
+use crate::dnsimple::list_options::list_options;
+use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
+use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
+pub use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Represents a charge in DNSimple
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Charge {
+    /// When the charge was invoiced.
+    pub invoiced_at: String,
+    /// The total amount of the charge.
+    pub total_amount: Decimal,
+    /// The amount of the charge that is still due.
+    pub balance_amount: Decimal,
+    /// The charge reference.
+    pub reference: String,
+    /// The state of the charge.
+    pub state: String,
+    /// The line items of the charge.
+    pub items: Vec<ChargeItem>,
+}
+
+/// Represents a line item of a charge
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChargeItem {
+    /// The line item description.
+    pub description: String,
+    /// The line item amount.
+    pub amount: Decimal,
+    /// The ID of the product the line item refers to, if any.
+    pub product_id: Option<u64>,
+    /// The type of product the line item refers to (i.e. `domain-registration`).
+    pub product_type: String,
+    /// The reference of the product (i.e. the domain name or the certificate ID), if any.
+    pub product_reference: Option<String>,
+}
+
+list_options! {
+    /// The options to list the billing charges
+    ChargeListOptions;
+    /// The fields the billing charges can be sorted by
+    ChargeSort {
+        /// Sort by invoice date.
+        Invoiced => "invoiced",
+    }
+}
+
+impl ChargeListOptions {
+    /// Only lists the charges invoiced from this date (in the `YYYY-MM-DD` format).
+    pub fn start_date(self, start_date: &str) -> Self {
+        self.filter("start_date", start_date)
+    }
+
+    /// Only lists the charges invoiced until this date (in the `YYYY-MM-DD` format).
+    pub fn end_date(self, end_date: &str) -> Self {
+        self.filter("end_date", end_date)
+    }
+}
+
+struct ChargesEndpoint;
+
+impl Endpoint for ChargesEndpoint {
+    type Output = Vec<Charge>;
+}
+
+/// The Billing Service handles the billing endpoint of the DNSimple API.
+///
+/// See [API Documentation: billing](https://developer.dnsimple.com/v2/billing/)
+pub struct Billing {
+    pub client: Client,
+}
+
+impl Billing {
+    /// Lists the billing charges for the account.
+    ///
+    /// The charges can be filtered by date range using the `start_date` and `end_date`
+    /// filters (in the `YYYY-MM-DD` format), and sorted by `invoiced` date.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::billing::ChargeListOptions;
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let options = ChargeListOptions::new().start_date("2023-01-01").end_date("2023-12-31");
+    ///     let charges = client.billing().list_charges(1234, options.into()).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `ChargeListOptions`
+    pub async fn list_charges(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Result<DNSimpleResponse<Vec<Charge>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/billing/charges", account_id);
+
+        self.client.get::<ChargesEndpoint>(&path, options).await
+    }
+
+    /// Lists all the billing charges for the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `ChargeListOptions`
+    pub fn list_charges_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Charge, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/billing/charges", account_id);
+
+        self.client
+            .paginate::<ChargesEndpoint, _>(path, options)
+            .boxed()
+    }
+}
@@ -0,0 +1,1123 @@
+//! A blocking (synchronous) client for the DNSimple API
+//!
+//! The blocking `Client` mirrors the services of the async `Client`: the methods have
+//! the same arguments and return the same `DNSimpleResponse` and `DNSimpleError` types,
+//! but block the current thread until the request completes. The paginated `*_stream`
+//! methods are replaced by `*_iter` methods returning an `Iterator`.
+//!
+//! The requests run on an internal single-threaded tokio runtime, so the blocking client
+//! must not be used (nor dropped) from within an async runtime.
+//!
+//! This module requires the `blocking` feature.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use dnsimple::dnsimple::blocking::new_client;
+//!
+//! let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+//! let identity = client.identity().whoami().unwrap().data.unwrap();
+//!
+//! for domain in client.domains().list_domains_iter(1234, None) {
+//!     println!("{}", domain.unwrap().name);
+//! }
+//! ```
+use crate::dnsimple::billing::Charge;
+use crate::dnsimple::certificates::{
+    Certificate, CertificateBundle, CertificatePrivateKey, LetsEncryptPurchase,
+    LetsEncryptPurchasePayload, LetsEncryptPurchaseRenewal, LetsEncryptPurchaseRenewalPayload,
+};
+use crate::dnsimple::certificates_letsencrypt::{CertificateMaterial, IssuancePolling};
+use crate::dnsimple::certificates_renewal::RenewalReport;
+use crate::dnsimple::contacts::{Contact, ContactPayload};
+use crate::dnsimple::domains::Domain;
+use crate::dnsimple::domains_dnssec::Dnssec;
+use crate::dnsimple::domains_email_forwards::{
+    EmailForward, EmailForwardPayload, EmailForwardsInList,
+};
+use crate::dnsimple::domains_push::{DomainPush, InitiatePushPayload};
+use crate::dnsimple::domains_research::DomainResearchStatus;
+use crate::dnsimple::domains_signer_records::{
+    DelegationSignerRecord, DelegationSignerRecordPayload,
+};
+use crate::dnsimple::identity::{Account, WhoamiData};
+use crate::dnsimple::ids::{
+    AccountId, CertificateId, CertificateRenewalId, ContactId, DelegationSignerRecordId,
+    DomainPushId, DomainRef, DomainRegistrationId, DomainRenewalId, DomainRestoreId,
+    DomainTransferId, EmailForwardId, PrimaryServerId, RegistrantChangeId, TemplateRecordId,
+    WebhookId, ZoneRecordId,
+};
+use crate::dnsimple::oauth::{AccessToken, OAuthTokenPayload};
+use crate::dnsimple::registrar::{
+    DomainCheck, DomainPrice, DomainRegistration, DomainRegistrationPayload, DomainRenewal,
+    DomainRenewalPayload, DomainRestore, DomainRestorePayload, DomainTransfer,
+    DomainTransferPayload,
+};
+use crate::dnsimple::registrar_name_servers::VanityNameServer;
+use crate::dnsimple::registrar_registrant_changes::{
+    RegistrantChange, RegistrantChangeCheck, RegistrantChangeCheckPayload, RegistrantChangePayload,
+};
+use crate::dnsimple::registrar_transfer_lock::TransferLock;
+use crate::dnsimple::registrar_whois_privacy::WhoisPrivacy;
+use crate::dnsimple::retry::RetryPolicy;
+use crate::dnsimple::secondary_dns::{PrimaryServer, PrimaryServerPayload};
+use crate::dnsimple::services::Service;
+use crate::dnsimple::templates::{
+    Template, TemplatePayload, TemplateRecord, TemplateRecordPayload,
+};
+use crate::dnsimple::tlds::{Tld, TldExtendedAttribute};
+use crate::dnsimple::webhooks::Webhook;
+use crate::dnsimple::zone_sync::{Plan, SyncOutcome};
+use crate::dnsimple::zones::{Zone, ZoneDistribution, ZoneFile, ZoneNsRecordsPayload};
+use crate::dnsimple::zones_analytics::{DnsAnalyticsData, DnsAnalyticsOptions};
+use crate::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+use crate::dnsimple::zones_records::{
+    ZoneRecord, ZoneRecordBatchChange, ZoneRecordBatchPayload, ZoneRecordPayload,
+    ZoneRecordUpdatePayload,
+};
+use crate::dnsimple::{
+    self, DNSimpleEmptyResponse, DNSimpleResponse, RateLimit, RequestOptions, accounts, billing,
+    certificates, certificates_renewal, contacts, domains, identity, oauth, registrar,
+    secondary_dns, services, templates, tlds, vanity_name_servers, webhooks, zone_sync, zones,
+};
+use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::runtime::Runtime;
+
+/// Defines the blocking version of a service: a wrapper around the async service running
+/// its methods to completion on the runtime of the blocking client.
+macro_rules! blocking_service {
+    ($(#[$meta:meta])* $blocking:ident => $module:ident::$service:ident { $($methods:tt)* }) => {
+        $(#[$meta])*
+        pub struct $blocking {
+            service: $module::$service,
+            runtime: Arc<Runtime>,
+        }
+
+        impl $blocking {
+            blocking_service!(@methods $service; $($methods)*);
+        }
+    };
+    (@methods $service:ident;) => {};
+    (
+        @methods $service:ident;
+        fn $method:ident => $stream:ident($($arg:ident: $ty:ty),* $(,)?) -> Paginated<$item:ty>;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("See `", stringify!($service), "::", stringify!($stream), "`.")]
+        ///
+        /// The pages are fetched lazily while iterating.
+        pub fn $method(&self, $($arg: $ty),*) -> Paginated<$item> {
+            Paginated {
+                stream: self.service.$stream($($arg),*),
+                runtime: self.runtime.clone(),
+            }
+        }
+
+        blocking_service!(@methods $service; $($rest)*);
+    };
+    (
+        @methods $service:ident;
+        fn $method:ident($($arg:ident: $ty:ty),* $(,)?) -> $ret:ty;
+        $($rest:tt)*
+    ) => {
+        #[doc = concat!("See `", stringify!($service), "::", stringify!($method), "`.")]
+        pub fn $method(&self, $($arg: $ty),*) -> $ret {
+            self.runtime.block_on(self.service.$method($($arg),*))
+        }
+
+        blocking_service!(@methods $service; $($rest)*);
+    };
+}
+
+/// Helper function to create a new blocking client
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::blocking::new_client;
+///
+/// let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+/// ```
+///
+/// # Arguments
+///
+/// `sandbox`: `true` if you want to run in the sandbox environment, otherwise `false`
+/// `token`: the bearer authentication token
+pub fn new_client(sandbox: bool, token: String) -> Result<Client, DNSimpleError> {
+    Client::new(dnsimple::new_client(sandbox, token)?)
+}
+
+/// The blocking DNSimple API client
+///
+/// It wraps an async `Client` (i.e. one created with a `ClientBuilder`), sharing its
+/// configuration. Cloning it is cheap, the clones share the same runtime.
+#[derive(Clone)]
+pub struct Client {
+    client: dnsimple::Client,
+    runtime: Arc<Runtime>,
+}
+
+impl Client {
+    /// Creates a blocking client sending the requests with the given async client
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use dnsimple::dnsimple::blocking;
+    /// use dnsimple::dnsimple::Client;
+    ///
+    /// let client = Client::builder(String::from("AUTH_TOKEN"))
+    ///     .timeout(Duration::from_secs(30))
+    ///     .build()
+    ///     .unwrap();
+    /// let client = blocking::Client::new(client).unwrap();
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `client`: the async client
+    ///
+    /// # Errors
+    ///
+    /// `DNSimpleError::Configuration` if the runtime cannot be started.
+    pub fn new(client: dnsimple::Client) -> Result<Client, DNSimpleError> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| {
+                DNSimpleError::Configuration(format!("Cannot start the runtime: {}", e))
+            })?;
+
+        Ok(Client {
+            client,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Returns the underlying async `Client`.
+    pub fn client(&self) -> &dnsimple::Client {
+        &self.client
+    }
+
+    /// Sets the base url. See `dnsimple::Client::set_base_url`.
+    ///
+    /// # Arguments
+    ///
+    /// `url`: The url we want to use (i.e. `https://my.api.url`)
+    pub fn set_base_url(&mut self, url: &str) {
+        self.client.set_base_url(url);
+    }
+
+    /// Sets a custom token prepended to the `User-Agent` header. See `dnsimple::Client::set_user_agent`.
+    ///
+    /// # Arguments
+    ///
+    /// `custom_user_agent`: The custom user agent token
+    pub fn set_user_agent(&mut self, custom_user_agent: &str) {
+        self.client.set_user_agent(custom_user_agent);
+    }
+
+    /// Sets the policy used to retry the failed requests. See `dnsimple::Client::set_retry_policy`.
+    ///
+    /// # Arguments
+    ///
+    /// `policy`: The `RetryPolicy` to use for every request.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.client.set_retry_policy(policy);
+    }
+
+    /// Returns the current url (including the `API_VERSION` as part of the path).
+    pub fn versioned_url(&self) -> String {
+        self.client.versioned_url()
+    }
+
+    /// Returns the rate limit of the last response received from the DNSimple API.
+    pub fn last_rate_limit(&self) -> Option<RateLimit> {
+        self.client.last_rate_limit()
+    }
+
+    /// Returns the blocking `accounts` service attached to this client
+    pub fn accounts(&self) -> Accounts {
+        Accounts {
+            service: self.client.accounts(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `billing` service attached to this client
+    pub fn billing(&self) -> Billing {
+        Billing {
+            service: self.client.billing(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `certificates` service attached to this client
+    pub fn certificates(&self) -> Certificates {
+        Certificates {
+            service: self.client.certificates(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `contacts` service attached to this client
+    pub fn contacts(&self) -> Contacts {
+        Contacts {
+            service: self.client.contacts(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `domains` service attached to this client
+    pub fn domains(&self) -> Domains {
+        Domains {
+            service: self.client.domains(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `identity` service attached to this client
+    pub fn identity(&self) -> Identity {
+        Identity {
+            service: self.client.identity(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `oauth` service attached to this client
+    pub fn oauth(&self) -> OAuth {
+        OAuth {
+            service: self.client.oauth(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `registrar` service attached to this client
+    pub fn registrar(&self) -> Registrar {
+        Registrar {
+            service: self.client.registrar(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `secondary_dns` service attached to this client
+    pub fn secondary_dns(&self) -> SecondaryDns {
+        SecondaryDns {
+            service: self.client.secondary_dns(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `services` service attached to this client
+    pub fn services(&self) -> Services {
+        Services {
+            service: self.client.services(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `templates` service attached to this client
+    pub fn templates(&self) -> Templates {
+        Templates {
+            service: self.client.templates(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `tlds` service attached to this client
+    pub fn tlds(&self) -> Tlds {
+        Tlds {
+            service: self.client.tlds(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `vanity_name_servers` service attached to this client
+    pub fn vanity_name_servers(&self) -> VanityNameServers {
+        VanityNameServers {
+            service: self.client.vanity_name_servers(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `webhooks` service attached to this client
+    pub fn webhooks(&self) -> Webhooks {
+        Webhooks {
+            service: self.client.webhooks(),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Returns the blocking `zones` service attached to this client
+    pub fn zones(&self) -> Zones {
+        Zones {
+            service: self.client.zones(),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// An iterator over every item of a paginated list endpoint
+///
+/// Returned by the `*_iter` methods of the blocking services, it fetches the next
+/// page once all the items of the current one have been consumed. An error ends
+/// the iteration.
+pub struct Paginated<T> {
+    stream: BoxStream<'static, Result<T, DNSimpleError>>,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Iterator for Paginated<T> {
+    type Item = Result<T, DNSimpleError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.runtime.block_on(self.stream.next())
+    }
+}
+
+impl<T> fmt::Debug for Paginated<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Paginated").finish_non_exhaustive()
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Accounts`.
+    Accounts => accounts::Accounts {
+        fn list_accounts() -> Result<DNSimpleResponse<Vec<Account>>, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Billing`.
+    Billing => billing::Billing {
+        fn list_charges(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Charge>>, DNSimpleError>;
+        fn list_charges_iter => list_charges_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Charge>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Certificates`.
+    Certificates => certificates::Certificates {
+        fn list_certificates(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Certificate>>, DNSimpleError>;
+        fn list_certificates_iter => list_certificates_stream(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Certificate>;
+        fn get_certificate(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        fn download_certificate(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<CertificateBundle>, DNSimpleError>;
+        fn get_certificate_private_key(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<CertificatePrivateKey>, DNSimpleError>;
+        fn purchase_letsencrypt_certificate(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: LetsEncryptPurchasePayload,
+        ) -> Result<DNSimpleResponse<LetsEncryptPurchase>, DNSimpleError>;
+        fn issue_letsencrypt_certificate(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        fn purchase_letsencrypt_certificate_renewal(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+            payload: LetsEncryptPurchaseRenewalPayload,
+        ) -> Result<DNSimpleResponse<LetsEncryptPurchaseRenewal>, DNSimpleError>;
+        fn issue_letsencrypt_certificate_renewal(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            certificate_id: impl Into<CertificateId>,
+            certificate_renewal_id: impl Into<CertificateRenewalId>,
+        ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError>;
+        fn obtain_letsencrypt_certificate(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: LetsEncryptPurchasePayload,
+            polling: &IssuancePolling,
+        ) -> Result<CertificateMaterial, DNSimpleError>;
+    }
+}
+
+impl Certificates {
+    /// See `Certificates::renewal_manager`.
+    pub fn renewal_manager(&self, account_id: impl Into<AccountId>) -> RenewalManager {
+        RenewalManager {
+            manager: self.service.renewal_manager(account_id),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// The blocking version of `RenewalManager`.
+#[derive(Clone)]
+pub struct RenewalManager {
+    manager: certificates_renewal::RenewalManager,
+    runtime: Arc<Runtime>,
+}
+
+impl RenewalManager {
+    /// See `RenewalManager::window`.
+    pub fn window(mut self, window: Duration) -> Self {
+        self.manager = self.manager.window(window);
+        self
+    }
+
+    /// See `RenewalManager::dry_run`.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.manager = self.manager.dry_run(dry_run);
+        self
+    }
+
+    /// See `RenewalManager::auto_renew`.
+    pub fn auto_renew(mut self, auto_renew: bool) -> Self {
+        self.manager = self.manager.auto_renew(auto_renew);
+        self
+    }
+
+    /// See `RenewalManager::run`.
+    pub fn run(&self) -> Result<RenewalReport, DNSimpleError> {
+        self.runtime.block_on(self.manager.run())
+    }
+
+    /// See `RenewalManager::run_at`.
+    pub fn run_at(&self, now: SystemTime) -> Result<RenewalReport, DNSimpleError> {
+        self.runtime.block_on(self.manager.run_at(now))
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Contacts`.
+    Contacts => contacts::Contacts {
+        fn list_contacts(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Contact>>, DNSimpleError>;
+        fn list_contacts_iter => list_contacts_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Contact>;
+        fn create_contact(
+            account_id: impl Into<AccountId>,
+            payload: ContactPayload,
+        ) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        fn get_contact(
+            account_id: impl Into<AccountId>,
+            contact: impl Into<ContactId>,
+        ) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        fn update_contact(
+            account_id: impl Into<AccountId>,
+            contact: impl Into<ContactId>,
+            payload: ContactPayload,
+        ) -> Result<DNSimpleResponse<Contact>, DNSimpleError>;
+        fn delete_contact(
+            account_id: impl Into<AccountId>,
+            contact: impl Into<ContactId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Domains`.
+    Domains => domains::Domains {
+        fn list_domains(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Domain>>, DNSimpleError>;
+        fn list_domains_iter => list_domains_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Domain>;
+        fn create_domain(
+            account_id: impl Into<AccountId>,
+            name: String,
+        ) -> Result<DNSimpleResponse<Domain>, DNSimpleError>;
+        fn get_domain(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Domain>, DNSimpleError>;
+        fn delete_domain(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn enable_dnssec(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError>;
+        fn disable_dnssec(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn get_dnssec(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError>;
+        fn list_email_forwards(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<EmailForwardsInList>>, DNSimpleError>;
+        fn list_email_forwards_iter => list_email_forwards_stream(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<EmailForwardsInList>;
+        fn create_email_forward(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: EmailForwardPayload,
+        ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError>;
+        fn get_email_forward(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            email_forward: impl Into<EmailForwardId>,
+        ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError>;
+        fn delete_email_forward(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            email_forward: impl Into<EmailForwardId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn initiate_push(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: InitiatePushPayload,
+        ) -> Result<DNSimpleResponse<DomainPush>, DNSimpleError>;
+        fn list_pushes(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<DomainPush>>, DNSimpleError>;
+        fn list_pushes_iter => list_pushes_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<DomainPush>;
+        fn accept_push(
+            account_id: impl Into<AccountId>,
+            push_id: impl Into<DomainPushId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn reject_push(
+            account_id: impl Into<AccountId>,
+            push_id: impl Into<DomainPushId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn get_domain_research_status(
+            account_id: impl Into<AccountId>,
+            domain: String,
+        ) -> Result<DNSimpleResponse<DomainResearchStatus>, DNSimpleError>;
+        fn list_delegation_signer_records(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<DelegationSignerRecord>>, DNSimpleError>;
+        fn list_delegation_signer_records_iter => list_delegation_signer_records_stream(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<DelegationSignerRecord>;
+        fn create_delegation_signer_record(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: DelegationSignerRecordPayload,
+        ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError>;
+        fn get_delegation_signer_record(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError>;
+        fn delete_delegation_signer_record(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            delegation_signer_record_id: impl Into<DelegationSignerRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Identity`.
+    Identity => identity::Identity {
+        fn whoami() -> Result<DNSimpleResponse<WhoamiData>, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `OAuth`.
+    OAuth => oauth::OAuth {
+        fn exchange_authorization_for_token(
+            payload: OAuthTokenPayload,
+        ) -> Result<AccessToken, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Registrar`.
+    Registrar => registrar::Registrar {
+        fn check_domain(
+            account_id: impl Into<AccountId>,
+            domain: &str,
+        ) -> Result<DNSimpleResponse<DomainCheck>, DNSimpleError>;
+        fn get_domain_prices(
+            account_id: impl Into<AccountId>,
+            domain: &str,
+        ) -> Result<DNSimpleResponse<DomainPrice>, DNSimpleError>;
+        fn get_domain_registration(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            domain_registration_id: impl Into<DomainRegistrationId>,
+        ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError>;
+        fn get_domain_renewal(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            domain_renewal_id: impl Into<DomainRenewalId>,
+        ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError>;
+        fn register_domain(
+            account_id: impl Into<AccountId>,
+            domain: &str,
+            payload: DomainRegistrationPayload,
+        ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError>;
+        fn transfer_domain(
+            account_id: impl Into<AccountId>,
+            domain: &str,
+            payload: DomainTransferPayload,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        fn get_domain_transfer(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            domain_transfer: impl Into<DomainTransferId>,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        fn cancel_domain_transfer(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            domain_transfer: impl Into<DomainTransferId>,
+        ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError>;
+        fn renew_domain(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: DomainRenewalPayload,
+        ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError>;
+        fn restore_domain(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            payload: DomainRestorePayload,
+        ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError>;
+        fn get_domain_restore(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            domain_restore_id: impl Into<DomainRestoreId>,
+        ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError>;
+        fn transfer_domain_out(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn enable_domain_auto_renewal(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn disable_domain_auto_renewal(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn get_domain_delegation(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError>;
+        fn change_domain_delegation(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            server_names: Vec<&str>,
+        ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError>;
+        fn change_domain_delegation_to_vanity(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            server_names: Vec<&str>,
+        ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError>;
+        fn change_domain_delegation_from_vanity(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn get_registrant_change(
+            account_id: impl Into<AccountId>,
+            registrant_change_id: impl Into<RegistrantChangeId>,
+        ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError>;
+        fn check_registrant_change(
+            account_id: impl Into<AccountId>,
+            payload: RegistrantChangeCheckPayload,
+        ) -> Result<DNSimpleResponse<RegistrantChangeCheck>, DNSimpleError>;
+        fn create_registrant_change(
+            account_id: impl Into<AccountId>,
+            payload: RegistrantChangePayload,
+        ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError>;
+        fn list_registrant_changes(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<RegistrantChange>>, DNSimpleError>;
+        fn list_registrant_changes_iter => list_registrant_changes_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<RegistrantChange>;
+        fn delete_registrant_change(
+            account_id: impl Into<AccountId>,
+            registrant_change_id: impl Into<RegistrantChangeId>,
+        ) -> Result<DNSimpleResponse<Option<RegistrantChange>>, DNSimpleError>;
+        fn enable_domain_transfer_lock(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        fn disable_domain_transfer_lock(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        fn get_domain_transfer_lock(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError>;
+        fn enable_whois_privacy(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError>;
+        fn disable_whois_privacy(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `SecondaryDns`.
+    SecondaryDns => secondary_dns::SecondaryDns {
+        fn list_primary_servers(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<PrimaryServer>>, DNSimpleError>;
+        fn list_primary_servers_iter => list_primary_servers_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<PrimaryServer>;
+        fn create_primary_server(
+            account_id: impl Into<AccountId>,
+            payload: PrimaryServerPayload,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        fn get_primary_server(
+            account_id: impl Into<AccountId>,
+            primary_server: impl Into<PrimaryServerId>,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        fn delete_primary_server(
+            account_id: impl Into<AccountId>,
+            primary_server: impl Into<PrimaryServerId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn link_primary_server(
+            account_id: impl Into<AccountId>,
+            primary_server: impl Into<PrimaryServerId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        fn unlink_primary_server(
+            account_id: impl Into<AccountId>,
+            primary_server: impl Into<PrimaryServerId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError>;
+        fn create_secondary_zone(
+            account_id: impl Into<AccountId>,
+            name: &str,
+        ) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Services`.
+    Services => services::Services {
+        fn list_services(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError>;
+        fn list_services_iter => list_services_stream(
+            options: Option<RequestOptions>,
+        ) -> Paginated<Service>;
+        fn get_service(service: String) -> Result<DNSimpleResponse<Service>, DNSimpleError>;
+        fn applied_services(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError>;
+        fn apply_service(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            service: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn unapply_service(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            service: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Templates`.
+    Templates => templates::Templates {
+        fn list_templates(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Template>>, DNSimpleError>;
+        fn list_templates_iter => list_templates_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Template>;
+        fn create_template(
+            account_id: impl Into<AccountId>,
+            payload: TemplatePayload,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        fn get_template(
+            account_id: impl Into<AccountId>,
+            template: String,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        fn update_template(
+            account_id: impl Into<AccountId>,
+            template: String,
+            payload: TemplatePayload,
+        ) -> Result<DNSimpleResponse<Template>, DNSimpleError>;
+        fn delete_template(
+            account_id: impl Into<AccountId>,
+            template: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn list_template_records(
+            account_id: impl Into<AccountId>,
+            template: String,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<TemplateRecord>>, DNSimpleError>;
+        fn list_template_records_iter => list_template_records_stream(
+            account_id: impl Into<AccountId>,
+            template: String,
+            options: Option<RequestOptions>,
+        ) -> Paginated<TemplateRecord>;
+        fn create_template_record(
+            account_id: impl Into<AccountId>,
+            template: String,
+            payload: TemplateRecordPayload,
+        ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError>;
+        fn get_template_record(
+            account_id: impl Into<AccountId>,
+            template: String,
+            record: impl Into<TemplateRecordId>,
+        ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError>;
+        fn delete_template_record(
+            account_id: impl Into<AccountId>,
+            template: String,
+            record: impl Into<TemplateRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn apply_template(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+            template: String,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Tlds`.
+    Tlds => tlds::Tlds {
+        fn list_tlds(
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Tld>>, DNSimpleError>;
+        fn list_tlds_iter => list_tlds_stream(options: Option<RequestOptions>) -> Paginated<Tld>;
+        fn get_tld(tld: String) -> Result<DNSimpleResponse<Tld>, DNSimpleError>;
+        fn get_tld_extended_attributes(
+            tld: String,
+        ) -> Result<DNSimpleResponse<Vec<TldExtendedAttribute>>, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `VanityNameServers`.
+    VanityNameServers => vanity_name_servers::VanityNameServers {
+        fn enable_vanity_name_servers(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError>;
+        fn disable_vanity_name_servers(
+            account_id: impl Into<AccountId>,
+            domain: impl Into<DomainRef>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Webhooks`.
+    Webhooks => webhooks::Webhooks {
+        fn list_webhooks(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Webhook>>, DNSimpleError>;
+        fn list_webhooks_iter => list_webhooks_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Webhook>;
+        fn create_webhook(
+            account_id: impl Into<AccountId>,
+            url: String,
+        ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError>;
+        fn get_webhook(
+            account_id: impl Into<AccountId>,
+            webhook: impl Into<WebhookId>,
+        ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError>;
+        fn delete_webhook(
+            account_id: impl Into<AccountId>,
+            webhook: impl Into<WebhookId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+    }
+}
+
+blocking_service! {
+    /// The blocking version of `Zones`.
+    Zones => zones::Zones {
+        fn activate_dns(
+            account_id: impl Into<AccountId>,
+            zone_name: &str,
+        ) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        fn deactivate_dns(
+            account_id: impl Into<AccountId>,
+            zone_name: &str,
+        ) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        fn list_zones(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<Zone>>, DNSimpleError>;
+        fn list_zones_iter => list_zones_stream(
+            account_id: impl Into<AccountId>,
+            options: Option<RequestOptions>,
+        ) -> Paginated<Zone>;
+        fn get_zone(account_id: impl Into<AccountId>, zone: &str) -> Result<DNSimpleResponse<Zone>, DNSimpleError>;
+        fn get_zone_file(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<ZoneFile>, DNSimpleError>;
+        fn check_zone_distribution(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+        ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>;
+        fn update_zone_ns_records(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            payload: ZoneNsRecordsPayload,
+        ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError>;
+        fn dns_analytics(
+            account_id: impl Into<AccountId>,
+            options: Option<DnsAnalyticsOptions>,
+        ) -> Result<DNSimpleResponse<DnsAnalyticsData>, DNSimpleError>;
+        fn list_zone_records(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            options: Option<RequestOptions>,
+        ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError>;
+        fn list_zone_records_iter => list_zone_records_stream(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            options: Option<RequestOptions>,
+        ) -> Paginated<ZoneRecord>;
+        fn create_zone_record(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            payload: ZoneRecordPayload,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        fn get_zone_record(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        fn update_zone_record(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+            payload: ZoneRecordUpdatePayload,
+        ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError>;
+        fn delete_zone_record(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleEmptyResponse, DNSimpleError>;
+        fn check_zone_record_distribution(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            record: impl Into<ZoneRecordId>,
+        ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>;
+        fn batch_change_zone_records(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            payload: ZoneRecordBatchPayload,
+        ) -> Result<DNSimpleResponse<ZoneRecordBatchChange>, DNSimpleError>;
+        fn wait_for_zone_distribution(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            polling: &DistributionPolling,
+        ) -> DistributionOutcome;
+        fn wait_for_record_distribution(
+            account_id: impl Into<AccountId>,
+            zone: &str,
+            records: impl IntoIterator<Item = impl Into<ZoneRecordId>>,
+            polling: &DistributionPolling,
+        ) -> DistributionOutcome;
+    }
+}
+
+impl Zones {
+    /// See `Zones::zone_sync`.
+    pub fn zone_sync(&self, account_id: impl Into<AccountId>, zone: &str) -> ZoneSync {
+        ZoneSync {
+            sync: self.service.zone_sync(account_id, zone),
+            runtime: self.runtime.clone(),
+        }
+    }
+}
+
+/// The blocking version of `ZoneSync`.
+#[derive(Clone)]
+pub struct ZoneSync {
+    sync: zone_sync::ZoneSync,
+    runtime: Arc<Runtime>,
+}
+
+impl ZoneSync {
+    /// See `ZoneSync::dry_run`.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.sync = self.sync.dry_run(dry_run);
+        self
+    }
+
+    /// See `ZoneSync::max_deletions`.
+    pub fn max_deletions(mut self, max_deletions: usize) -> Self {
+        self.sync = self.sync.max_deletions(max_deletions);
+        self
+    }
+
+    /// See `ZoneSync::plan`.
+    pub fn plan(&self, desired: Vec<ZoneRecordPayload>) -> Result<Plan, DNSimpleError> {
+        self.runtime.block_on(self.sync.plan(desired))
+    }
+
+    /// See `ZoneSync::apply`.
+    pub fn apply(&self, plan: &Plan) -> Result<SyncOutcome, DNSimpleError> {
+        self.runtime.block_on(self.sync.apply(plan))
+    }
+}
@@ -1,5 +1,9 @@
+use crate::dnsimple::ids::{AccountId, CertificateId, CertificateRenewalId, DomainId, DomainRef};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -7,9 +11,9 @@ use serde_json::Value;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Certificate {
     /// The certificate ID in DNSimple.
-    pub id: u64,
+    pub id: CertificateId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The certificate name.
     pub name: String,
     /// The certificate common name.
@@ -58,9 +62,9 @@ pub struct CertificatePrivateKey {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LetsEncryptPurchase {
     /// The id of the purchase
-    pub id: u64,
+    pub id: CertificateId,
     /// The id of the certificate
-    pub certificate_id: u64,
+    pub certificate_id: CertificateId,
     /// The state of the purchase
     pub state: String,
     /// True if the certificate will auto renew
@@ -75,11 +79,11 @@ pub struct LetsEncryptPurchase {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct LetsEncryptPurchaseRenewal {
     /// The id of the renewal
-    pub id: u64,
+    pub id: CertificateRenewalId,
     /// The previous id of the certificate
-    pub old_certificate_id: u64,
+    pub old_certificate_id: CertificateId,
     /// The id of the certificate after the renewal
-    pub new_certificate_id: u64,
+    pub new_certificate_id: CertificateId,
     /// The state of the renewal
     pub state: String,
     /// True if the certificate will auto renew
@@ -122,6 +126,20 @@ pub struct LetsEncryptPurchaseRenewalPayload {
     pub signature_algorithm: Option<LetsEncryptSignatureAlgorithm>,
 }
 
+list_options! {
+    /// The options to list the certificates of a domain
+    CertificateListOptions;
+    /// The fields the certificates can be sorted by
+    CertificateSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by common name.
+        CommonName => "common_name",
+        /// Sort by expiration date.
+        Expiration => "expiration",
+    }
+}
+
 struct ListCertificatesEndpoint;
 
 impl Endpoint for ListCertificatesEndpoint {
@@ -161,11 +179,11 @@ impl Endpoint for LetsEncryptPurchaseRenewalEndpoint {
 /// The Certificates Service handles the certificates endpoint of the DNSimple API.
 ///
 /// See [API Documentation: certificates](https://developer.dnsimple.com/v2/certificates/)
-pub struct Certificates<'a> {
-    pub client: &'a Client,
+pub struct Certificates {
+    pub client: Client,
 }
 
-impl Certificates<'_> {
+impl Certificates {
     /// List the certificates for a domain in the account.
     ///
     /// # Examples
@@ -184,14 +202,16 @@ impl Certificates<'_> {
     ///
     /// `account_id`: The id of the account
     /// `domain`: The domain name or id
-    /// `options`: The `RequestOptions`.
+    /// `options`: The `RequestOptions`, i.e. built with `CertificateListOptions`
     ///            - Sorting: `id`, `common_name`, `expiration`
     pub async fn list_certificates(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Certificate>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/certificates", account_id, domain);
 
         self.client
@@ -199,6 +219,31 @@ impl Certificates<'_> {
             .await
     }
 
+    /// Lists all the certificates of a domain, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The id of the account
+    /// `domain`: The domain name or id
+    /// `options`: The `RequestOptions`, i.e. built with `CertificateListOptions`
+    ///            - Sorting: `id`, `common_name`, `expiration`
+    pub fn list_certificates_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Certificate, DNSimpleError>> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/domains/{}/certificates", account_id, domain);
+
+        self.client
+            .paginate::<ListCertificatesEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Get the details of a certificate
     ///
     /// # Examples
@@ -220,10 +265,13 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub async fn get_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
     ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/{}",
             account_id, domain, certificate_id
@@ -253,10 +301,13 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub async fn download_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
     ) -> Result<DNSimpleResponse<CertificateBundle>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/{}/download",
             account_id, domain, certificate_id
@@ -288,10 +339,13 @@ impl Certificates<'_> {
     /// `certificate_id`: The certificate id
     pub async fn get_certificate_private_key(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
     ) -> Result<DNSimpleResponse<CertificatePrivateKey>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/{}/private_key",
             account_id, domain, certificate_id
@@ -330,10 +384,12 @@ impl Certificates<'_> {
     /// `payload`: The `LetsEncryptPurchasePayload` containing the information to purchase the certificate
     pub async fn purchase_letsencrypt_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: LetsEncryptPurchasePayload,
     ) -> Result<DNSimpleResponse<LetsEncryptPurchase>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt",
             account_id, domain
@@ -372,10 +428,13 @@ impl Certificates<'_> {
     /// `certificate_id`: The id of the certificate to be issued
     pub async fn issue_letsencrypt_certificate(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
     ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/issue",
             account_id, domain, certificate_id
@@ -411,11 +470,14 @@ impl Certificates<'_> {
     /// `payload`: The `LetsEncryptPurchaseRenewalPayload` containing the information to purchase the certificate
     pub async fn purchase_letsencrypt_certificate_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
         payload: LetsEncryptPurchaseRenewalPayload,
     ) -> Result<DNSimpleResponse<LetsEncryptPurchaseRenewal>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/renewals",
             account_id, domain, certificate_id
@@ -454,11 +516,15 @@ impl Certificates<'_> {
     /// `certificate_renewal_id`: The certificate renewal id
     pub async fn issue_letsencrypt_certificate_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        certificate_id: u64,
-        certificate_renewal_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        certificate_id: impl Into<CertificateId>,
+        certificate_renewal_id: impl Into<CertificateRenewalId>,
     ) -> Result<DNSimpleResponse<Certificate>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let certificate_id = certificate_id.into();
+        let certificate_renewal_id = certificate_renewal_id.into();
         let path = format!(
             "/{}/domains/{}/certificates/letsencrypt/{}/renewals/{}/issue",
             account_id, domain, certificate_id, certificate_renewal_id
@@ -0,0 +1,306 @@
+//! Issuance of Let's Encrypt certificates in a single call.
+//!
+//! `Certificates::obtain_letsencrypt_certificate` purchases a certificate, issues it, waits
+//! until it is issued and downloads it with its private key as a `CertificateMaterial`, which
+//! can be written to the `fullchain.pem` and `privkey.pem` files used by most servers.
+
+use crate::dnsimple::DNSimpleResponse;
+use crate::dnsimple::certificates::{Certificate, Certificates, LetsEncryptPurchasePayload};
+use crate::dnsimple::ids::{AccountId, CertificateId, DomainRef};
+use crate::errors::DNSimpleError;
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// The states in which a certificate will never be issued, or is no longer valid.
+pub(crate) const FAILED_STATES: [&str; 5] =
+    ["failed", "rejected", "cancelled", "refunded", "expired"];
+
+/// How to poll a certificate until it is issued
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::certificates_letsencrypt::IssuancePolling;
+///
+/// let polling = IssuancePolling {
+///     timeout: Duration::from_secs(120),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct IssuancePolling {
+    /// The time to wait between two checks of the certificate state (default: 5s).
+    pub interval: Duration,
+    /// The time after which to stop waiting (default: 10 minutes).
+    pub timeout: Duration,
+}
+
+impl Default for IssuancePolling {
+    fn default() -> Self {
+        IssuancePolling {
+            interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+/// An issued certificate with its chain and private key, all PEM-encoded
+pub struct CertificateMaterial {
+    /// The issued certificate.
+    pub certificate: Certificate,
+    /// The server (leaf) certificate.
+    pub leaf: String,
+    /// The intermediate certificates.
+    pub chain: Vec<String>,
+    /// The root certificate, if provided.
+    pub root: Option<String>,
+    /// The private key of the certificate.
+    pub private_key: String,
+}
+
+impl fmt::Debug for CertificateMaterial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CertificateMaterial")
+            .field("certificate", &self.certificate)
+            .field("leaf", &self.leaf)
+            .field("chain", &self.chain)
+            .field("root", &self.root)
+            .field("private_key", &"[REDACTED]")
+            .finish()
+    }
+}
+
+impl CertificateMaterial {
+    /// Returns the leaf certificate followed by the intermediate certificates.
+    pub fn fullchain(&self) -> String {
+        std::iter::once(&self.leaf)
+            .chain(&self.chain)
+            .map(|pem| pem.trim_end())
+            .filter(|pem| !pem.is_empty())
+            .fold(String::new(), |mut fullchain, pem| {
+                fullchain.push_str(pem);
+                fullchain.push('\n');
+                fullchain
+            })
+    }
+
+    /// Writes the full chain to `path`, readable by everyone (mode `0644` on Unix).
+    ///
+    /// The file is written to a temporary file in the same directory, then renamed, so
+    /// `path` never contains a partial certificate.
+    pub fn write_fullchain(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        write_atomically(path.as_ref(), self.fullchain().as_bytes(), 0o644)
+    }
+
+    /// Writes the private key to `path`, only readable by its owner (mode `0600` on Unix).
+    ///
+    /// The file is written to a temporary file in the same directory, then renamed, so
+    /// `path` never contains a partial key and is never readable by other users.
+    pub fn write_private_key(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut private_key = String::from(self.private_key.trim_end());
+        private_key.push('\n');
+        write_atomically(path.as_ref(), private_key.as_bytes(), 0o600)
+    }
+
+    /// Writes `fullchain.pem` and `privkey.pem` in the directory.
+    ///
+    /// See `write_fullchain` and `write_private_key`.
+    pub fn write_pem_files(&self, directory: impl AsRef<Path>) -> io::Result<()> {
+        let directory = directory.as_ref();
+        self.write_private_key(directory.join("privkey.pem"))?;
+        self.write_fullchain(directory.join("fullchain.pem"))
+    }
+}
+
+/// Writes the contents to a temporary file next to `path`, created with the given mode, and
+/// renames it to `path`.
+fn write_atomically(path: &Path, contents: &[u8], mode: u32) -> io::Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "the path has no file name"))?;
+    let mut temporary_name = std::ffi::OsString::from(".");
+    temporary_name.push(file_name);
+    temporary_name.push(format!(".{}.tmp", std::process::id()));
+    let temporary = path.with_file_name(temporary_name);
+
+    match fs::remove_file(&temporary) {
+        Err(error) if error.kind() != io::ErrorKind::NotFound => return Err(error),
+        _ => {}
+    }
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(mode);
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+
+    let result = options.open(&temporary).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()?;
+        fs::rename(&temporary, path)
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(&temporary);
+    }
+    result
+}
+
+/// Returns the data of the response, or an error naming what is missing.
+fn data<T>(response: DNSimpleResponse<T>, what: &str) -> Result<T, DNSimpleError> {
+    response
+        .data
+        .ok_or_else(|| DNSimpleError::Deserialization(format!("Missing {} in the response", what)))
+}
+
+/// The error of a certificate that failed to be issued, or is not issued after `elapsed`.
+fn issuance_error(
+    certificate_id: CertificateId,
+    state: &str,
+    elapsed: Option<Duration>,
+) -> DNSimpleError {
+    DNSimpleError::CertificateIssuance(match elapsed {
+        Some(elapsed) => format!(
+            "certificate {} is still {} after {}s",
+            certificate_id,
+            state,
+            elapsed.as_secs()
+        ),
+        None => format!("certificate {} is {}", certificate_id, state),
+    })
+}
+
+impl Certificates {
+    /// Purchases, issues and downloads a Let's Encrypt certificate
+    ///
+    /// Purchases the certificate, requests its issuance, polls it until its state is `issued`,
+    /// then downloads it with its private key.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::certificates::LetsEncryptPurchasePayload;
+    /// use dnsimple::dnsimple::certificates_letsencrypt::IssuancePolling;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let payload = LetsEncryptPurchasePayload {
+    ///         auto_renew: true,
+    ///         name: String::from("www"),
+    ///         alternate_names: vec![],
+    ///         signature_algorithm: None,
+    ///     };
+    ///     let material = client
+    ///         .certificates()
+    ///         .obtain_letsencrypt_certificate(1010, "example.com", payload, &IssuancePolling::default())
+    ///         .await
+    ///         .unwrap();
+    ///
+    ///     material.write_pem_files("/etc/ssl/example.com").unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The id of the account
+    /// `domain`: The domain name or id
+    /// `payload`: The `LetsEncryptPurchasePayload` containing the information to purchase the certificate
+    /// `polling`: The interval and timeout of the checks of the certificate state
+    ///
+    /// # Errors
+    ///
+    /// The error of any of the calls to the API, or `DNSimpleError::CertificateIssuance` if
+    /// the issuance failed or the certificate is not issued before the timeout.
+    pub async fn obtain_letsencrypt_certificate(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        payload: LetsEncryptPurchasePayload,
+        polling: &IssuancePolling,
+    ) -> Result<CertificateMaterial, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+
+        let purchase = self
+            .purchase_letsencrypt_certificate(account_id, domain.clone(), payload)
+            .await?;
+        let certificate_id = data(purchase, "purchase")?.certificate_id;
+        let issued = self
+            .issue_letsencrypt_certificate(account_id, domain.clone(), certificate_id)
+            .await?;
+        let certificate = self
+            .wait_for_issuance(account_id, &domain, data(issued, "certificate")?, polling)
+            .await?;
+
+        self.download_certificate_material(account_id, domain, certificate)
+            .await
+    }
+
+    /// Polls the certificate until it is issued.
+    async fn wait_for_issuance(
+        &self,
+        account_id: AccountId,
+        domain: &DomainRef,
+        mut certificate: Certificate,
+        polling: &IssuancePolling,
+    ) -> Result<Certificate, DNSimpleError> {
+        let start = Instant::now();
+
+        loop {
+            if certificate.state == "issued" {
+                return Ok(certificate);
+            }
+            if FAILED_STATES.contains(&certificate.state.as_str()) {
+                return Err(issuance_error(certificate.id, &certificate.state, None));
+            }
+            let elapsed = start.elapsed();
+            if elapsed >= polling.timeout {
+                return Err(issuance_error(
+                    certificate.id,
+                    &certificate.state,
+                    Some(elapsed),
+                ));
+            }
+
+            tokio::time::sleep(polling.interval.min(polling.timeout - elapsed)).await;
+            let response = self
+                .get_certificate(account_id, domain.clone(), certificate.id)
+                .await?;
+            certificate = data(response, "certificate")?;
+        }
+    }
+
+    /// Downloads the certificate bundle and the private key of an issued certificate.
+    async fn download_certificate_material(
+        &self,
+        account_id: AccountId,
+        domain: DomainRef,
+        certificate: Certificate,
+    ) -> Result<CertificateMaterial, DNSimpleError> {
+        let bundle = self
+            .download_certificate(account_id, domain.clone(), certificate.id)
+            .await?;
+        let bundle = data(bundle, "certificate bundle")?;
+        let private_key = self
+            .get_certificate_private_key(account_id, domain, certificate.id)
+            .await?;
+        let private_key = data(private_key, "private key")?.private_key;
+
+        Ok(CertificateMaterial {
+            certificate,
+            leaf: bundle.server,
+            chain: bundle.chain,
+            root: bundle.root,
+            private_key,
+        })
+    }
+}
@@ -0,0 +1,418 @@
+//! Renewal of the certificates about to expire.
+//!
+//! A `RenewalManager` walks the certificates of every domain of an account, finds the ones
+//! expiring within a window and renews the Let's Encrypt certificates that are not renewed
+//! automatically, reporting what it did for each of them in a `RenewalReport`.
+
+use crate::dnsimple::Client;
+use crate::dnsimple::certificates::{
+    Certificate, Certificates, LetsEncryptPurchaseRenewal, LetsEncryptPurchaseRenewalPayload,
+};
+use crate::dnsimple::certificates_letsencrypt::FAILED_STATES;
+use crate::dnsimple::domains::Domain;
+use crate::dnsimple::ids::{AccountId, CertificateId};
+use crate::errors::DNSimpleError;
+use futures_util::TryStreamExt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the `RenewalManager` did with a certificate expiring within the window
+#[derive(Debug)]
+pub enum RenewalAction {
+    /// The renewal was purchased and its issuance requested.
+    Renewed {
+        /// The renewal purchase.
+        renewal: LetsEncryptPurchaseRenewal,
+        /// The new certificate, being issued.
+        certificate: Box<Certificate>,
+    },
+    /// The certificate would be renewed, but the manager is in dry-run mode.
+    WouldRenew,
+    /// The certificate is renewed automatically by DNSimple.
+    AutoRenewing,
+    /// The certificate is not a Let's Encrypt certificate and must be renewed manually.
+    NotLetsEncrypt,
+    /// The certificate was already renewed by the given newer certificate.
+    Superseded(CertificateId),
+    /// The renewal failed.
+    Failed(DNSimpleError),
+    /// The certificate is issued but its expiration is missing or can't be parsed, so it
+    /// can't be checked.
+    UnknownExpiration,
+}
+
+/// A certificate expiring within the window (or with an unknown expiration), and what was
+/// done with it
+#[derive(Debug)]
+pub struct RenewalEntry {
+    /// The name of the domain of the certificate.
+    pub domain: String,
+    /// The expiring certificate.
+    pub certificate: Certificate,
+    /// When the certificate expires, unless the action is `RenewalAction::UnknownExpiration`.
+    pub expires_at: Option<SystemTime>,
+    /// What was done with the certificate.
+    pub action: RenewalAction,
+}
+
+/// The result of `RenewalManager::run`
+#[derive(Debug, Default)]
+pub struct RenewalReport {
+    /// The number of certificates checked, in all the domains.
+    pub checked: usize,
+    /// The certificates expiring within the window, and the ones whose expiration is unknown.
+    pub entries: Vec<RenewalEntry>,
+}
+
+impl RenewalReport {
+    /// Returns the certificates renewed.
+    pub fn renewed(&self) -> impl Iterator<Item = &RenewalEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.action, RenewalAction::Renewed { .. }))
+    }
+
+    /// Returns the certificates whose renewal failed.
+    pub fn failed(&self) -> impl Iterator<Item = &RenewalEntry> {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry.action, RenewalAction::Failed(_)))
+    }
+}
+
+/// Renews the Let's Encrypt certificates of an account about to expire
+///
+/// The certificates set to auto-renew are left to DNSimple, and a certificate for which a
+/// newer certificate with the same common name exists is considered already renewed. The
+/// issued certificates whose expiration is missing or invalid are reported as
+/// `RenewalAction::UnknownExpiration`, as they can't be checked.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::new_client;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+///     let report = client
+///         .certificates()
+///         .renewal_manager(1234)
+///         .window(Duration::from_secs(14 * 24 * 3600))
+///         .run()
+///         .await
+///         .unwrap();
+///
+///     for entry in report.renewed() {
+///         println!("renewed {} ({})", entry.certificate.common_name, entry.domain);
+///     }
+///     for entry in report.failed() {
+///         println!("failed to renew {}: {:?}", entry.certificate.common_name, entry.action);
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct RenewalManager {
+    client: Client,
+    account_id: AccountId,
+    window: Duration,
+    dry_run: bool,
+    auto_renew: bool,
+}
+
+impl RenewalManager {
+    /// Creates the manager of the certificates of the account, renewing the certificates
+    /// expiring within 30 days.
+    ///
+    /// # Arguments
+    ///
+    /// `client`: The client to call the API with
+    /// `account_id`: The account ID
+    pub fn new(client: Client, account_id: impl Into<AccountId>) -> Self {
+        RenewalManager {
+            client,
+            account_id: account_id.into(),
+            window: Duration::from_secs(30 * 24 * 3600),
+            dry_run: false,
+            auto_renew: false,
+        }
+    }
+
+    /// Renews the certificates expiring within `window` (the expired ones included).
+    pub fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+        self
+    }
+
+    /// In dry-run mode, `run` reports the certificates to renew without renewing them.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Enables the auto-renewal of the renewed certificates, so they don't need to be
+    /// renewed again.
+    pub fn auto_renew(mut self, auto_renew: bool) -> Self {
+        self.auto_renew = auto_renew;
+        self
+    }
+
+    /// Checks the certificates of all the domains and renews the ones expiring within the window.
+    ///
+    /// # Errors
+    ///
+    /// The error of listing the domains or the certificates. The errors of the renewals are
+    /// reported in the `RenewalReport` as `RenewalAction::Failed`.
+    pub async fn run(&self) -> Result<RenewalReport, DNSimpleError> {
+        self.run_at(SystemTime::now()).await
+    }
+
+    /// Same as `run`, with the window starting at `now` instead of the current time.
+    ///
+    /// # Arguments
+    ///
+    /// `now`: The start of the window
+    pub async fn run_at(&self, now: SystemTime) -> Result<RenewalReport, DNSimpleError> {
+        let deadline = now + self.window;
+        let certificates = self.client.certificates();
+        let mut domains = self
+            .client
+            .domains()
+            .list_domains_stream(self.account_id, None);
+        let mut report = RenewalReport::default();
+
+        while let Some(domain) = domains.try_next().await? {
+            let domain_certificates: Vec<Certificate> = certificates
+                .list_certificates_stream(self.account_id, domain.id, None)
+                .try_collect()
+                .await?;
+            report.checked += domain_certificates.len();
+
+            let superseded: Vec<Option<CertificateId>> = domain_certificates
+                .iter()
+                .map(|certificate| superseded_by(certificate, &domain_certificates))
+                .collect();
+            for (certificate, superseded) in domain_certificates.into_iter().zip(superseded) {
+                let expires_at = expiration(&certificate);
+                let action = match expires_at {
+                    Some(expires_at) if expires_at > deadline => continue,
+                    Some(_) => self.expiring(&domain, &certificate, superseded).await,
+                    None if has_expiration(&certificate) => RenewalAction::UnknownExpiration,
+                    // Not issued yet, so it doesn't expire.
+                    None => continue,
+                };
+                report.entries.push(RenewalEntry {
+                    domain: domain.name.clone(),
+                    certificate,
+                    expires_at,
+                    action,
+                });
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Renews the expiring certificate, unless it doesn't need to be renewed by the manager.
+    async fn expiring(
+        &self,
+        domain: &Domain,
+        certificate: &Certificate,
+        superseded: Option<CertificateId>,
+    ) -> RenewalAction {
+        if certificate.auto_renew {
+            RenewalAction::AutoRenewing
+        } else if certificate.authority_identifier != "letsencrypt" {
+            RenewalAction::NotLetsEncrypt
+        } else if let Some(newer) = superseded {
+            RenewalAction::Superseded(newer)
+        } else if self.dry_run {
+            RenewalAction::WouldRenew
+        } else {
+            self.renew(domain, certificate).await
+        }
+    }
+
+    /// Purchases the renewal of the certificate and requests its issuance.
+    async fn renew(&self, domain: &Domain, certificate: &Certificate) -> RenewalAction {
+        let certificates = self.client.certificates();
+        let payload = LetsEncryptPurchaseRenewalPayload {
+            auto_renew: self.auto_renew,
+            signature_algorithm: None,
+        };
+        let renewal = match certificates
+            .purchase_letsencrypt_certificate_renewal(
+                self.account_id,
+                domain.id,
+                certificate.id,
+                payload,
+            )
+            .await
+        {
+            Ok(response) => match response.data {
+                Some(renewal) => renewal,
+                None => return missing("renewal"),
+            },
+            Err(error) => return RenewalAction::Failed(error),
+        };
+        match certificates
+            .issue_letsencrypt_certificate_renewal(
+                self.account_id,
+                domain.id,
+                certificate.id,
+                renewal.id,
+            )
+            .await
+        {
+            Ok(response) => match response.data {
+                Some(certificate) => RenewalAction::Renewed {
+                    renewal,
+                    certificate: Box::new(certificate),
+                },
+                None => missing("certificate"),
+            },
+            Err(error) => RenewalAction::Failed(error),
+        }
+    }
+}
+
+fn missing(what: &str) -> RenewalAction {
+    RenewalAction::Failed(DNSimpleError::Deserialization(format!(
+        "Missing {} in the response",
+        what
+    )))
+}
+
+/// Returns the newest certificate of the domain with the same common name, created after
+/// the certificate and not failed nor expired, if any.
+fn superseded_by(certificate: &Certificate, all: &[Certificate]) -> Option<CertificateId> {
+    all.iter()
+        .filter(|other| {
+            other.id != certificate.id
+                && other.common_name == certificate.common_name
+                && other.created_at > certificate.created_at
+                && !FAILED_STATES.contains(&other.state.as_str())
+        })
+        .max_by(|a, b| a.created_at.cmp(&b.created_at))
+        .map(|newer| newer.id)
+}
+
+/// Returns whether the certificate should have an expiration: it is issued, or one of
+/// `expires_at` and `expires_on` is set.
+fn has_expiration(certificate: &Certificate) -> bool {
+    certificate.state == "issued"
+        || certificate.expires_at.is_some()
+        || certificate.expires_on.is_some()
+}
+
+/// Returns when the certificate expires, from `expires_at` or else `expires_on`.
+fn expiration(certificate: &Certificate) -> Option<SystemTime> {
+    match (&certificate.expires_at, &certificate.expires_on) {
+        (Some(expires_at), _) => parse_timestamp(expires_at),
+        (None, Some(expires_on)) => parse_timestamp(&format!("{}T00:00:00Z", expires_on)),
+        (None, None) => None,
+    }
+}
+
+/// Parses a UTC timestamp of the API (i.e. `2020-09-16T19:30:07Z`), ignoring fractions of seconds.
+fn parse_timestamp(timestamp: &str) -> Option<SystemTime> {
+    let (date, time) = timestamp.strip_suffix('Z')?.split_once('T')?;
+    let date: Vec<i64> = date
+        .split('-')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let time = time.split('.').next()?;
+    let time: Vec<i64> = time
+        .split(':')
+        .map(|part| part.parse().ok())
+        .collect::<Option<_>>()?;
+    let ([year, month, day], [hours, minutes, seconds]) = (
+        <[i64; 3]>::try_from(date).ok()?,
+        <[i64; 3]>::try_from(time).ok()?,
+    );
+    if !(1..=12).contains(&month)
+        || !(1..=31).contains(&day)
+        || !(0..24).contains(&hours)
+        || !(0..60).contains(&minutes)
+        || !(0..=60).contains(&seconds)
+    {
+        return None;
+    }
+
+    // Days since the epoch of the proleptic Gregorian date (Howard Hinnant's `days_from_civil`).
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+
+    let seconds = days * 86400 + hours * 3600 + minutes * 60 + seconds;
+    u64::try_from(seconds)
+        .ok()
+        .map(|seconds| UNIX_EPOCH + Duration::from_secs(seconds))
+}
+
+impl Certificates {
+    /// Creates a `RenewalManager` to renew the certificates of the account about to expire
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    pub fn renewal_manager(&self, account_id: impl Into<AccountId>) -> RenewalManager {
+        RenewalManager::new(self.client.clone(), account_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn certificate(id: u64, state: &str, created_at: &str) -> Certificate {
+        Certificate {
+            id: CertificateId(id),
+            domain_id: 1.into(),
+            name: String::from("www"),
+            common_name: String::from("www.example.com"),
+            years: 1,
+            csr: None,
+            state: String::from(state),
+            auto_renew: false,
+            alternate_names: vec![],
+            authority_identifier: String::from("letsencrypt"),
+            created_at: String::from(created_at),
+            updated_at: String::from(created_at),
+            expires_at: None,
+            expires_on: None,
+        }
+    }
+
+    #[test]
+    fn superseded_by_test() {
+        let all = [
+            certificate(1, "issued", "2020-06-18T20:15:09Z"),
+            certificate(2, "issued", "2020-08-18T20:15:09Z"),
+            certificate(3, "requesting", "2020-08-20T20:15:09Z"),
+            certificate(4, "expired", "2020-08-21T20:15:09Z"),
+            certificate(5, "failed", "2020-08-22T20:15:09Z"),
+        ];
+
+        assert_eq!(Some(CertificateId(3)), superseded_by(&all[0], &all));
+        assert_eq!(Some(CertificateId(3)), superseded_by(&all[1], &all));
+        assert_eq!(None, superseded_by(&all[2], &all));
+        assert_eq!(None, superseded_by(&all[0], &all[3..]));
+    }
+
+    #[test]
+    fn parse_timestamp_test() {
+        let at = |seconds| Some(UNIX_EPOCH + Duration::from_secs(seconds));
+
+        assert_eq!(at(0), parse_timestamp("1970-01-01T00:00:00Z"));
+        assert_eq!(at(1600284607), parse_timestamp("2020-09-16T19:30:07Z"));
+        assert_eq!(at(1600284607), parse_timestamp("2020-09-16T19:30:07.123Z"));
+        assert_eq!(at(951782400), parse_timestamp("2000-02-29T00:00:00Z"));
+        assert_eq!(None, parse_timestamp("2020-09-16"));
+        assert_eq!(None, parse_timestamp("2020-13-16T19:30:07Z"));
+        assert_eq!(None, parse_timestamp("1969-12-31T23:59:59Z"));
+    }
+}
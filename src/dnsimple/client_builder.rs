@@ -0,0 +1,241 @@
+use crate::dnsimple::middleware::{Middleware, Middlewares};
+use crate::dnsimple::retry::RetryPolicy;
+use crate::dnsimple::{
+    Client, ClientInner, DEFAULT_BASE_URL, DEFAULT_SANDBOX_URL, DEFAULT_USER_AGENT, VERSION,
+};
+use crate::errors::DNSimpleError;
+use reqwest::header::{ACCEPT, AUTHORIZATION, HeaderMap, USER_AGENT};
+use reqwest::{Certificate, Proxy};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// The DNSimple environment the client sends the requests to
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Environment {
+    /// The production environment (`https://api.dnsimple.com`).
+    #[default]
+    Production,
+    /// The [sandbox environment](https://developer.dnsimple.com/sandbox/) (`https://api.sandbox.dnsimple.com`).
+    Sandbox,
+    /// A custom base url (without the API version), i.e. `https://dnsimple.example.com`.
+    Custom(String),
+}
+
+impl Environment {
+    /// Returns the base url of the environment.
+    pub fn base_url(&self) -> &str {
+        match self {
+            Environment::Production => DEFAULT_BASE_URL,
+            Environment::Sandbox => DEFAULT_SANDBOX_URL,
+            Environment::Custom(url) => url.trim_end_matches('/'),
+        }
+    }
+}
+
+/// Builds a `Client` with a custom configuration
+///
+/// Use `new_client` for the default configuration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::Client;
+/// use dnsimple::dnsimple::client_builder::Environment;
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .environment(Environment::Sandbox)
+///     .connect_timeout(Duration::from_secs(5))
+///     .timeout(Duration::from_secs(30))
+///     .user_agent("my-app/1.0")
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ClientBuilder {
+    token: String,
+    environment: Environment,
+    user_agent: Option<String>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    built_in_root_certificates: bool,
+    default_headers: HeaderMap,
+    http_client: Option<reqwest::Client>,
+    retry_policy: Option<RetryPolicy>,
+    middlewares: Middlewares,
+}
+
+impl ClientBuilder {
+    /// Creates a builder for a client of the production environment.
+    ///
+    /// # Arguments
+    ///
+    /// `token`: the bearer authentication token
+    pub fn new(token: String) -> ClientBuilder {
+        ClientBuilder {
+            token,
+            environment: Environment::Production,
+            user_agent: None,
+            connect_timeout: None,
+            timeout: None,
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            built_in_root_certificates: true,
+            default_headers: HeaderMap::new(),
+            http_client: None,
+            retry_policy: None,
+            middlewares: Middlewares::default(),
+        }
+    }
+
+    /// Sets the environment the client sends the requests to (default: production).
+    pub fn environment(mut self, environment: Environment) -> ClientBuilder {
+        self.environment = environment;
+        self
+    }
+
+    /// Sets a custom token to be prepended to the default `User-Agent` header.
+    ///
+    /// See `Client::set_user_agent`.
+    pub fn user_agent(mut self, custom_user_agent: &str) -> ClientBuilder {
+        self.user_agent = Some(String::from(custom_user_agent));
+        self
+    }
+
+    /// Sets the timeout to establish a connection to the API.
+    ///
+    /// It cannot be combined with `http_client`.
+    pub fn connect_timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout of each request, from the moment it starts connecting until
+    /// the response body has been received.
+    pub fn timeout(mut self, timeout: Duration) -> ClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a proxy to send the requests through.
+    ///
+    /// It cannot be combined with `http_client`.
+    pub fn proxy(mut self, proxy: Proxy) -> ClientBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Adds a trusted root certificate, i.e. the certificate of a corporate proxy.
+    ///
+    /// It cannot be combined with `http_client`.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> ClientBuilder {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Whether to trust the built-in root certificates (default: true). When disabled,
+    /// only the certificates added with `add_root_certificate` are trusted.
+    ///
+    /// It cannot be combined with `http_client`.
+    pub fn built_in_root_certificates(mut self, enabled: bool) -> ClientBuilder {
+        self.built_in_root_certificates = enabled;
+        self
+    }
+
+    /// Sets the headers sent with every request. The `Authorization`, `User-Agent`
+    /// and `Accept` headers are always set by the client.
+    pub fn default_headers(mut self, headers: HeaderMap) -> ClientBuilder {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Uses a pre-built `reqwest::Client` to send the requests, i.e. to share its
+    /// connection pool between several clients.
+    ///
+    /// The connection settings (`connect_timeout`, `proxy` and the root certificates)
+    /// are those of the given client, and cannot be set on this builder.
+    pub fn http_client(mut self, client: reqwest::Client) -> ClientBuilder {
+        self.http_client = Some(client);
+        self
+    }
+
+    /// Sets the policy used to retry the requests failing with a transient error.
+    ///
+    /// See `Client::set_retry_policy`.
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> ClientBuilder {
+        self.retry_policy = Some(policy);
+        self
+    }
+
+    /// Registers a middleware called around every request sent by the client.
+    ///
+    /// See `Client::add_middleware`.
+    pub fn middleware(mut self, middleware: impl Middleware + 'static) -> ClientBuilder {
+        self.middlewares.push(Arc::new(middleware));
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// `DNSimpleError::Configuration` if connection settings are combined with `http_client`,
+    /// or `DNSimpleError::Network` if the HTTP client cannot be built.
+    pub fn build(self) -> Result<Client, DNSimpleError> {
+        let client = match self.http_client {
+            Some(client) => {
+                if self.connect_timeout.is_some()
+                    || !self.proxies.is_empty()
+                    || !self.root_certificates.is_empty()
+                    || !self.built_in_root_certificates
+                {
+                    return Err(DNSimpleError::Configuration(String::from(
+                        "The connect timeout, proxies and root certificates cannot be set when using a custom HTTP client",
+                    )));
+                }
+                client
+            }
+            None => {
+                let mut builder = reqwest::Client::builder();
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                builder = match self.built_in_root_certificates {
+                    true if self.root_certificates.is_empty() => builder,
+                    true => builder.tls_certs_merge(self.root_certificates),
+                    false => builder.tls_certs_only(self.root_certificates),
+                };
+                builder.build().map_err(DNSimpleError::from_reqwest)?
+            }
+        };
+
+        let mut default_headers = self.default_headers;
+        for header in [ACCEPT, AUTHORIZATION, USER_AGENT] {
+            default_headers.remove(header);
+        }
+
+        let mut client = Client {
+            inner: Arc::new(ClientInner {
+                base_url: String::from(self.environment.base_url()),
+                user_agent: DEFAULT_USER_AGENT.to_owned() + VERSION,
+                auth_token: self.token,
+                client,
+                default_headers,
+                timeout: self.timeout,
+                retry_policy: self.retry_policy,
+                middlewares: self.middlewares,
+                last_rate_limit: Arc::new(Mutex::new(None)),
+            }),
+        };
+        if let Some(user_agent) = self.user_agent {
+            client.set_user_agent(&user_agent);
+        }
+
+        Ok(client)
+    }
+}
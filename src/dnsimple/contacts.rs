@@ -1,13 +1,17 @@
+use crate::dnsimple::ids::{AccountId, ContactId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Contact {
     /// The contact ID in DNSimple.
-    pub id: u64,
+    pub id: ContactId,
     /// The associated account ID.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The label to represent the contact.
     pub label: String,
     /// The contact first name.
@@ -74,6 +78,20 @@ pub struct ContactPayload {
     pub country: String,
 }
 
+list_options! {
+    /// The options to list the contacts
+    ContactListOptions;
+    /// The fields the contacts can be sorted by
+    ContactSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by label.
+        Label => "label",
+        /// Sort by email.
+        Email => "email",
+    }
+}
+
 struct ContactsEndpoint;
 
 impl Endpoint for ContactsEndpoint {
@@ -89,28 +107,51 @@ impl Endpoint for ContactEndpoint {
 /// The Contacts Service handles the contacts endpoint of the DNSimple API.
 ///
 /// See [API Documentation: contacts](https://developer.dnsimple.com/v2/contacts/)
-pub struct Contacts<'a> {
-    pub client: &'a Client,
+pub struct Contacts {
+    pub client: Client,
 }
 
-impl Contacts<'_> {
+impl Contacts {
     /// Lists the contacts in the account.
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions`, i.e. built with `ContactListOptions`
     ///            - Sort: `id`, `label`, `email`
     pub async fn list_contacts(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Contact>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/contacts", account_id);
 
         self.client.get::<ContactsEndpoint>(&path, options).await
     }
 
+    /// Lists all the contacts in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `ContactListOptions`
+    ///            - Sort: `id`, `label`, `email`
+    pub fn list_contacts_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Contact, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/contacts", account_id);
+
+        self.client
+            .paginate::<ContactsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create a contact in the account.
     ///
     /// # Arguments
@@ -119,9 +160,10 @@ impl Contacts<'_> {
     /// `payload`: The `ContactPayload` with the information needed to create the contact
     pub async fn create_contact(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: ContactPayload,
     ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/contacts", account_id);
 
         match serde_json::to_value(payload) {
@@ -140,9 +182,11 @@ impl Contacts<'_> {
     /// `contact`: The contact id
     pub async fn get_contact(
         &self,
-        account_id: u64,
-        contact: u64,
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
     ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
+        let account_id = account_id.into();
+        let contact = contact.into();
         let path = format!("/{}/contacts/{}", account_id, contact);
 
         self.client.get::<ContactEndpoint>(&path, None).await
@@ -157,10 +201,12 @@ impl Contacts<'_> {
     /// `payload`: The `ContactPayload` with the information needed to update the contact
     pub async fn update_contact(
         &self,
-        account_id: u64,
-        contact: u64,
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
         payload: ContactPayload,
     ) -> Result<DNSimpleResponse<Contact>, DNSimpleError> {
+        let account_id = account_id.into();
+        let contact = contact.into();
         let path = format!("/{}/contacts/{}", account_id, contact);
 
         match serde_json::to_value(payload) {
@@ -179,9 +225,11 @@ impl Contacts<'_> {
     /// `contact`: The contact id
     pub async fn delete_contact(
         &self,
-        account_id: u64,
-        contact: u64,
+        account_id: impl Into<AccountId>,
+        contact: impl Into<ContactId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let contact = contact.into();
         let path = format!("/{}/contacts/{}", account_id, contact);
 
         self.client.delete(&path).await
@@ -1,16 +1,20 @@
+use crate::dnsimple::ids::{AccountId, ContactId, DomainId, DomainRef};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a domain
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Domain {
     /// The domain ID in DNSimple
-    pub id: u64,
+    pub id: DomainId,
     /// The account ID holding the domain
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The registrants ID
-    pub registrant_id: Option<u64>,
+    pub registrant_id: Option<ContactId>,
     /// The name of the domain
     pub name: String,
     /// The name of the domain in unicode
@@ -40,6 +44,46 @@ pub struct DomainCreationPayload {
     pub name: String,
 }
 
+list_options! {
+    /// The options to list the domains
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::domains::{DomainListOptions, DomainSort};
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let options = DomainListOptions::new().name_like("example").sort(DomainSort::Expiration.asc());
+    ///     let domains = client.domains().list_domains(1234, options.into()).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    DomainListOptions;
+    /// The fields the domains can be sorted by
+    DomainSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by name.
+        Name => "name",
+        /// Sort by expiration date.
+        Expiration => "expiration",
+    }
+}
+
+impl DomainListOptions {
+    /// Only lists the domains whose name contains `name`.
+    pub fn name_like(self, name: &str) -> Self {
+        self.filter("name_like", name)
+    }
+
+    /// Only lists the domains registered with the `registrant_id` contact.
+    pub fn registrant_id(self, registrant_id: impl Into<ContactId>) -> Self {
+        self.filter("registrant_id", registrant_id.into())
+    }
+}
+
 struct DomainsEndpoint;
 
 impl Endpoint for DomainsEndpoint {
@@ -55,11 +99,11 @@ impl Endpoint for DomainEndpoint {
 /// The Domains Service handles the domains endpoint of the DNSimple API.
 ///
 /// See [API Documentation: domains](https://developer.dnsimple.com/v2/domains/)
-pub struct Domains<'a> {
-    pub client: &'a Client,
+pub struct Domains {
+    pub client: Client,
 }
 
-impl Domains<'_> {
+impl Domains {
     /// Lists the domains in the account
     ///
     /// # Examples
@@ -78,18 +122,59 @@ impl Domains<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions`, i.e. built with `DomainListOptions`
     ///             - Filters: `name_like`, `registrant_id`
     ///             - Sorting: `id`, `name`, `expiration`
     pub async fn list_domains(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Domain>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/domains", account_id);
         self.client.get::<DomainsEndpoint>(&path, options).await
     }
 
+    /// Lists all the domains in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use futures_util::TryStreamExt;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let mut domains = Box::pin(client.domains().list_domains_stream(1234, None));
+    ///
+    ///     while let Some(domain) = domains.try_next().await.unwrap() {
+    ///         println!("{}", domain.name);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `DomainListOptions`
+    ///             - Filters: `name_like`, `registrant_id`
+    ///             - Sorting: `id`, `name`, `expiration`
+    pub fn list_domains_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Domain, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/domains", account_id);
+
+        self.client
+            .paginate::<DomainsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Adds a domain to the account.
     ///
     /// # Examples
@@ -113,9 +198,10 @@ impl Domains<'_> {
     // pub fn create_domain(&self, account_id: u64, name: String) -> DNSimpleResponse<DomainData> {
     pub async fn create_domain(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         name: String,
     ) -> Result<DNSimpleResponse<Domain>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/domains", account_id);
 
         let payload = DomainCreationPayload { name };
@@ -134,26 +220,29 @@ impl Domains<'_> {
     ///
     /// ```no_run
     /// use dnsimple::dnsimple::{Client, new_client};
-    /// use dnsimple::dnsimple::domains::DomainCreationPayload;
+    /// use dnsimple::dnsimple::ids::DomainId;
     ///
     /// #[tokio::main(flavor = "current_thread")]
     /// async fn main() {
     ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
-    ///     let domains_response = client.domains().get_domain(1234, 42).await;
+    ///     let domains_response = client.domains().get_domain(1234, DomainId(42)).await;
+    ///     let domains_response = client.domains().get_domain(1234, "example.com").await;
     /// }
     /// ```
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to retrieve
+    /// `domain`: The ID or name of the domain we want to retrieve
     // pub fn get_domain(&self, account_id: u64, domain_id: u64) -> DNSimpleResponse<DomainData> {
     pub async fn get_domain(
         &self,
-        account_id: u64,
-        domain_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<Domain>, DNSimpleError> {
-        let path = format!("/{}/domains/{}", account_id, domain_id);
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/domains/{}", account_id, domain);
         self.client.get::<DomainEndpoint>(&path, None).await
     }
 
@@ -163,25 +252,28 @@ impl Domains<'_> {
     ///
     /// ```no_run
     /// use dnsimple::dnsimple::{Client, new_client};
-    /// use dnsimple::dnsimple::domains::DomainCreationPayload;
+    /// use dnsimple::dnsimple::ids::DomainId;
     ///
     /// #[tokio::main(flavor = "current_thread")]
     /// async fn main() {
     ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
-    ///     let domains_response = client.domains().delete_domain(1234, 42).await;
+    ///     let domains_response = client.domains().delete_domain(1234, DomainId(42)).await;
+    ///     let domains_response = client.domains().delete_domain(1234, "example.com").await;
     /// }
     /// ```
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `domain_id`: The ID of the domain we want to permanently delete
+    /// `domain`: The ID or name of the domain we want to permanently delete
     pub async fn delete_domain(
         &self,
-        account_id: u64,
-        domain_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
-        let path = format!("/{}/domains/{}", account_id, domain_id);
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/domains/{}", account_id, domain);
 
         self.client.delete(&path).await
     }
@@ -1,4 +1,5 @@
 use crate::dnsimple::domains::Domains;
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
 use serde::{Deserialize, Serialize};
@@ -24,7 +25,7 @@ impl Endpoint for DnssecStatusEndpoint {
 /// The domains dnssec set of endpoints
 ///
 /// See [API Documentation: domains/dnssec](https://developer.dnsimple.com/v2/domains/dnssec)
-impl Domains<'_> {
+impl Domains {
     /// Enable DNSSEC for the domain in the account. This will sign the zone. If the domain is
     /// registered it will also add the DS record to the corresponding registry.
     ///
@@ -46,9 +47,11 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want to enable DNSSEC on
     pub async fn enable_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/dnssec", account_id, domain);
 
         self.client
@@ -76,9 +79,11 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want to disable DNSSEC on
     pub async fn disable_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/dnssec", account_id, domain);
 
         self.client.delete(&path).await
@@ -104,9 +109,11 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want retrieve the DNSSEC status from
     pub async fn get_dnssec(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<Dnssec>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/dnssec", account_id, domain);
 
         self.client.get::<DnssecStatusEndpoint>(&path, None).await
@@ -1,6 +1,10 @@
 use crate::dnsimple::domains::Domains;
+use crate::dnsimple::ids::{AccountId, DomainId, DomainRef, EmailForwardId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 struct EmailForwardsListEndpoint;
@@ -13,9 +17,9 @@ impl Endpoint for EmailForwardsListEndpoint {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EmailForward {
     /// The email forward ID in DNSimple.
-    pub id: u64,
+    pub id: EmailForwardId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The email alias
     pub alias_email: String,
     /// The destination email
@@ -32,9 +36,9 @@ pub struct EmailForward {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct EmailForwardsInList {
     /// The email forward ID in DNSimple.
-    pub id: u64,
+    pub id: EmailForwardId,
     /// The domain id
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The "local part" of the originating email address. Anything to the left of the @ symbol.
     pub alias_email: String,
     /// The full email address to forward to.
@@ -56,6 +60,20 @@ pub struct EmailForwardPayload {
     pub destination_email: String,
 }
 
+list_options! {
+    /// The options to list the email forwards of a domain
+    EmailForwardListOptions;
+    /// The fields the email forwards can be sorted by
+    EmailForwardSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by the email address forwarded.
+        From => "from",
+        /// Sort by the destination email address.
+        To => "to",
+    }
+}
+
 struct EmailForwardEndpoint;
 
 impl Endpoint for EmailForwardEndpoint {
@@ -65,7 +83,7 @@ impl Endpoint for EmailForwardEndpoint {
 /// The domains email forwards set of endpoints
 ///
 /// See [API Documentation: domains/email-forwards](https://developer.dnsimple.com/v2/domains/email-forwards)
-impl Domains<'_> {
+impl Domains {
     /// List email forwards for the domain in the account.
     ///
     /// # Examples
@@ -84,15 +102,17 @@ impl Domains<'_> {
     ///
     /// `account_id`: The account ID
     /// `domain`: The ID or name of the domain we want list the email forwards
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions`, i.e. built with `EmailForwardListOptions`
     ///            - Sort: `id`, `from`, `to`
     ///            - Pagination
     pub async fn list_email_forwards(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<EmailForwardsInList>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/email_forwards", account_id, domain);
 
         self.client
@@ -100,6 +120,32 @@ impl Domains<'_> {
             .await
     }
 
+    /// Lists all the email forwards of a domain, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `domain`: The ID or name of the domain we want list the email forwards
+    /// `options`: The `RequestOptions`, i.e. built with `EmailForwardListOptions`
+    ///            - Sort: `id`, `from`, `to`
+    ///            - Pagination
+    pub fn list_email_forwards_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<EmailForwardsInList, DNSimpleError>> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/domains/{}/email_forwards", account_id, domain);
+
+        self.client
+            .paginate::<EmailForwardsListEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create an email forward
     ///
     /// # Examples
@@ -126,10 +172,12 @@ impl Domains<'_> {
     /// `payload`: The `EmailForwardPayload` with the data needed to create the email forward
     pub async fn create_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: EmailForwardPayload,
     ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/email_forwards", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -161,10 +209,13 @@ impl Domains<'_> {
     /// `email_forward`: The email forward id
     pub async fn get_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
-        email_forward: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
     ) -> Result<DNSimpleResponse<EmailForward>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let email_forward = email_forward.into();
         let path = format!(
             "/{}/domains/{}/email_forwards/{}",
             account_id, domain, email_forward
@@ -194,10 +245,13 @@ impl Domains<'_> {
     /// `email_forward`: The email forward id
     pub async fn delete_email_forward(
         &self,
-        account_id: u64,
-        domain: &str,
-        email_forward: i32,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        email_forward: impl Into<EmailForwardId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let email_forward = email_forward.into();
         let path = format!(
             "/{}/domains/{}/email_forwards/{}",
             account_id, domain, email_forward
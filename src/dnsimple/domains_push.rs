@@ -1,6 +1,9 @@
 use crate::dnsimple::domains::Domains;
+use crate::dnsimple::ids::{AccountId, ContactId, DomainId, DomainPushId, DomainRef};
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 struct DomainPushesListEndpoint;
@@ -19,13 +22,13 @@ impl Endpoint for DomainPushEndpoint {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainPush {
     /// The domain push ID in DNSimple.
-    pub id: u64,
+    pub id: DomainPushId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The associated contact ID.
-    pub contact_id: Option<u64>,
+    pub contact_id: Option<ContactId>,
     /// The associated account ID.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// When the domain push was created in DNSimple.
     pub created_at: String,
     /// When the domain push was last updated in DNSimple.
@@ -48,7 +51,7 @@ pub struct InitiatePushPayload {
 /// The domains push set of endpoints
 ///
 /// See [API Documentation: domains/pushes](https://developer.dnsimple.com/v2/domains/pushes)
-impl Domains<'_> {
+impl Domains {
     /// Initiate a push
     ///
     /// # Examples
@@ -75,10 +78,12 @@ impl Domains<'_> {
     /// `payload`: The `InitiatePushPayload` used to initiate a push
     pub async fn initiate_push(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: InitiatePushPayload,
     ) -> Result<DNSimpleResponse<DomainPush>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/pushes", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -110,9 +115,10 @@ impl Domains<'_> {
     ///            - Pagination
     pub async fn list_pushes(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<DomainPush>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/domains/pushes", account_id);
 
         self.client
@@ -120,6 +126,28 @@ impl Domains<'_> {
             .await
     }
 
+    /// Lists all the pending pushes for the target account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `options`: The `RequestOptions`
+    ///            - Pagination
+    pub fn list_pushes_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<DomainPush, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/domains/pushes", account_id);
+
+        self.client
+            .paginate::<DomainPushesListEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Accept a push
     ///
     /// # Examples
@@ -140,9 +168,11 @@ impl Domains<'_> {
     /// `push_id`: The push id
     pub async fn accept_push(
         &self,
-        account_id: u64,
-        push_id: u64,
+        account_id: impl Into<AccountId>,
+        push_id: impl Into<DomainPushId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let push_id = push_id.into();
         let path = format!("/{}/domains/pushes/{}", account_id, push_id);
 
         self.client.empty_post(&path).await
@@ -168,9 +198,11 @@ impl Domains<'_> {
     /// `push_id`: The push id
     pub async fn reject_push(
         &self,
-        account_id: u64,
-        push_id: u64,
+        account_id: impl Into<AccountId>,
+        push_id: impl Into<DomainPushId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let push_id = push_id.into();
         let path = format!("/{}/domains/pushes/{}", account_id, push_id);
 
         self.client.delete(&path).await
@@ -1,4 +1,5 @@
 use crate::dnsimple::domains::Domains;
+use crate::dnsimple::ids::AccountId;
 use crate::dnsimple::{DNSimpleResponse, Endpoint, Filters, RequestOptions};
 use crate::errors::DNSimpleError;
 use serde::{Deserialize, Serialize};
@@ -25,7 +26,7 @@ impl Endpoint for DomainResearchStatusEndpoint {
 /// The domains research set of endpoints
 ///
 /// See [API Documentation: domains/research](https://developer.dnsimple.com/v2/domains/research)
-impl Domains<'_> {
+impl Domains {
     /// Research a domain name for availability and registration status information.
     ///
     /// This endpoint provides information about a domain's availability status.
@@ -48,9 +49,10 @@ impl Domains<'_> {
     /// `domain`: The domain name to research
     pub async fn get_domain_research_status(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         domain: String,
     ) -> Result<DNSimpleResponse<DomainResearchStatus>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/domains/research/status", account_id);
         let mut filter_map = std::collections::HashMap::new();
         filter_map.insert("domain".to_string(), domain);
@@ -1,15 +1,19 @@
 use crate::dnsimple::domains::Domains;
+use crate::dnsimple::ids::{AccountId, DelegationSignerRecordId, DomainId, DomainRef};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a delegation signer record
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DelegationSignerRecord {
     /// The ID of the delegation signer record in DNSimple.
-    pub id: u64,
+    pub id: DelegationSignerRecordId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The signing algorithm used.
     pub algorithm: String,
     /// The digest value.
@@ -47,6 +51,18 @@ pub struct DelegationSignerRecordPayload {
     pub public_key: Option<String>,
 }
 
+list_options! {
+    /// The options to list the delegation signer records of a domain
+    DelegationSignerRecordListOptions;
+    /// The fields the delegation signer records can be sorted by
+    DelegationSignerRecordSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by creation date.
+        CreatedAt => "created_at",
+    }
+}
+
 struct SignerRecordEndpoint;
 
 impl Endpoint for SignerRecordEndpoint {
@@ -56,7 +72,7 @@ impl Endpoint for SignerRecordEndpoint {
 /// The domains signer records set of endpoints
 ///
 /// See [API Documentation: domains/dnssec](https://developer.dnsimple.com/v2/domains/dnssec)
-impl Domains<'_> {
+impl Domains {
     /// List delegation signer records for the domain in the account.
     ///
     /// # Examples
@@ -75,15 +91,17 @@ impl Domains<'_> {
     ///
     /// `account_id`: The account ID
     /// `domain`: The ID or name of the domain we want list the signer records from
-    /// `options` The `RequestOptions`
+    /// `options` The `RequestOptions`, i.e. built with `DelegationSignerRecordListOptions`
     ///           - Sort: `id`, `created_at`
     ///           - Pagination
     pub async fn list_delegation_signer_records(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<DelegationSignerRecord>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/ds_records", account_id, domain);
 
         self.client
@@ -91,6 +109,32 @@ impl Domains<'_> {
             .await
     }
 
+    /// Lists all the delegation signer records of a domain, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `domain`: The ID or name of the domain we want list the signer records from
+    /// `options` The `RequestOptions`, i.e. built with `DelegationSignerRecordListOptions`
+    ///           - Sort: `id`, `created_at`
+    ///           - Pagination
+    pub fn list_delegation_signer_records_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<DelegationSignerRecord, DNSimpleError>> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/domains/{}/ds_records", account_id, domain);
+
+        self.client
+            .paginate::<ListSignerRecordsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Creates a delegation signer record
     ///
     /// You only need to create a delegation signer record manually if your domain is registered
@@ -124,10 +168,12 @@ impl Domains<'_> {
     /// `payload`: The `SignerRecordPayload` with the data needed to create the delegation signer record
     pub async fn create_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DelegationSignerRecordPayload,
     ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/ds_records", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -158,9 +204,11 @@ impl Domains<'_> {
     /// `domain`: The ID or name of the domain we want list the signer records from
     pub async fn get_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<DelegationSignerRecord>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/ds_records", account_id, domain);
 
         self.client.get::<SignerRecordEndpoint>(&path, None).await
@@ -187,10 +235,13 @@ impl Domains<'_> {
     /// `ds_record_id`: The delegation signer record id
     pub async fn delete_delegation_signer_record(
         &self,
-        account_id: u64,
-        domain: &str,
-        delegation_signer_record_id: i32,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        delegation_signer_record_id: impl Into<DelegationSignerRecordId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let delegation_signer_record_id = delegation_signer_record_id.into();
         let path = format!(
             "/{}/domains/{}/ds_records/{}",
             account_id, domain, delegation_signer_record_id
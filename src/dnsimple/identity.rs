@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::dnsimple::ids::{AccountId, UserId};
 use crate::dnsimple::{Client, DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
 
@@ -7,7 +8,7 @@ use crate::errors::DNSimpleError;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct User {
     /// The ID of the user in DNSimple
-    pub id: u64,
+    pub id: UserId,
     /// The users email
     pub email: String,
     /// When the user was created in DNSimple
@@ -20,7 +21,7 @@ pub struct User {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Account {
     /// The account ID in DNSimple
-    pub id: u64,
+    pub id: AccountId,
     /// The account email
     pub email: String,
     /// The account name
@@ -51,11 +52,11 @@ impl Endpoint for IdentityEndpoint {
 /// The Identity Service handles the identity (whoami) endpoint of the DNSimple API.
 ///
 /// See [API Documentation: identity](https://developer.dnsimple.com/v2/identity/)
-pub struct Identity<'a> {
-    pub client: &'a Client,
+pub struct Identity {
+    pub client: Client,
 }
 
-impl Identity<'_> {
+impl Identity {
     /// Retrieves the details about the current authenticated entity used to access the API.
     ///
     /// # Examples
@@ -78,11 +79,12 @@ impl Identity<'_> {
 #[cfg(test)]
 mod tests {
     use crate::dnsimple::identity;
+    use crate::dnsimple::ids::{AccountId, UserId};
 
     #[test]
     fn user_fields() {
         let user = identity::User {
-            id: 12,
+            id: UserId(12),
             email: String::from("testing@dnsimple.com"),
             created_at: String::from("some_time_ago"),
             updated_at: String::from("recently"),
@@ -94,7 +96,7 @@ mod tests {
     #[test]
     fn account_fields() {
         let account = identity::Account {
-            id: 14,
+            id: AccountId(14),
             email: String::from("account@dnsimple.com"),
             name: Some(String::from("Test Account")),
             plan_identifier: String::from("testing_plan"),
@@ -0,0 +1,238 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Defines the newtype of an ID, so that IDs of different resources cannot be mixed up.
+///
+/// The IDs are (de)serialized as plain numbers, and can be compared with and converted
+/// from and into `u64`.
+macro_rules! id_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(
+            Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Deserialize, Serialize,
+        )]
+        #[serde(transparent)]
+        pub struct $name(pub u64);
+
+        impl From<u64> for $name {
+            fn from(id: u64) -> Self {
+                $name(id)
+            }
+        }
+
+        impl From<$name> for u64 {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl PartialEq<u64> for $name {
+            fn eq(&self, other: &u64) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl PartialEq<$name> for u64 {
+            fn eq(&self, other: &$name) -> bool {
+                *self == other.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+    };
+}
+
+id_type! {
+    /// The ID of an account
+    AccountId
+}
+
+id_type! {
+    /// The ID of a domain
+    DomainId
+}
+
+id_type! {
+    /// The ID of a zone
+    ZoneId
+}
+
+id_type! {
+    /// The ID of a zone record
+    ZoneRecordId
+}
+
+id_type! {
+    /// The ID of a contact
+    ContactId
+}
+
+id_type! {
+    /// The ID of a certificate
+    CertificateId
+}
+
+id_type! {
+    /// The ID of a certificate renewal
+    CertificateRenewalId
+}
+
+id_type! {
+    /// The ID of an email forward
+    EmailForwardId
+}
+
+id_type! {
+    /// The ID of a delegation signer record
+    DelegationSignerRecordId
+}
+
+id_type! {
+    /// The ID of a domain push
+    DomainPushId
+}
+
+id_type! {
+    /// The ID of a domain registration
+    DomainRegistrationId
+}
+
+id_type! {
+    /// The ID of a domain transfer
+    DomainTransferId
+}
+
+id_type! {
+    /// The ID of a domain renewal
+    DomainRenewalId
+}
+
+id_type! {
+    /// The ID of a domain restore
+    DomainRestoreId
+}
+
+id_type! {
+    /// The ID of a registrant change
+    RegistrantChangeId
+}
+
+id_type! {
+    /// The ID of a primary server of a secondary zone
+    PrimaryServerId
+}
+
+id_type! {
+    /// The ID of a template
+    TemplateId
+}
+
+id_type! {
+    /// The ID of a template record
+    TemplateRecordId
+}
+
+id_type! {
+    /// The ID of a webhook
+    WebhookId
+}
+
+id_type! {
+    /// The ID of a user
+    UserId
+}
+
+id_type! {
+    /// The ID of the whois privacy of a domain
+    WhoisPrivacyId
+}
+
+id_type! {
+    /// The ID of an invitation to join an account
+    AccountInvitationId
+}
+
+id_type! {
+    /// The ID of the subscription of an account
+    SubscriptionId
+}
+
+id_type! {
+    /// The ID of a one-click service
+    ServiceId
+}
+
+id_type! {
+    /// The ID of a vanity name server
+    VanityNameServerId
+}
+
+id_type! {
+    /// The ID of a set of name servers
+    NameServerSetId
+}
+
+/// A reference to a domain, either by ID or by name
+///
+/// The endpoints accepting a domain name also accept its ID, so the methods taking a
+/// domain accept anything converting into a `DomainRef`: a `DomainId`, a `&str` or a `String`.
+///
+/// # Examples
+///
+/// ```
+/// use dnsimple::dnsimple::ids::{DomainId, DomainRef};
+///
+/// assert_eq!(DomainRef::Id(DomainId(1)), DomainRef::from(DomainId(1)));
+/// assert_eq!(DomainRef::Name(String::from("example.com")), DomainRef::from("example.com"));
+/// assert_eq!("1", DomainRef::from(DomainId(1)).to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DomainRef {
+    /// The domain ID.
+    Id(DomainId),
+    /// The domain name.
+    Name(String),
+}
+
+impl From<DomainId> for DomainRef {
+    fn from(id: DomainId) -> Self {
+        DomainRef::Id(id)
+    }
+}
+
+impl From<&str> for DomainRef {
+    fn from(name: &str) -> Self {
+        DomainRef::Name(String::from(name))
+    }
+}
+
+impl From<&String> for DomainRef {
+    fn from(name: &String) -> Self {
+        DomainRef::Name(name.clone())
+    }
+}
+
+impl From<String> for DomainRef {
+    fn from(name: String) -> Self {
+        DomainRef::Name(name)
+    }
+}
+
+impl From<&DomainRef> for DomainRef {
+    fn from(domain: &DomainRef) -> Self {
+        domain.clone()
+    }
+}
+
+impl fmt::Display for DomainRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DomainRef::Id(id) => write!(f, "{}", id),
+            DomainRef::Name(name) => write!(f, "{}", name),
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use crate::dnsimple::{API_VERSION, RateLimit};
+use std::future::Future;
+use std::time::Instant;
+use tracing::{Instrument, Span, field};
+
+/// The collections of the API, and the placeholder of the identifier following them.
+const COLLECTIONS: [(&str, &str); 20] = [
+    ("certificates", "{certificate}"),
+    ("contacts", "{contact}"),
+    ("domains", "{domain}"),
+    ("ds_records", "{ds_record}"),
+    ("email_forwards", "{email_forward}"),
+    ("letsencrypt", "{certificate}"),
+    ("primaries", "{primary}"),
+    ("pushes", "{push}"),
+    ("records", "{record}"),
+    ("registrant_changes", "{registrant_change}"),
+    ("registrations", "{registration}"),
+    ("renewals", "{renewal}"),
+    ("restores", "{restore}"),
+    ("services", "{service}"),
+    ("templates", "{template}"),
+    ("tlds", "{tld}"),
+    ("transfers", "{transfer}"),
+    ("vanity", "{domain}"),
+    ("webhooks", "{webhook}"),
+    ("zones", "{zone}"),
+];
+
+/// The segments that follow a collection without being an identifier.
+const ACTIONS: [&str; 2] = ["check", "research"];
+
+/// Returns the path of the request with its identifiers replaced by placeholders,
+/// i.e. `/{account}/zones/{zone}/records` for `/v2/1010/zones/example.com/records`.
+///
+/// Unlike the actual path, the templated path has a low cardinality, so it can be
+/// used to aggregate the requests by endpoint.
+pub(crate) fn templated_path(path: &str) -> String {
+    let version = format!("/{}", API_VERSION);
+    let path = match path.find(&format!("{}/", version)) {
+        Some(index) => &path[index + version.len()..],
+        None => path,
+    };
+
+    let mut templated = String::new();
+    let mut placeholder = None;
+    for (index, segment) in path.split('/').skip(1).enumerate() {
+        let collection = COLLECTIONS.iter().find(|(name, _)| *name == segment);
+        let is_identifier = collection.is_none() && !ACTIONS.contains(&segment);
+
+        templated.push('/');
+        match placeholder {
+            _ if index == 0 && segment.parse::<u64>().is_ok() => templated.push_str("{account}"),
+            Some(placeholder) if is_identifier => templated.push_str(placeholder),
+            _ => templated.push_str(segment),
+        }
+        placeholder = collection.map(|(_, placeholder)| *placeholder);
+    }
+    templated
+}
+
+/// Creates the span of a request to the API.
+pub(crate) fn request_span(request: &reqwest::Request) -> Span {
+    tracing::info_span!(
+        "dnsimple.request",
+        http.method = %request.method(),
+        http.path = %templated_path(request.url().path()),
+        http.status = field::Empty,
+        rate_limit.remaining = field::Empty,
+        attempt = field::Empty,
+        latency_ms = field::Empty,
+    )
+}
+
+/// Runs the request in its span, recording the latency of the whole call (including retries).
+pub(crate) async fn in_span<F: Future>(span: Span, future: F) -> F::Output {
+    let start = Instant::now();
+    let output = future.instrument(span.clone()).await;
+    span.record("latency_ms", start.elapsed().as_millis() as u64);
+    output
+}
+
+/// Records the outcome of an attempt in the current span, and the failures as events.
+pub(crate) fn record_attempt(attempt: u32, result: &Result<reqwest::Response, reqwest::Error>) {
+    let span = Span::current();
+    span.record("attempt", attempt);
+
+    match result {
+        Ok(response) => {
+            let status = response.status();
+            span.record("http.status", status.as_u16());
+            if let Some(rate_limit) = RateLimit::from_headers(response.headers()) {
+                span.record("rate_limit.remaining", rate_limit.remaining);
+            }
+            if !status.is_success() {
+                tracing::warn!(
+                    http.status = status.as_u16(),
+                    attempt,
+                    "The DNSimple API responded with an error"
+                );
+            }
+        }
+        Err(error) => {
+            tracing::error!(error = %error, attempt, "The request to the DNSimple API failed");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::instrumentation::templated_path;
+
+    #[test]
+    fn templated_path_replaces_the_identifiers() {
+        let paths = [
+            ("/v2/whoami", "/whoami"),
+            ("/v2/1010/zones", "/{account}/zones"),
+            (
+                "/v2/1010/zones/example.com/records",
+                "/{account}/zones/{zone}/records",
+            ),
+            (
+                "/v2/1010/zones/example.com/records/5/distribution",
+                "/{account}/zones/{zone}/records/{record}/distribution",
+            ),
+            (
+                "/v2/1010/domains/pushes/42",
+                "/{account}/domains/pushes/{push}",
+            ),
+            (
+                "/v2/1010/domains/research/status",
+                "/{account}/domains/research/status",
+            ),
+            (
+                "/v2/1010/domains/example.com/certificates/letsencrypt/1/renewals/2/issue",
+                "/{account}/domains/{domain}/certificates/letsencrypt/{certificate}/renewals/{renewal}/issue",
+            ),
+            (
+                "/v2/1010/registrar/registrant_changes/check",
+                "/{account}/registrar/registrant_changes/check",
+            ),
+            (
+                "/v2/1010/registrar/domains/example.com/whois_privacy/renewals",
+                "/{account}/registrar/domains/{domain}/whois_privacy/renewals",
+            ),
+            (
+                "/v2/tlds/com/extended_attributes",
+                "/tlds/{tld}/extended_attributes",
+            ),
+            (
+                "/prefix/v2/1010/vanity/example.com",
+                "/{account}/vanity/{domain}",
+            ),
+        ];
+
+        for (path, expected) in paths {
+            assert_eq!(expected, templated_path(path), "{}", path);
+        }
+    }
+}
@@ -0,0 +1,161 @@
+//! The typed options of the list endpoints.
+//!
+//! Each list endpoint accepting filters or sorting has its own options builder (i.e.
+//! `DomainListOptions` in the `domains` module), only exposing the filters and sort fields
+//! supported by the endpoint. The builders convert into the `Option<RequestOptions>` taken
+//! by the list methods.
+
+use std::fmt;
+
+/// A sorting criteria of a list: the field to sort by and the order
+///
+/// Created with the `asc` and `desc` methods of the sort fields (i.e. `DomainSort::Name.asc()`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SortCriteria<F> {
+    /// The field to sort by.
+    pub field: F,
+    /// True for ascending order, false for descending order.
+    pub ascending: bool,
+}
+
+impl<F: fmt::Display> fmt::Display for SortCriteria<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let order = if self.ascending { "asc" } else { "desc" };
+        write!(f, "{}:{}", self.field, order)
+    }
+}
+
+/// Defines the sort fields of an endpoint: an enum with `asc` and `desc` methods creating
+/// its `SortCriteria`, displayed as the names of the fields in the API.
+macro_rules! sort_fields {
+    (
+        $(#[$sort_meta:meta])*
+        $sort:ident {
+            $($(#[$field_meta:meta])* $field:ident => $name:literal),* $(,)?
+        }
+    ) => {
+        $(#[$sort_meta])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+        pub enum $sort {
+            $($(#[$field_meta])* $field),*
+        }
+
+        impl $sort {
+            /// Sorts by this field in ascending order.
+            pub fn asc(self) -> $crate::dnsimple::list_options::SortCriteria<$sort> {
+                $crate::dnsimple::list_options::SortCriteria {
+                    field: self,
+                    ascending: true,
+                }
+            }
+
+            /// Sorts by this field in descending order.
+            pub fn desc(self) -> $crate::dnsimple::list_options::SortCriteria<$sort> {
+                $crate::dnsimple::list_options::SortCriteria {
+                    field: self,
+                    ascending: false,
+                }
+            }
+        }
+
+        impl std::fmt::Display for $sort {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $($sort::$field => write!(f, $name)),*
+                }
+            }
+        }
+    };
+}
+
+/// Defines the sort fields of a list endpoint and its options builder.
+///
+/// The builder gets the `new`, `sort` and `paginate` methods, and converts into
+/// `RequestOptions` (and `Option<RequestOptions>`, as taken by the list methods).
+/// The filters are added in a separate `impl` block, using `filter`.
+macro_rules! list_options {
+    (
+        $(#[$meta:meta])*
+        $options:ident;
+        $(#[$sort_meta:meta])*
+        $sort:ident {
+            $($(#[$field_meta:meta])* $field:ident => $name:literal),* $(,)?
+        }
+    ) => {
+        $crate::dnsimple::list_options::sort_fields! {
+            $(#[$sort_meta])*
+            $sort {
+                $($(#[$field_meta])* $field => $name),*
+            }
+        }
+
+        $(#[$meta])*
+        #[derive(Clone, Debug, Default)]
+        pub struct $options {
+            filters: std::collections::HashMap<String, String>,
+            sort: Vec<$crate::dnsimple::list_options::SortCriteria<$sort>>,
+            paginate: Option<$crate::dnsimple::Paginate>,
+        }
+
+        impl $options {
+            /// Creates the options without any filter, sorting or pagination.
+            pub fn new() -> Self {
+                Self::default()
+            }
+
+            /// Adds a sorting criteria. The criteria are applied in the order they are added.
+            pub fn sort(mut self, criteria: $crate::dnsimple::list_options::SortCriteria<$sort>) -> Self {
+                self.sort.push(criteria);
+                self
+            }
+
+            /// Requests a single page of the list.
+            ///
+            /// # Arguments
+            ///
+            /// `page`: The page number (starting at 1)
+            /// `per_page`: The number of items per page
+            pub fn paginate(mut self, page: u32, per_page: u32) -> Self {
+                self.paginate = Some($crate::dnsimple::Paginate { per_page, page });
+                self
+            }
+
+            #[allow(dead_code)]
+            fn filter(mut self, name: &str, value: impl ToString) -> Self {
+                self.filters.insert(String::from(name), value.to_string());
+                self
+            }
+        }
+
+        impl From<$options> for $crate::dnsimple::RequestOptions {
+            fn from(options: $options) -> Self {
+                let sort = match options.sort.is_empty() {
+                    true => None,
+                    false => {
+                        let sort: Vec<String> = options.sort.iter().map(|s| s.to_string()).collect();
+                        Some($crate::dnsimple::Sort::new(sort.join(",")))
+                    }
+                };
+                let filters = match options.filters.is_empty() {
+                    true => None,
+                    false => Some($crate::dnsimple::Filters::new(options.filters)),
+                };
+
+                $crate::dnsimple::RequestOptions {
+                    filters,
+                    sort,
+                    paginate: options.paginate,
+                }
+            }
+        }
+
+        impl From<$options> for Option<$crate::dnsimple::RequestOptions> {
+            fn from(options: $options) -> Self {
+                Some(options.into())
+            }
+        }
+    };
+}
+
+pub(crate) use list_options;
+pub(crate) use sort_fields;
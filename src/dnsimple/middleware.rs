@@ -0,0 +1,116 @@
+use reqwest::{Method, Request, Response, Url};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A hook called by the client around every request sent to the DNSimple API
+///
+/// The middlewares are called in the order they were registered, on every attempt of
+/// every request (a request retried by the `RetryPolicy` is seen once per attempt).
+/// They can be used for logging, metrics, request signing or adding custom headers.
+///
+/// Both methods have a default implementation doing nothing, so a middleware only
+/// implements the ones it needs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::Client;
+/// use dnsimple::dnsimple::middleware::{Middleware, RequestInfo};
+/// use reqwest::header::HeaderValue;
+///
+/// struct CorrelationId;
+///
+/// impl Middleware for CorrelationId {
+///     fn on_request(&self, request: &mut reqwest::Request) {
+///         request.headers_mut().insert("X-Correlation-Id", HeaderValue::from_static("abc123"));
+///     }
+///
+///     fn on_response(
+///         &self,
+///         request: &RequestInfo,
+///         result: Result<&reqwest::Response, &reqwest::Error>,
+///         elapsed: Duration,
+///     ) {
+///         let status = result.map(|response| response.status().as_u16()).ok();
+///         println!("{} {} -> {:?} in {:?}", request.method, request.url, status, elapsed);
+///     }
+/// }
+///
+/// let client = Client::builder(String::from("AUTH_TOKEN"))
+///     .middleware(CorrelationId)
+///     .build()
+///     .unwrap();
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called right before the request is sent. The request can be modified,
+    /// i.e. to add headers.
+    ///
+    /// # Arguments
+    ///
+    /// `request`: the request about to be sent
+    fn on_request(&self, request: &mut Request) {
+        let _ = request;
+    }
+
+    /// Called once the response headers have been received, or the request failed.
+    ///
+    /// # Arguments
+    ///
+    /// `request`: the method, url and attempt of the request
+    /// `result`: the response, or the error if the request could not be sent
+    /// `elapsed`: the time from sending the request to receiving the response headers
+    fn on_response(
+        &self,
+        request: &RequestInfo,
+        result: Result<&Response, &reqwest::Error>,
+        elapsed: Duration,
+    ) {
+        let _ = (request, result, elapsed);
+    }
+}
+
+/// The request a response belongs to, as passed to `Middleware::on_response`
+#[derive(Clone, Debug)]
+pub struct RequestInfo {
+    /// The HTTP method.
+    pub method: Method,
+    /// The full url, including the query string.
+    pub url: Url,
+    /// The number of the attempt (starting at 1, greater when the request is retried).
+    pub attempt: u32,
+}
+
+/// The middlewares registered on a client.
+#[derive(Clone, Default)]
+pub(crate) struct Middlewares(Vec<Arc<dyn Middleware>>);
+
+impl Middlewares {
+    pub(crate) fn push(&mut self, middleware: Arc<dyn Middleware>) {
+        self.0.push(middleware);
+    }
+
+    pub(crate) fn on_request(&self, request: &mut Request) {
+        for middleware in &self.0 {
+            middleware.on_request(request);
+        }
+    }
+
+    pub(crate) fn on_response(
+        &self,
+        request: &RequestInfo,
+        result: Result<&Response, &reqwest::Error>,
+        elapsed: Duration,
+    ) {
+        for middleware in &self.0 {
+            middleware.on_response(request, result, elapsed);
+        }
+    }
+}
+
+impl fmt::Debug for Middlewares {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} middlewares]", self.0.len())
+    }
+}
@@ -1,4 +1,5 @@
 use crate::dnsimple::Client;
+use crate::dnsimple::ids::AccountId;
 use crate::errors::DNSimpleError;
 use serde::{Deserialize, Serialize};
 
@@ -34,7 +35,7 @@ pub struct AccessToken {
     /// The token you can use to authenticate.
     pub access_token: String,
     /// The account ID in DNSimple this token belongs to.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The token scope (not used for now).
     pub scope: Option<String>,
     /// The token type.
@@ -44,11 +45,11 @@ pub struct AccessToken {
 /// The Oauth Service is used to request access to the API
 ///
 /// See [API Documentation: oauth](https://developer.dnsimple.com/v2/oauth/)
-pub struct OAuth<'a> {
-    pub client: &'a Client,
+pub struct OAuth {
+    pub client: Client,
 }
 
-impl OAuth<'_> {
+impl OAuth {
     /// Exchange the short-lived authorization code for an access token
     /// you can use to authenticate your API calls.
     ///
@@ -95,9 +96,7 @@ impl OAuth<'_> {
 
         let response = self
             .client
-            .build_post_request(path)
-            .json(&value)
-            .send()
+            .send(self.client.build_post_request(path).json(&value))
             .await;
 
         self.client
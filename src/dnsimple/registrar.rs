@@ -1,3 +1,7 @@
+use crate::dnsimple::ids::{
+    AccountId, ContactId, DomainId, DomainRef, DomainRegistrationId, DomainRenewalId,
+    DomainRestoreId, DomainTransferId,
+};
 use crate::dnsimple::tlds::TldExtendedAttribute;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
@@ -35,7 +39,7 @@ pub struct DomainPrice {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainRegistrationPayload {
     /// The associated registrant (contact) ID.
-    pub registrant_id: u64,
+    pub registrant_id: ContactId,
     /// True if the domain WHOIS privacy was requested.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub whois_privacy: Option<bool>,
@@ -57,11 +61,11 @@ pub struct DomainRegistrationPayload {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainRegistration {
     /// The domain registration ID in DNSimple.
-    pub id: u64,
+    pub id: DomainRegistrationId,
     ///  The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The associated registrant (contact) ID.
-    pub registrant_id: u64,
+    pub registrant_id: ContactId,
     /// The number of years the domain was registered for.
     pub period: u64,
     /// The state of the renewal.
@@ -82,7 +86,7 @@ pub struct DomainRegistration {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainTransferPayload {
     /// The associated registrant (contact) ID.
-    pub registrant_id: u64,
+    pub registrant_id: ContactId,
     /// The authorization code
     pub auth_code: String,
     /// True if the domain WHOIS privacy was requested.
@@ -106,11 +110,11 @@ pub struct DomainTransferPayload {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainTransfer {
     /// The domain registration ID in DNSimple.
-    pub id: u64,
+    pub id: DomainTransferId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The associated registrant (contact) ID.
-    pub registrant_id: u64,
+    pub registrant_id: ContactId,
     /// The state of the transfer.
     pub state: String,
     /// True if the domain auto-renew was requested.
@@ -140,9 +144,9 @@ pub struct DomainRenewalPayload {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct DomainRenewal {
     /// The domain renewal ID in DNSimple.
-    pub id: u64,
+    pub id: DomainRenewalId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The number of years the domain was renewed for.
     pub period: u64,
     /// The state of the renewal.
@@ -153,6 +157,29 @@ pub struct DomainRenewal {
     pub updated_at: String,
 }
 
+/// Payload to restore a domain
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct DomainRestorePayload {
+    /// The domain premium price
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub premium_price: Option<String>,
+}
+
+/// Represents a domain restore
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DomainRestore {
+    /// The domain restore ID in DNSimple.
+    pub id: DomainRestoreId,
+    /// The associated domain ID.
+    pub domain_id: DomainId,
+    /// The state of the restore.
+    pub state: String,
+    /// When the domain restore was created in DNSimple.
+    pub created_at: String,
+    /// When the domain restore was last updated in DNSimple.
+    pub updated_at: String,
+}
+
 struct DomainCheckEndpoint;
 
 impl Endpoint for DomainCheckEndpoint {
@@ -183,14 +210,20 @@ impl Endpoint for DomainRenewalEndpoint {
     type Output = DomainRenewal;
 }
 
+struct DomainRestoreEndpoint;
+
+impl Endpoint for DomainRestoreEndpoint {
+    type Output = DomainRestore;
+}
+
 /// The Registrar Service handles the domains registrations of the DNSimple API.
 ///
 /// See [API Documentation: registrar](https://developer.dnsimple.com/v2/registrar/)
-pub struct Registrar<'a> {
-    pub client: &'a Client,
+pub struct Registrar {
+    pub client: Client,
 }
 
-impl Registrar<'_> {
+impl Registrar {
     /// Checks a domain name for availability.
     ///
     /// # Examples
@@ -211,9 +244,10 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub async fn check_domain(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         domain: &str,
     ) -> Result<DNSimpleResponse<DomainCheck>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/domains/{}/check", account_id, domain);
 
         self.client.get::<DomainCheckEndpoint>(&path, None).await
@@ -239,9 +273,10 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub async fn get_domain_prices(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         domain: &str,
     ) -> Result<DNSimpleResponse<DomainPrice>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/domains/{}/prices", account_id, domain);
 
         self.client.get::<DomainPricesEndpoint>(&path, None).await
@@ -268,10 +303,13 @@ impl Registrar<'_> {
     /// `domain_registration_id`: The domain registration id
     pub async fn get_domain_registration(
         &self,
-        account_id: u64,
-        domain: &str,
-        domain_registration_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_registration_id: impl Into<DomainRegistrationId>,
     ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let domain_registration_id = domain_registration_id.into();
         let path = format!(
             "/{}/registrar/domains/{}/registrations/{}",
             account_id, domain, domain_registration_id
@@ -303,10 +341,13 @@ impl Registrar<'_> {
     /// `domain_renewal_id`: The domain renewal id
     pub async fn get_domain_renewal(
         &self,
-        account_id: u64,
-        domain: &str,
-        domain_renewal_id: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_renewal_id: impl Into<DomainRenewalId>,
     ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let domain_renewal_id = domain_renewal_id.into();
         let path = format!(
             "/{}/registrar/domains/{}/renewals/{}",
             account_id, domain, domain_renewal_id
@@ -321,13 +362,14 @@ impl Registrar<'_> {
     ///
     /// ```no_run
     /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::ids::ContactId;
     /// use dnsimple::dnsimple::registrar::DomainRegistrationPayload;
     ///
     /// #[tokio::main(flavor = "current_thread")]
     /// async fn main() {
     ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
     ///     let payload = DomainRegistrationPayload {
-    ///         registrant_id: 42,
+    ///         registrant_id: ContactId(42),
     ///         whois_privacy: None,
     ///         trustee: None,
     ///         auto_renew: None,
@@ -345,10 +387,11 @@ impl Registrar<'_> {
     /// `payload`: The `DomainRegistrationPayload` with the information needed to register the domain
     pub async fn register_domain(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         domain: &str,
         payload: DomainRegistrationPayload,
     ) -> Result<DNSimpleResponse<DomainRegistration>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/domains/{}/registrations", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -369,13 +412,14 @@ impl Registrar<'_> {
     ///
     /// ```no_run
     /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::ids::ContactId;
     /// use dnsimple::dnsimple::registrar::DomainTransferPayload;
     ///
     /// #[tokio::main(flavor = "current_thread")]
     /// async fn main() {
     ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
     ///     let payload = DomainTransferPayload {
-    ///         registrant_id: 42,
+    ///         registrant_id: ContactId(42),
     ///         auth_code: "Some code".to_string(),
     ///         whois_privacy: None,
     ///         trustee: None,
@@ -394,10 +438,11 @@ impl Registrar<'_> {
     /// `payload`: The `DomainTransferPayload` with the information needed to transfer the domain
     pub async fn transfer_domain(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         domain: &str,
         payload: DomainTransferPayload,
     ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/domains/{}/transfers", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -421,10 +466,13 @@ impl Registrar<'_> {
     /// `domain_transfer`: The domain transfer id
     pub async fn get_domain_transfer(
         &self,
-        account_id: u64,
-        domain: String,
-        domain_transfer: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
     ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let domain_transfer = domain_transfer.into();
         let path = format!(
             "/{}/registrar/domains/{}/transfers/{}",
             account_id, domain, domain_transfer
@@ -442,10 +490,13 @@ impl Registrar<'_> {
     /// `domain_transfer`: The domain transfer id
     pub async fn cancel_domain_transfer(
         &self,
-        account_id: u64,
-        domain: String,
-        domain_transfer: u64,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_transfer: impl Into<DomainTransferId>,
     ) -> Result<DNSimpleResponse<DomainTransfer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let domain_transfer = domain_transfer.into();
         let path = format!(
             "/{}/registrar/domains/{}/transfers/{}",
             account_id, domain, domain_transfer
@@ -465,10 +516,12 @@ impl Registrar<'_> {
     /// `payload`: The `DomainRenewalPayload` with the information needed to renew the domain
     pub async fn renew_domain(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         payload: DomainRenewalPayload,
     ) -> Result<DNSimpleResponse<DomainRenewal>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/renewals", account_id, domain);
 
         match serde_json::to_value(payload) {
@@ -479,6 +532,81 @@ impl Registrar<'_> {
         }
     }
 
+    /// Restore a domain that expired and is in the redemption period.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::registrar::DomainRestorePayload;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let payload = DomainRestorePayload { premium_price: None };
+    ///     let domain_restore = client.registrar().restore_domain(1234, "example.com", payload).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Attributes
+    ///
+    /// `account_id`: The account id
+    /// `domain`: The domain name
+    /// `payload`: The `DomainRestorePayload` with the information needed to restore the domain
+    pub async fn restore_domain(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        payload: DomainRestorePayload,
+    ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let path = format!("/{}/registrar/domains/{}/restores", account_id, domain);
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.post::<DomainRestoreEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
+
+    /// Get the details of an existing domain restore.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let domain_restore = client.registrar().get_domain_restore(1234, "example.com", 43).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Attributes
+    ///
+    /// `account_id`: The account id
+    /// `domain`: The domain name
+    /// `domain_restore_id`: The domain restore id
+    pub async fn get_domain_restore(
+        &self,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
+        domain_restore_id: impl Into<DomainRestoreId>,
+    ) -> Result<DNSimpleResponse<DomainRestore>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
+        let domain_restore_id = domain_restore_id.into();
+        let path = format!(
+            "/{}/registrar/domains/{}/restores/{}",
+            account_id, domain, domain_restore_id
+        );
+
+        self.client.get::<DomainRestoreEndpoint>(&path, None).await
+    }
+
     /// Authorize a domain transfer out
     ///
     /// # Attributes
@@ -487,9 +615,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name
     pub async fn transfer_domain_out(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!(
             "/{}/registrar/domains/{}/authorize_transfer_out",
             account_id, domain
@@ -1,8 +1,9 @@
 use crate::dnsimple::DNSimpleEmptyResponse;
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar::Registrar;
 use crate::errors::DNSimpleError;
 
-impl Registrar<'_> {
+impl Registrar {
     /// Enable domain auto-renewal
     ///
     /// # Arguments
@@ -11,9 +12,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn enable_domain_auto_renewal(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/auto_renewal", account_id, domain);
 
         self.client.empty_put(&path).await
@@ -27,9 +30,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn disable_domain_auto_renewal(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/auto_renewal", account_id, domain);
 
         self.client.delete(&path).await
@@ -1,3 +1,4 @@
+use crate::dnsimple::ids::{AccountId, DomainRef, VanityNameServerId};
 use crate::dnsimple::registrar::Registrar;
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
@@ -14,7 +15,7 @@ impl Endpoint for DomainDelegationEndpoint {
 #[derive(Debug, Deserialize)]
 pub struct VanityNameServer {
     /// The vanity name server ID in DNSimple.
-    pub id: u64,
+    pub id: VanityNameServerId,
     /// The vanity name server name.
     pub name: String,
     /// The vanity name server IPv4.
@@ -33,7 +34,7 @@ impl Endpoint for DomainDelegationVanityEndpoint {
     type Output = Vec<VanityNameServer>;
 }
 
-impl Registrar<'_> {
+impl Registrar {
     /// List name servers for the domain in the account.
     ///
     /// # Arguments
@@ -42,9 +43,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn get_domain_delegation(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/delegation", account_id, domain);
 
         self.client
@@ -61,10 +64,12 @@ impl Registrar<'_> {
     /// `server_names`: A list of name server names as strings
     pub async fn change_domain_delegation(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         server_names: Vec<&str>,
     ) -> Result<DNSimpleResponse<Vec<String>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/delegation", account_id, domain);
 
         self.client
@@ -81,10 +86,12 @@ impl Registrar<'_> {
     /// `server_names`: A list of name server names as strings
     pub async fn change_domain_delegation_to_vanity(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         server_names: Vec<&str>,
     ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!(
             "/{}/registrar/domains/{}/delegation/vanity",
             account_id, domain
@@ -103,9 +110,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn change_domain_delegation_from_vanity(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!(
             "/{}/registrar/domains/{}/delegation/vanity",
             account_id, domain
@@ -1,7 +1,11 @@
+use crate::dnsimple::ids::{AccountId, ContactId, DomainId, RegistrantChangeId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::registrar::Registrar;
 use crate::dnsimple::tlds::TldExtendedAttribute;
 use crate::dnsimple::{DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -9,13 +13,13 @@ use std::collections::HashMap;
 #[derive(Debug, Deserialize)]
 pub struct RegistrantChange {
     /// The contact change id in DNSimple
-    pub id: u64,
+    pub id: RegistrantChangeId,
     /// The associated account ID.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The associated contact ID.
-    pub contact_id: u64,
+    pub contact_id: ContactId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The registrant change state.
     pub state: String,
     /// The extended attributes.
@@ -34,9 +38,9 @@ pub struct RegistrantChange {
 #[derive(Debug, Deserialize)]
 pub struct RegistrantChangeCheck {
     /// The associated contact ID.
-    pub contact_id: u64,
+    pub contact_id: ContactId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The extended attributes.
     pub extended_attributes: Option<Vec<TldExtendedAttribute>>,
     /// True if the registrant change is a registry owner change.
@@ -47,9 +51,9 @@ pub struct RegistrantChangeCheck {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RegistrantChangePayload {
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The associated registrant (contact) ID.
-    pub contact_id: u64,
+    pub contact_id: ContactId,
     // The extended attributes.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extended_attributes: Option<HashMap<String, String>>,
@@ -59,9 +63,37 @@ pub struct RegistrantChangePayload {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct RegistrantChangeCheckPayload {
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The associated registrant (contact) ID.
-    pub contact_id: u64,
+    pub contact_id: ContactId,
+}
+
+list_options! {
+    /// The options to list the registrant changes
+    RegistrantChangeListOptions;
+    /// The fields the registrant changes can be sorted by
+    RegistrantChangeSort {
+        /// Sort by ID.
+        Id => "id",
+    }
+}
+
+impl RegistrantChangeListOptions {
+    /// Only lists the registrant changes of the domain.
+    pub fn domain_id(self, domain_id: impl Into<DomainId>) -> Self {
+        self.filter("domain_id", domain_id.into())
+    }
+
+    /// Only lists the registrant changes to the contact.
+    pub fn contact_id(self, contact_id: impl Into<ContactId>) -> Self {
+        self.filter("contact_id", contact_id.into())
+    }
+
+    /// Only lists the registrant changes in the state (i.e. `new`, `pending`, `completed`
+    /// or `cancelled`).
+    pub fn state(self, state: &str) -> Self {
+        self.filter("state", state)
+    }
 }
 
 struct RegistrantChangeEndpoint;
@@ -88,7 +120,7 @@ impl Endpoint for RegistrantChangeCheckEndpoint {
     type Output = RegistrantChangeCheck;
 }
 
-impl Registrar<'_> {
+impl Registrar {
     /// Retrieve the domain contact change
     ///
     /// # Arguments
@@ -97,9 +129,11 @@ impl Registrar<'_> {
     /// `registrant_change_id`: The contact change ID
     pub async fn get_registrant_change(
         &self,
-        account_id: u64,
-        registrant_change_id: u64,
+        account_id: impl Into<AccountId>,
+        registrant_change_id: impl Into<RegistrantChangeId>,
     ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError> {
+        let account_id = account_id.into();
+        let registrant_change_id = registrant_change_id.into();
         let path = format!(
             "/{}/registrar/registrant_changes/{}",
             account_id, registrant_change_id
@@ -119,9 +153,10 @@ impl Registrar<'_> {
     /// requirements for a registrant change
     pub async fn check_registrant_change(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: RegistrantChangeCheckPayload,
     ) -> Result<DNSimpleResponse<RegistrantChangeCheck>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/registrant_changes/check", account_id);
 
         match serde_json::to_value(payload) {
@@ -144,9 +179,10 @@ impl Registrar<'_> {
     /// `payload`: The `RegistrantChangePayload` with the information needed to start a registrant change
     pub async fn create_registrant_change(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: RegistrantChangePayload,
     ) -> Result<DNSimpleResponse<RegistrantChange>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/registrant_changes", account_id);
 
         match serde_json::to_value(payload) {
@@ -166,14 +202,15 @@ impl Registrar<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
-    /// `options`: The `RequestOptions`
+    /// `options`: The `RequestOptions`, i.e. built with `RegistrantChangeListOptions`
     ///             - Filters: `domain_id`, `state`, `contact_id`
     ///             - Sorting: `id`
     pub async fn list_registrant_changes(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<RegistrantChange>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/registrar/registrant_changes", account_id);
 
         self.client
@@ -181,6 +218,29 @@ impl Registrar<'_> {
             .await
     }
 
+    /// Lists all the registrant changes in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `RegistrantChangeListOptions`
+    ///             - Filters: `domain_id`, `state`, `contact_id`
+    ///             - Sorting: `id`
+    pub fn list_registrant_changes_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<RegistrantChange, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/registrar/registrant_changes", account_id);
+
+        self.client
+            .paginate::<RegistrantChangesEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Cancel a registrant change.
     ///
     /// # Arguments
@@ -189,9 +249,11 @@ impl Registrar<'_> {
     /// `registrant_change_id`: The contact change ID
     pub async fn delete_registrant_change(
         &self,
-        account_id: u64,
-        registrant_change_id: u64,
+        account_id: impl Into<AccountId>,
+        registrant_change_id: impl Into<RegistrantChangeId>,
     ) -> Result<DNSimpleResponse<Option<RegistrantChange>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let registrant_change_id = registrant_change_id.into();
         let path = format!(
             "/{}/registrar/registrant_changes/{}",
             account_id, registrant_change_id
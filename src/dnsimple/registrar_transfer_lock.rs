@@ -1,3 +1,4 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar::Registrar;
 use crate::dnsimple::{DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
@@ -17,7 +18,7 @@ impl Endpoint for DomainTransferLockEndpoint {
     type Output = TransferLock;
 }
 
-impl Registrar<'_> {
+impl Registrar {
     /// Enable domain transfer lock
     ///
     /// # Arguments
@@ -26,9 +27,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn enable_domain_transfer_lock(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/transfer_lock", account_id, domain);
 
         self.client
@@ -44,9 +47,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn disable_domain_transfer_lock(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/transfer_lock", account_id, domain);
 
         self.client
@@ -62,9 +67,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn get_domain_transfer_lock(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<TransferLock>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/transfer_lock", account_id, domain);
 
         self.client
@@ -1,3 +1,4 @@
+use crate::dnsimple::ids::{AccountId, DomainId, DomainRef, WhoisPrivacyId};
 use crate::dnsimple::registrar::Registrar;
 use crate::dnsimple::{DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
@@ -8,9 +9,9 @@ use serde_json::Value;
 #[derive(Debug, Deserialize)]
 pub struct WhoisPrivacy {
     /// The whois privacy id in DNSimple
-    pub id: u64,
+    pub id: WhoisPrivacyId,
     /// The associated domain ID.
-    pub domain_id: u64,
+    pub domain_id: DomainId,
     /// The date the whois privacy will expire on.
     pub expires_on: Option<String>,
     /// Whether the whois privacy is enabled for the domain.
@@ -27,7 +28,7 @@ impl Endpoint for WhoisPrivacyEndpoint {
     type Output = WhoisPrivacy;
 }
 
-impl Registrar<'_> {
+impl Registrar {
     /// Enable WHOIS privacy
     ///
     /// # Arguments
@@ -36,9 +37,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn enable_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
 
         self.client
@@ -54,9 +57,11 @@ impl Registrar<'_> {
     /// `domain`: The domain name or id
     pub async fn disable_whois_privacy(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<WhoisPrivacy>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/registrar/domains/{}/whois_privacy", account_id, domain);
 
         self.client
@@ -0,0 +1,131 @@
+use crate::dnsimple::RateLimit;
+use reqwest::{Method, Response, StatusCode};
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The policy used by the client to retry the requests that failed with a transient error
+///
+/// The requests are retried when the API responds with `429 Too Many Requests`,
+/// `502 Bad Gateway`, `503 Service Unavailable` or `504 Gateway Timeout`, or when the
+/// request could not reach the API (connection errors and timeouts).
+///
+/// Between attempts the client waits using an exponential backoff with jitter. When
+/// the rate limit is exhausted it waits until the time in the `X-RateLimit-Reset` header
+/// instead, or for the backoff if that time is already past.
+///
+/// Retries are disabled by default. See `Client::set_retry_policy`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::retry::RetryPolicy;
+///
+/// let mut client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+/// client.set_retry_policy(RetryPolicy {
+///     max_attempts: 5,
+///     initial_backoff: Duration::from_secs(1),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The maximum number of attempts for a request, including the first one (default: 3).
+    pub max_attempts: u32,
+    /// The backoff before the first retry, doubled on every retry (default: 500ms).
+    pub initial_backoff: Duration,
+    /// The maximum backoff between two attempts (default: 30s).
+    pub max_backoff: Duration,
+    /// The maximum time to wait for the rate limit to be reset (default: 1 hour).
+    /// If the rate limit is reset later than this, the request is not retried.
+    pub max_rate_limit_wait: Duration,
+    /// Whether to also retry the non idempotent requests, i.e. `POST` and `PATCH` (default: false).
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_rate_limit_wait: Duration::from_secs(3600),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns whether requests with the given method can be retried.
+    pub(crate) fn can_retry(&self, method: &Method) -> bool {
+        self.retry_non_idempotent
+            || matches!(
+                *method,
+                Method::GET | Method::HEAD | Method::OPTIONS | Method::PUT | Method::DELETE
+            )
+    }
+
+    /// Returns how long to wait before the next attempt, or `None` if the result
+    /// of the previous attempt must not be retried.
+    ///
+    /// `attempt` is the number of the attempt that just finished (starting at 1).
+    pub(crate) fn delay_for(
+        &self,
+        attempt: u32,
+        result: &Result<Response, reqwest::Error>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        match result {
+            Ok(response) => match response.status() {
+                StatusCode::TOO_MANY_REQUESTS => match RateLimit::from_headers(response.headers())
+                    .map(|rate_limit| rate_limit.reset_in())
+                {
+                    Some(wait) if wait > self.max_rate_limit_wait => None,
+                    Some(wait) => Some(wait.max(self.backoff(attempt))),
+                    None => Some(self.backoff(attempt)),
+                },
+                StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT => Some(self.backoff(attempt)),
+                _ => None,
+            },
+            Err(error) if error.is_connect() || error.is_timeout() => Some(self.backoff(attempt)),
+            Err(_) => None,
+        }
+    }
+
+    /// The exponential backoff after the given attempt, with "equal jitter": half of
+    /// the backoff is always waited, the other half is random.
+    fn backoff(&self, attempt: u32) -> Duration {
+        let exponential = self
+            .initial_backoff
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_backoff);
+        let half = exponential / 2;
+
+        half + jitter(exponential - half)
+    }
+}
+
+/// A random duration between zero and `max`.
+fn jitter(max: Duration) -> Duration {
+    let nanos = max.as_nanos() as u64;
+    if nanos == 0 {
+        return Duration::ZERO;
+    }
+
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos(),
+    );
+
+    Duration::from_nanos(hasher.finish() % nanos)
+}
@@ -0,0 +1,308 @@
+use crate::dnsimple::ids::{AccountId, PrimaryServerId};
+use crate::dnsimple::zones::Zone;
+use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
+use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
+use serde::{Deserialize, Serialize};
+
+/// Represents a primary server in DNSimple
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PrimaryServer {
+    /// The primary server ID in DNSimple.
+    pub id: PrimaryServerId,
+    /// The associated account ID.
+    pub account_id: AccountId,
+    /// The primary server name.
+    pub name: String,
+    /// The primary server IP address.
+    pub ip: String,
+    /// The primary server port.
+    pub port: u64,
+    /// The names of the secondary zones linked to the primary server.
+    pub linked_secondary_zones: Vec<String>,
+    /// When the primary server was created in DNSimple.
+    pub created_at: String,
+    /// When the primary server was last updated in DNSimple.
+    pub updated_at: String,
+}
+
+/// Represents the payload to be send to create a primary server
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PrimaryServerPayload {
+    /// The primary server name.
+    pub name: String,
+    /// The primary server IP address.
+    pub ip: String,
+    /// The primary server port (defaults to 53).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u64>,
+}
+
+/// Represents the payload to be send to link or unlink a primary server to a secondary zone
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PrimaryServerLinkPayload {
+    /// The secondary zone name.
+    pub zone: String,
+}
+
+/// Represents the payload to be send to create a secondary zone
+#[derive(Debug, Deserialize, Serialize)]
+pub struct SecondaryZonePayload {
+    /// The secondary zone name.
+    pub name: String,
+}
+
+struct PrimaryServersEndpoint;
+
+impl Endpoint for PrimaryServersEndpoint {
+    type Output = Vec<PrimaryServer>;
+}
+
+struct PrimaryServerEndpoint;
+
+impl Endpoint for PrimaryServerEndpoint {
+    type Output = PrimaryServer;
+}
+
+struct SecondaryZoneEndpoint;
+
+impl Endpoint for SecondaryZoneEndpoint {
+    type Output = Zone;
+}
+
+/// The Secondary DNS Service handles the primary servers and secondary zones of the DNSimple API.
+///
+/// See [API Documentation: secondary DNS](https://developer.dnsimple.com/v2/secondary-dns/)
+pub struct SecondaryDns {
+    pub client: Client,
+}
+
+impl SecondaryDns {
+    /// List the primary servers in the account.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let primary_servers = client.secondary_dns().list_primary_servers(1234, None).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions` (pagination and sorting)
+    pub async fn list_primary_servers(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> Result<DNSimpleResponse<Vec<PrimaryServer>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/secondary_dns/primaries", account_id);
+
+        self.client
+            .get::<PrimaryServersEndpoint>(&path, options)
+            .await
+    }
+
+    /// Lists all the primary servers in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions` (pagination and sorting)
+    pub fn list_primary_servers_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<PrimaryServer, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/secondary_dns/primaries", account_id);
+
+        self.client
+            .paginate::<PrimaryServersEndpoint, _>(path, options)
+            .boxed()
+    }
+
+    /// Create a primary server in the account.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::secondary_dns::PrimaryServerPayload;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let payload = PrimaryServerPayload {
+    ///         name: String::from("PrimaryProduction"),
+    ///         ip: String::from("1.2.3.4"),
+    ///         port: Some(53),
+    ///     };
+    ///     let primary_server = client.secondary_dns().create_primary_server(1234, payload).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `payload`: The `PrimaryServerPayload` with the information to create the primary server
+    pub async fn create_primary_server(
+        &self,
+        account_id: impl Into<AccountId>,
+        payload: PrimaryServerPayload,
+    ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/secondary_dns/primaries", account_id);
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.post::<PrimaryServerEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
+
+    /// Retrieve a primary server.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `primary_server`: The primary server ID
+    pub async fn get_primary_server(
+        &self,
+        account_id: impl Into<AccountId>,
+        primary_server: impl Into<PrimaryServerId>,
+    ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let primary_server = primary_server.into();
+        let path = format!("/{}/secondary_dns/primaries/{}", account_id, primary_server);
+
+        self.client.get::<PrimaryServerEndpoint>(&path, None).await
+    }
+
+    /// Delete a primary server.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `primary_server`: The primary server ID
+    pub async fn delete_primary_server(
+        &self,
+        account_id: impl Into<AccountId>,
+        primary_server: impl Into<PrimaryServerId>,
+    ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let primary_server = primary_server.into();
+        let path = format!("/{}/secondary_dns/primaries/{}", account_id, primary_server);
+
+        self.client.delete(&path).await
+    }
+
+    /// Link a primary server to a secondary zone.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `primary_server`: The primary server ID
+    /// `zone`: The secondary zone name
+    pub async fn link_primary_server(
+        &self,
+        account_id: impl Into<AccountId>,
+        primary_server: impl Into<PrimaryServerId>,
+        zone: &str,
+    ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let primary_server = primary_server.into();
+        let path = format!(
+            "/{}/secondary_dns/primaries/{}/link",
+            account_id, primary_server
+        );
+        let payload = PrimaryServerLinkPayload {
+            zone: String::from(zone),
+        };
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.put::<PrimaryServerEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
+
+    /// Unlink a primary server from a secondary zone.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `primary_server`: The primary server ID
+    /// `zone`: The secondary zone name
+    pub async fn unlink_primary_server(
+        &self,
+        account_id: impl Into<AccountId>,
+        primary_server: impl Into<PrimaryServerId>,
+        zone: &str,
+    ) -> Result<DNSimpleResponse<PrimaryServer>, DNSimpleError> {
+        let account_id = account_id.into();
+        let primary_server = primary_server.into();
+        let path = format!(
+            "/{}/secondary_dns/primaries/{}/unlink",
+            account_id, primary_server
+        );
+        let payload = PrimaryServerLinkPayload {
+            zone: String::from(zone),
+        };
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.put::<PrimaryServerEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
+
+    /// Create a secondary zone in the account.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let zone = client.secondary_dns().create_secondary_zone(1234, "secondaryexample.com").await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `name`: The secondary zone name
+    pub async fn create_secondary_zone(
+        &self,
+        account_id: impl Into<AccountId>,
+        name: &str,
+    ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/secondary_dns/zones", account_id);
+        let payload = SecondaryZonePayload {
+            name: String::from(name),
+        };
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.post::<SecondaryZoneEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
+}
@@ -1,12 +1,16 @@
+use crate::dnsimple::ids::{AccountId, DomainRef, ServiceId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a service in DNSimple
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Service {
     /// The service ID in DNSimple.
-    pub id: u64,
+    pub id: ServiceId,
     /// The service name.
     pub name: String,
     ///  A string ID for the service.
@@ -44,6 +48,18 @@ pub struct ServiceSetting {
     pub password: bool,
 }
 
+list_options! {
+    /// The options to list the one-click services
+    ServiceListOptions;
+    /// The fields the services can be sorted by
+    ServiceSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by short name.
+        Sid => "sid",
+    }
+}
+
 struct ServicesEndpoint;
 
 impl Endpoint for ServicesEndpoint {
@@ -59,12 +75,16 @@ impl Endpoint for ServiceEndpoint {
 /// The Services Service handles the domains services of the DNSimple API.
 ///
 /// See [API Documentation: services](https://developer.dnsimple.com/v2/services/)
-pub struct Services<'a> {
-    pub client: &'a Client,
+pub struct Services {
+    pub client: Client,
 }
 
-impl Services<'_> {
+impl Services {
     /// List services
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, i.e. built with `ServiceListOptions`
     pub async fn list_services(
         &self,
         options: Option<RequestOptions>,
@@ -74,6 +94,24 @@ impl Services<'_> {
         self.client.get::<ServicesEndpoint>(path, options).await
     }
 
+    /// Lists all the one-click services, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, i.e. built with `ServiceListOptions`
+    pub fn list_services_stream(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Service, DNSimpleError>> {
+        let path = String::from("/services");
+
+        self.client
+            .paginate::<ServicesEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Retrieve a service
     ///
     /// # Arguments
@@ -94,10 +132,12 @@ impl Services<'_> {
     /// `domain`: The domain name or id
     pub async fn applied_services(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Service>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/services", account_id, domain);
 
         self.client.get::<ServicesEndpoint>(&path, options).await
@@ -111,10 +151,12 @@ impl Services<'_> {
     /// `service`: The service name or id
     pub async fn apply_service(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         service: String,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/services/{}", account_id, domain, service);
 
         self.client.empty_post(&path).await
@@ -128,10 +170,12 @@ impl Services<'_> {
     /// `service`: The service name or id
     pub async fn unapply_service(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         service: String,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/services/{}", account_id, domain, service);
 
         self.client.delete(&path).await
@@ -1,14 +1,18 @@
+use crate::dnsimple::ids::{AccountId, DomainRef, TemplateId, TemplateRecordId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a template in DNSimple
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Template {
     /// The template ID in DNSimple.
-    pub id: u64,
+    pub id: TemplateId,
     /// The associated account ID.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The template name.
     pub name: String,
     /// The string ID for the template.
@@ -36,9 +40,9 @@ pub struct TemplatePayload {
 #[derive(Debug, Deserialize, Serialize)]
 pub struct TemplateRecord {
     /// The template record ID in DNSimple.
-    pub id: u64,
+    pub id: TemplateRecordId,
     /// The template ID in DNSimple.
-    pub template_id: u64,
+    pub template_id: TemplateId,
     /// The template record name (without the domain name).
     pub name: String,
     /// The plain-text template record content.
@@ -72,6 +76,36 @@ pub struct TemplateRecordPayload {
     pub priority: Option<u64>,
 }
 
+list_options! {
+    /// The options to list the templates
+    TemplateListOptions;
+    /// The fields the templates can be sorted by
+    TemplateSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by name.
+        Name => "name",
+        /// Sort by short name.
+        Sid => "sid",
+    }
+}
+
+list_options! {
+    /// The options to list the records of a template
+    TemplateRecordListOptions;
+    /// The fields the template records can be sorted by
+    TemplateRecordSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by name.
+        Name => "name",
+        /// Sort by content.
+        Content => "content",
+        /// Sort by type.
+        Type => "type",
+    }
+}
+
 struct TemplatesEndpoint;
 
 impl Endpoint for TemplatesEndpoint {
@@ -99,27 +133,49 @@ impl Endpoint for TemplateRecordEndpoint {
 /// The Templates Service handles the domains templates of the DNSimple API.
 ///
 /// See [API Documentation: templates](https://developer.dnsimple.com/v2/templates/)
-pub struct Templates<'a> {
-    pub client: &'a Client,
+pub struct Templates {
+    pub client: Client,
 }
 
-impl Templates<'_> {
+impl Templates {
     /// List templates in the account.
     ///
     /// # Arguments
     ///
     /// `account_id`: The account id
-    /// `options`: The `RequestOptions` for sorting, etc.
+    /// `options`: The `RequestOptions`, i.e. built with `TemplateListOptions`
     pub async fn list_templates(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Template>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates", account_id);
 
         self.client.get::<TemplatesEndpoint>(&path, options).await
     }
 
+    /// Lists all the templates in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    /// `options`: The `RequestOptions`, i.e. built with `TemplateListOptions`
+    pub fn list_templates_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Template, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/templates", account_id);
+
+        self.client
+            .paginate::<TemplatesEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create a template in the account
     ///
     /// # Arguments
@@ -128,9 +184,10 @@ impl Templates<'_> {
     /// `payload`: The `Template payload` with the information to create the template
     pub async fn create_template(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         payload: TemplatePayload,
     ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates", account_id);
 
         match serde_json::to_value(payload) {
@@ -149,9 +206,10 @@ impl Templates<'_> {
     /// `template`: The template name or id
     pub async fn get_template(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
     ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates/{}", account_id, template);
 
         self.client.get::<TemplateEndpoint>(&path, None).await
@@ -166,10 +224,11 @@ impl Templates<'_> {
     /// `payload`: The `Template payload` with the information to create the template
     pub async fn update_template(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
         payload: TemplatePayload,
     ) -> Result<DNSimpleResponse<Template>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates/{}", account_id, template);
 
         match serde_json::to_value(payload) {
@@ -187,9 +246,10 @@ impl Templates<'_> {
     /// `template`: The template name or id
     pub async fn delete_template(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates/{}", account_id, template);
 
         self.client.delete(&path).await
@@ -200,12 +260,14 @@ impl Templates<'_> {
     /// # Arguments
     /// `account_id`: The account id
     /// `template`: The template name or id
+    /// `options`: The `RequestOptions`, i.e. built with `TemplateRecordListOptions`
     pub async fn list_template_records(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<TemplateRecord>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates/{}/records", account_id, template);
 
         self.client
@@ -213,6 +275,28 @@ impl Templates<'_> {
             .await
     }
 
+    /// Lists all the records of a template, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    /// `account_id`: The account id
+    /// `template`: The template name or id
+    /// `options`: The `RequestOptions`, i.e. built with `TemplateRecordListOptions`
+    pub fn list_template_records_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        template: String,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<TemplateRecord, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/templates/{}/records", account_id, template);
+
+        self.client
+            .paginate::<TemplateRecordsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create a template record
     ///
     /// # Arguments
@@ -221,10 +305,11 @@ impl Templates<'_> {
     /// `payload`: The `TemplateRecordPayload` with the information needed to create the template record
     pub async fn create_template_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
         payload: TemplateRecordPayload,
     ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/templates/{}/records", account_id, template);
 
         match serde_json::to_value(payload) {
@@ -247,10 +332,12 @@ impl Templates<'_> {
     /// `record`: The record id
     pub async fn get_template_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
-        record: u64,
+        record: impl Into<TemplateRecordId>,
     ) -> Result<DNSimpleResponse<TemplateRecord>, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!("/{}/templates/{}/records/{}", account_id, template, record);
 
         self.client.get::<TemplateRecordEndpoint>(&path, None).await
@@ -264,10 +351,12 @@ impl Templates<'_> {
     /// `record`: The record id
     pub async fn delete_template_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         template: String,
-        record: u64,
+        record: impl Into<TemplateRecordId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!("/{}/templates/{}/records/{}", account_id, template, record);
 
         self.client.delete(&path).await
@@ -281,10 +370,12 @@ impl Templates<'_> {
     /// `template`: The template id or short name
     pub async fn apply_template(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
         template: String,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/domains/{}/templates/{}", account_id, domain, template);
 
         self.client.empty_post(&path).await
@@ -1,5 +1,8 @@
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a TLD in DNSimple
@@ -55,6 +58,16 @@ pub struct ExtendedAttributeOption {
     pub description: String,
 }
 
+list_options! {
+    /// The options to list the TLDs
+    TldListOptions;
+    /// The fields the TLDs can be sorted by
+    TldSort {
+        /// Sort by TLD.
+        Tld => "tld",
+    }
+}
+
 struct ListTldsEndpoint;
 
 impl Endpoint for ListTldsEndpoint {
@@ -76,12 +89,16 @@ impl Endpoint for ListTldsExtendedAttributesEndpoint {
 /// The Tlds Service handles the tlds of the DNSimple API.
 ///
 /// See [API Documentation: tlds](https://developer.dnsimple.com/v2/tlds/)
-pub struct Tlds<'a> {
-    pub client: &'a Client,
+pub struct Tlds {
+    pub client: Client,
 }
 
-impl Tlds<'_> {
+impl Tlds {
     /// Returns the list of TLDs supported for registration or transfer.
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, i.e. built with `TldListOptions`
     pub async fn list_tlds(
         &self,
         options: Option<RequestOptions>,
@@ -91,6 +108,24 @@ impl Tlds<'_> {
         self.client.get::<ListTldsEndpoint>(path, options).await
     }
 
+    /// Lists all the supported TLDs, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `options`: The `RequestOptions`, i.e. built with `TldListOptions`
+    pub fn list_tlds_stream(
+        &self,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Tld, DNSimpleError>> {
+        let path = String::from("/tlds");
+
+        self.client
+            .paginate::<ListTldsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Retrieves the details of a supported TLD.
     ///
     /// # Attributes
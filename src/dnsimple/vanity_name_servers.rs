@@ -1,3 +1,4 @@
+use crate::dnsimple::ids::{AccountId, DomainRef};
 use crate::dnsimple::registrar_name_servers::VanityNameServer;
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint};
 use crate::errors::DNSimpleError;
@@ -12,11 +13,11 @@ impl Endpoint for VanityNameServersEndpoint {
 /// The Vanity Name Servers Service handles the vanity name servers of the DNSimple API.
 ///
 /// See [API Documentation: vanity](https://developer.dnsimple.com/v2/vanity/)
-pub struct VanityNameServers<'a> {
-    pub client: &'a Client,
+pub struct VanityNameServers {
+    pub client: Client,
 }
 
-impl VanityNameServers<'_> {
+impl VanityNameServers {
     /// Enable vanity name servers
     ///
     /// # Arguments
@@ -24,9 +25,11 @@ impl VanityNameServers<'_> {
     /// `domain`: The domain name or id
     pub async fn enable_vanity_name_servers(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleResponse<Vec<VanityNameServer>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/vanity/{}", account_id, domain);
 
         self.client
@@ -41,9 +44,11 @@ impl VanityNameServers<'_> {
     /// `domain`: The domain name or id
     pub async fn disable_vanity_name_servers(
         &self,
-        account_id: u64,
-        domain: String,
+        account_id: impl Into<AccountId>,
+        domain: impl Into<DomainRef>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let domain = domain.into();
         let path = format!("/{}/vanity/{}", account_id, domain);
 
         self.client.delete(&path).await
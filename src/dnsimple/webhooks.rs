@@ -1,12 +1,15 @@
+use crate::dnsimple::ids::{AccountId, WebhookId};
 use crate::dnsimple::{Client, DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 
 /// Represents a webhook
 #[derive(Debug, Deserialize)]
 pub struct Webhook {
     /// The webhook ID in DNSimple.
-    pub id: u64,
+    pub id: WebhookId,
     /// The callback URL.
     pub url: String,
 }
@@ -33,11 +36,11 @@ impl Endpoint for WebhookEndpoint {
 /// The Webhooks Service handles the webhooks of the DNSimple API.
 ///
 /// See [API Documentation: webhooks](https://developer.dnsimple.com/v2/webhooks/)
-pub struct Webhooks<'a> {
-    pub client: &'a Client,
+pub struct Webhooks {
+    pub client: Client,
 }
 
-impl Webhooks<'_> {
+impl Webhooks {
     /// List webhooks in the account.
     ///
     /// # Arguments
@@ -45,14 +48,35 @@ impl Webhooks<'_> {
     /// `account_id`: The account id
     pub async fn list_webhooks(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Webhook>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/webhooks", account_id);
 
         self.client.get::<WebhooksEndpoint>(&path, options).await
     }
 
+    /// Lists all the webhooks in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account id
+    pub fn list_webhooks_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Webhook, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/webhooks", account_id);
+
+        self.client
+            .paginate::<WebhooksEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create a webhook in the account
     ///
     /// # Arguments
@@ -61,9 +85,10 @@ impl Webhooks<'_> {
     /// `url`: The webhook url
     pub async fn create_webhook(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         url: String,
     ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/webhooks", account_id);
         let payload = WebhookPayload { url };
 
@@ -82,9 +107,11 @@ impl Webhooks<'_> {
     /// `webhook`: The webhook id
     pub async fn get_webhook(
         &self,
-        account_id: u64,
-        webhook: String,
+        account_id: impl Into<AccountId>,
+        webhook: impl Into<WebhookId>,
     ) -> Result<DNSimpleResponse<Webhook>, DNSimpleError> {
+        let account_id = account_id.into();
+        let webhook = webhook.into();
         let path = format!("/{}/webhooks/{}", account_id, webhook);
 
         self.client.get::<WebhookEndpoint>(&path, None).await
@@ -97,9 +124,11 @@ impl Webhooks<'_> {
     /// `webhook`: The webhook id
     pub async fn delete_webhook(
         &self,
-        account_id: u64,
-        webhook: String,
+        account_id: impl Into<AccountId>,
+        webhook: impl Into<WebhookId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let webhook = webhook.into();
         let path = format!("/{}/webhooks/{}", account_id, webhook);
 
         self.client.delete(&path).await
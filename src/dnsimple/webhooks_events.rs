@@ -0,0 +1,601 @@
+use crate::dnsimple::certificates::Certificate;
+use crate::dnsimple::contacts::Contact;
+use crate::dnsimple::domains::Domain;
+use crate::dnsimple::domains_dnssec::Dnssec;
+use crate::dnsimple::domains_email_forwards::EmailForward;
+use crate::dnsimple::identity::{Account, User};
+use crate::dnsimple::ids::{
+    AccountId, AccountInvitationId, ContactId, DelegationSignerRecordId, DomainId, SubscriptionId,
+};
+use crate::dnsimple::registrar_whois_privacy::WhoisPrivacy;
+use crate::dnsimple::webhooks::Webhook;
+use crate::dnsimple::zones::Zone;
+use crate::dnsimple::zones_records::ZoneRecord;
+use crate::errors::DNSimpleError;
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Represents a webhook event sent by DNSimple
+///
+/// See [API Documentation: webhooks](https://developer.dnsimple.com/v2/webhooks/events/)
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::webhooks_events::{EventData, WebhookEvent};
+///
+/// fn handle_webhook(body: &str) {
+///     let event = WebhookEvent::parse(body).unwrap();
+///
+///     match event.data {
+///         EventData::ZoneRecordCreate(data) => println!("Record {} created", data.zone_record.id),
+///         EventData::Unknown(data) => println!("Unhandled event {}: {}", event.name, data),
+///         _ => {}
+///     }
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "RawWebhookEvent")]
+pub struct WebhookEvent {
+    /// The event name (i.e. `zone_record.create` or `domain.register:started`).
+    pub name: String,
+    /// The entity that triggered the event.
+    pub actor: WebhookActor,
+    /// The account the event belongs to.
+    pub account: WebhookAccount,
+    /// The version of the API used to serialize the event.
+    pub api_version: String,
+    /// The identifier of the request that triggered the event.
+    pub request_identifier: String,
+    /// The event data, typed according to the event name.
+    pub data: EventData,
+}
+
+/// Represents the entity that triggered a webhook event
+#[derive(Debug, Deserialize)]
+pub struct WebhookActor {
+    /// The actor ID.
+    pub id: String,
+    /// The actor entity (i.e. `user`, `account` or `dnsimple`).
+    pub entity: String,
+    /// A human readable representation of the actor.
+    pub pretty: String,
+}
+
+/// Represents the account a webhook event belongs to
+#[derive(Debug, Deserialize)]
+pub struct WebhookAccount {
+    /// The account ID in DNSimple.
+    pub id: AccountId,
+    /// The account display name.
+    pub display: String,
+    /// The account identifier.
+    pub identifier: String,
+}
+
+/// Represents the data of a webhook event, one variant per event name
+///
+/// Events with a name that is not known to this client are returned as
+/// `EventData::Unknown` holding the raw JSON data.
+#[derive(Debug)]
+pub enum EventData {
+    /// The `account.billing_settings_update` event.
+    AccountBillingSettingsUpdate(AccountBillingSettingsEventData),
+    /// The `account.sso_user_add` event.
+    AccountSsoUserAdd(AccountSsoUserEventData),
+    /// The `account.update` event.
+    AccountUpdate(AccountEventData),
+    /// The `account.user_invitation_accept` event.
+    AccountUserInvitationAccept(AccountInvitationEventData),
+    /// The `account.user_invitation_revoke` event.
+    AccountUserInvitationRevoke(AccountInvitationEventData),
+    /// The `account.user_invite` event.
+    AccountUserInvite(AccountInvitationEventData),
+    /// The `account.user_remove` event.
+    AccountUserRemove(AccountUserEventData),
+    /// The `certificate.issue` event.
+    CertificateIssue(CertificateEventData),
+    /// The `certificate.remove_private_key` event.
+    CertificateRemovePrivateKey(CertificateEventData),
+    /// The `contact.create` event.
+    ContactCreate(ContactEventData),
+    /// The `contact.delete` event.
+    ContactDelete(ContactEventData),
+    /// The `contact.update` event.
+    ContactUpdate(ContactEventData),
+    /// The `dnssec.create` event.
+    DnssecCreate(DnssecEventData),
+    /// The `dnssec.delete` event.
+    DnssecDelete(DnssecEventData),
+    /// The `dnssec.rotation_complete` event.
+    DnssecRotationComplete(DnssecRotationEventData),
+    /// The `dnssec.rotation_start` event.
+    DnssecRotationStart(DnssecRotationEventData),
+    /// The `domain.auto_renewal_disable` event.
+    DomainAutoRenewalDisable(DomainEventData),
+    /// The `domain.auto_renewal_enable` event.
+    DomainAutoRenewalEnable(DomainEventData),
+    /// The `domain.create` event.
+    DomainCreate(DomainEventData),
+    /// The `domain.delegation_change` event.
+    DomainDelegationChange(DomainDelegationEventData),
+    /// The `domain.delete` event.
+    DomainDelete(DomainEventData),
+    /// The `domain.register` event.
+    DomainRegister(DomainEventData),
+    /// The `domain.register:cancelled` event.
+    DomainRegisterCancelled(DomainEventData),
+    /// The `domain.register:started` event.
+    DomainRegisterStarted(DomainEventData),
+    /// The `domain.registrant_change` event.
+    DomainRegistrantChange(DomainRegistrantChangeEventData),
+    /// The `domain.registrant_change:cancelled` event.
+    DomainRegistrantChangeCancelled(DomainRegistrantChangeEventData),
+    /// The `domain.registrant_change:started` event.
+    DomainRegistrantChangeStarted(DomainRegistrantChangeEventData),
+    /// The `domain.renew` event.
+    DomainRenew(DomainRenewalEventData),
+    /// The `domain.renew:cancelled` event.
+    DomainRenewCancelled(DomainRenewalEventData),
+    /// The `domain.renew:started` event.
+    DomainRenewStarted(DomainRenewalEventData),
+    /// The `domain.resolution_disable` event.
+    DomainResolutionDisable(DomainEventData),
+    /// The `domain.resolution_enable` event.
+    DomainResolutionEnable(DomainEventData),
+    /// The `domain.restore` event.
+    DomainRestore(DomainRenewalEventData),
+    /// The `domain.restore:cancelled` event.
+    DomainRestoreCancelled(DomainRenewalEventData),
+    /// The `domain.restore:started` event.
+    DomainRestoreStarted(DomainRenewalEventData),
+    /// The `domain.transfer` event.
+    DomainTransfer(DomainEventData),
+    /// The `domain.transfer:cancelled` event.
+    DomainTransferCancelled(DomainEventData),
+    /// The `domain.transfer:started` event.
+    DomainTransferStarted(DomainEventData),
+    /// The `domain.transfer_lock_disable` event.
+    DomainTransferLockDisable(DomainEventData),
+    /// The `domain.transfer_lock_enable` event.
+    DomainTransferLockEnable(DomainEventData),
+    /// The `email_forward.activate` event.
+    EmailForwardActivate(EmailForwardEventData),
+    /// The `email_forward.create` event.
+    EmailForwardCreate(EmailForwardEventData),
+    /// The `email_forward.deactivate` event.
+    EmailForwardDeactivate(EmailForwardEventData),
+    /// The `email_forward.delete` event.
+    EmailForwardDelete(EmailForwardEventData),
+    /// The `email_forward.update` event.
+    EmailForwardUpdate(EmailForwardEventData),
+    /// The `subscription.renew` event.
+    SubscriptionRenew(SubscriptionEventData),
+    /// The `subscription.renew:failed` event.
+    SubscriptionRenewFailed(SubscriptionEventData),
+    /// The `webhook.create` event.
+    WebhookCreate(WebhookEventData),
+    /// The `webhook.delete` event.
+    WebhookDelete(WebhookEventData),
+    /// The `whois_privacy.disable` event.
+    WhoisPrivacyDisable(WhoisPrivacyEventData),
+    /// The `whois_privacy.enable` event.
+    WhoisPrivacyEnable(WhoisPrivacyEventData),
+    /// The `whois_privacy.purchase` event.
+    WhoisPrivacyPurchase(WhoisPrivacyEventData),
+    /// The `whois_privacy.renew` event.
+    WhoisPrivacyRenew(WhoisPrivacyEventData),
+    /// The `zone.create` event.
+    ZoneCreate(ZoneEventData),
+    /// The `zone.delete` event.
+    ZoneDelete(ZoneEventData),
+    /// The `zone_record.create` event.
+    ZoneRecordCreate(ZoneRecordEventData),
+    /// The `zone_record.delete` event.
+    ZoneRecordDelete(ZoneRecordEventData),
+    /// The `zone_record.update` event.
+    ZoneRecordUpdate(ZoneRecordEventData),
+    /// An event not known to this client, with its raw JSON data.
+    Unknown(Value),
+}
+
+/// Represents the billing settings of an account
+#[derive(Debug, Deserialize)]
+pub struct BillingSettings {
+    /// The billing address.
+    pub address: Option<String>,
+    /// The billing company.
+    pub company: Option<String>,
+    /// The tax identifier.
+    pub tax_identifier: Option<String>,
+}
+
+/// Represents an invitation to join an account
+#[derive(Debug, Deserialize)]
+pub struct AccountInvitation {
+    /// The invitation ID in DNSimple.
+    pub id: AccountInvitationId,
+    /// The email of the invited user.
+    pub email: String,
+    /// The invitation token.
+    pub token: String,
+    /// The associated account ID.
+    pub account_id: AccountId,
+    /// When the invitation was created in DNSimple.
+    pub created_at: String,
+    /// When the invitation was last updated in DNSimple.
+    pub updated_at: String,
+    /// When the invitation was sent.
+    pub invitation_sent_at: Option<String>,
+    /// When the invitation was accepted.
+    pub invitation_accepted_at: Option<String>,
+}
+
+/// Represents the identity provider linked to an account
+#[derive(Debug, Deserialize)]
+pub struct AccountIdentityProvider {
+    /// The organization identifier in the identity provider.
+    pub organization_identifier: String,
+}
+
+/// Represents a delegation signer record as sent in DNSSEC rotation events
+///
+/// Unlike `DelegationSignerRecord`, the record might not have been created in DNSimple yet.
+#[derive(Debug, Deserialize)]
+pub struct DnssecRotationSignerRecord {
+    /// The ID of the delegation signer record in DNSimple, if any.
+    pub id: Option<DelegationSignerRecordId>,
+    /// The associated domain ID.
+    pub domain_id: DomainId,
+    /// The signing algorithm used.
+    pub algorithm: String,
+    /// The digest value.
+    pub digest: String,
+    /// The digest type used.
+    pub digest_type: String,
+    /// The keytag for the associated DNSKEY.
+    pub keytag: String,
+    /// The public key that references the corresponding DNSKEY record.
+    pub public_key: Option<String>,
+    /// When the delegation signing record was created in DNSimple.
+    pub created_at: Option<String>,
+    /// When the delegation signing record was last updated in DNSimple.
+    pub updated_at: Option<String>,
+}
+
+/// Represents the registrant contact as sent in registrant change events
+#[derive(Debug, Deserialize)]
+pub struct Registrant {
+    /// The contact ID in DNSimple.
+    pub id: ContactId,
+    /// The associated account ID.
+    pub account_id: AccountId,
+    /// The label to represent the contact.
+    pub label: Option<String>,
+    /// The contact first name.
+    pub first_name: String,
+    /// The contact last name.
+    pub last_name: String,
+    /// The contact's job title.
+    pub job_title: Option<String>,
+    /// The name of the organization in which the contact works.
+    pub organization_name: Option<String>,
+    /// The contact email address.
+    pub email_address: String,
+    /// The contact phone number.
+    pub phone: String,
+    /// The contact fax number.
+    pub fax: Option<String>,
+    /// The contact street address.
+    pub address1: String,
+    /// Apartment or suite number.
+    pub address2: Option<String>,
+    /// The city name.
+    pub city: String,
+    /// The state or province name.
+    pub state_province: String,
+    /// The contact postal code.
+    pub postal_code: String,
+    /// The contact country (as a 2-character country code).
+    pub country: String,
+    /// When the contact was created in DNSimple.
+    pub created_at: String,
+    /// When the contact was last updated in DNSimple.
+    pub updated_at: String,
+}
+
+/// Represents a subscription of an account
+#[derive(Debug, Deserialize)]
+pub struct Subscription {
+    /// The subscription ID in DNSimple.
+    pub id: SubscriptionId,
+    /// The subscription state.
+    pub state: String,
+    /// The name of the subscribed plan.
+    pub plan_name: String,
+    /// When the subscription was created in DNSimple.
+    pub created_at: String,
+    /// When the subscription was last updated in DNSimple.
+    pub updated_at: String,
+}
+
+/// The data of the `account.update` event
+#[derive(Debug, Deserialize)]
+pub struct AccountEventData {
+    /// The account.
+    pub account: Account,
+}
+
+/// The data of the `account.billing_settings_update` event
+#[derive(Debug, Deserialize)]
+pub struct AccountBillingSettingsEventData {
+    /// The account.
+    pub account: Account,
+    /// The updated billing settings of the account.
+    pub billing_settings: BillingSettings,
+}
+
+/// The data of the `account.user_invite`, `account.user_invitation_accept` and
+/// `account.user_invitation_revoke` events
+#[derive(Debug, Deserialize)]
+pub struct AccountInvitationEventData {
+    /// The account.
+    pub account: Account,
+    /// The invitation.
+    pub account_invitation: AccountInvitation,
+}
+
+/// The data of the `account.user_remove` event
+#[derive(Debug, Deserialize)]
+pub struct AccountUserEventData {
+    /// The account.
+    pub account: Account,
+    /// The user.
+    pub user: User,
+}
+
+/// The data of the `account.sso_user_add` event
+#[derive(Debug, Deserialize)]
+pub struct AccountSsoUserEventData {
+    /// The account.
+    pub account: Account,
+    /// The user.
+    pub user: User,
+    /// The identity provider the user signed in with.
+    pub account_identity_provider: AccountIdentityProvider,
+}
+
+/// The data of the `certificate.*` events
+#[derive(Debug, Deserialize)]
+pub struct CertificateEventData {
+    /// The certificate.
+    pub certificate: Certificate,
+}
+
+/// The data of the `contact.*` events
+#[derive(Debug, Deserialize)]
+pub struct ContactEventData {
+    /// The contact.
+    pub contact: Contact,
+}
+
+/// The data of the `dnssec.create` and `dnssec.delete` events
+#[derive(Debug, Deserialize)]
+pub struct DnssecEventData {
+    /// The zone.
+    pub zone: Zone,
+    /// The DNSSEC status of the zone.
+    pub dnssec: Dnssec,
+}
+
+/// The data of the `dnssec.rotation_start` and `dnssec.rotation_complete` events
+#[derive(Debug, Deserialize)]
+pub struct DnssecRotationEventData {
+    /// The zone.
+    pub zone: Zone,
+    /// The DNSSEC status of the zone.
+    pub dnssec: Dnssec,
+    /// The delegation signer record being rotated.
+    pub delegation_signer_record: DnssecRotationSignerRecord,
+}
+
+/// The data of the `domain.*` events that only carry the domain
+#[derive(Debug, Deserialize)]
+pub struct DomainEventData {
+    /// The domain.
+    pub domain: Domain,
+}
+
+/// The data of the `domain.delegation_change` event
+#[derive(Debug, Deserialize)]
+pub struct DomainDelegationEventData {
+    /// The domain.
+    pub domain: Domain,
+    /// The new name servers of the domain.
+    pub name_servers: Vec<String>,
+}
+
+/// The data of the `domain.registrant_change` events
+#[derive(Debug, Deserialize)]
+pub struct DomainRegistrantChangeEventData {
+    /// The domain.
+    pub domain: Domain,
+    /// The new registrant of the domain.
+    pub registrant: Registrant,
+}
+
+/// The data of the `domain.renew` and `domain.restore` events
+#[derive(Debug, Deserialize)]
+pub struct DomainRenewalEventData {
+    /// True if the operation was triggered automatically.
+    #[serde(default)]
+    pub auto: bool,
+    /// The domain.
+    pub domain: Domain,
+}
+
+/// The data of the `email_forward.*` events
+#[derive(Debug, Deserialize)]
+pub struct EmailForwardEventData {
+    /// The email forward.
+    pub email_forward: EmailForward,
+}
+
+/// The data of the `subscription.*` events
+#[derive(Debug, Deserialize)]
+pub struct SubscriptionEventData {
+    /// The subscription.
+    pub subscription: Subscription,
+}
+
+/// The data of the `webhook.*` events
+#[derive(Debug, Deserialize)]
+pub struct WebhookEventData {
+    /// The webhook.
+    pub webhook: Webhook,
+}
+
+/// The data of the `whois_privacy.*` events
+#[derive(Debug, Deserialize)]
+pub struct WhoisPrivacyEventData {
+    /// The domain.
+    pub domain: Domain,
+    /// The whois privacy of the domain.
+    pub whois_privacy: WhoisPrivacy,
+}
+
+/// The data of the `zone.*` events
+#[derive(Debug, Deserialize)]
+pub struct ZoneEventData {
+    /// The zone.
+    pub zone: Zone,
+}
+
+/// The data of the `zone_record.*` events
+#[derive(Debug, Deserialize)]
+pub struct ZoneRecordEventData {
+    /// The zone record.
+    pub zone_record: ZoneRecord,
+}
+
+#[derive(Deserialize)]
+struct RawWebhookEvent {
+    name: String,
+    actor: WebhookActor,
+    account: WebhookAccount,
+    api_version: String,
+    request_identifier: String,
+    data: Value,
+}
+
+impl TryFrom<RawWebhookEvent> for WebhookEvent {
+    type Error = serde_json::Error;
+
+    fn try_from(raw: RawWebhookEvent) -> Result<Self, Self::Error> {
+        let data = EventData::parse(&raw.name, raw.data)?;
+
+        Ok(WebhookEvent {
+            name: raw.name,
+            actor: raw.actor,
+            account: raw.account,
+            api_version: raw.api_version,
+            request_identifier: raw.request_identifier,
+            data,
+        })
+    }
+}
+
+impl WebhookEvent {
+    /// Parses the body of a webhook request sent by DNSimple
+    ///
+    /// # Arguments
+    ///
+    /// `payload`: The raw JSON body of the webhook request
+    pub fn parse(payload: &str) -> Result<WebhookEvent, DNSimpleError> {
+        serde_json::from_str(payload).map_err(|e| DNSimpleError::Deserialization(e.to_string()))
+    }
+}
+
+impl EventData {
+    fn parse(name: &str, data: Value) -> Result<EventData, serde_json::Error> {
+        use serde_json::from_value;
+
+        let event_data = match name {
+            "account.billing_settings_update" => {
+                EventData::AccountBillingSettingsUpdate(from_value(data)?)
+            }
+            "account.sso_user_add" => EventData::AccountSsoUserAdd(from_value(data)?),
+            "account.update" => EventData::AccountUpdate(from_value(data)?),
+            "account.user_invitation_accept" => {
+                EventData::AccountUserInvitationAccept(from_value(data)?)
+            }
+            "account.user_invitation_revoke" => {
+                EventData::AccountUserInvitationRevoke(from_value(data)?)
+            }
+            "account.user_invite" => EventData::AccountUserInvite(from_value(data)?),
+            "account.user_remove" => EventData::AccountUserRemove(from_value(data)?),
+            "certificate.issue" => EventData::CertificateIssue(from_value(data)?),
+            "certificate.remove_private_key" => {
+                EventData::CertificateRemovePrivateKey(from_value(data)?)
+            }
+            "contact.create" => EventData::ContactCreate(from_value(data)?),
+            "contact.delete" => EventData::ContactDelete(from_value(data)?),
+            "contact.update" => EventData::ContactUpdate(from_value(data)?),
+            "dnssec.create" => EventData::DnssecCreate(from_value(data)?),
+            "dnssec.delete" => EventData::DnssecDelete(from_value(data)?),
+            "dnssec.rotation_complete" => EventData::DnssecRotationComplete(from_value(data)?),
+            "dnssec.rotation_start" => EventData::DnssecRotationStart(from_value(data)?),
+            "domain.auto_renewal_disable" => EventData::DomainAutoRenewalDisable(from_value(data)?),
+            "domain.auto_renewal_enable" => EventData::DomainAutoRenewalEnable(from_value(data)?),
+            "domain.create" => EventData::DomainCreate(from_value(data)?),
+            "domain.delegation_change" => EventData::DomainDelegationChange(from_value(data)?),
+            "domain.delete" => EventData::DomainDelete(from_value(data)?),
+            "domain.register" => EventData::DomainRegister(from_value(data)?),
+            "domain.register:cancelled" => EventData::DomainRegisterCancelled(from_value(data)?),
+            "domain.register:started" => EventData::DomainRegisterStarted(from_value(data)?),
+            "domain.registrant_change" => EventData::DomainRegistrantChange(from_value(data)?),
+            "domain.registrant_change:cancelled" => {
+                EventData::DomainRegistrantChangeCancelled(from_value(data)?)
+            }
+            "domain.registrant_change:started" => {
+                EventData::DomainRegistrantChangeStarted(from_value(data)?)
+            }
+            "domain.renew" => EventData::DomainRenew(from_value(data)?),
+            "domain.renew:cancelled" => EventData::DomainRenewCancelled(from_value(data)?),
+            "domain.renew:started" => EventData::DomainRenewStarted(from_value(data)?),
+            "domain.resolution_disable" => EventData::DomainResolutionDisable(from_value(data)?),
+            "domain.resolution_enable" => EventData::DomainResolutionEnable(from_value(data)?),
+            "domain.restore" => EventData::DomainRestore(from_value(data)?),
+            "domain.restore:cancelled" => EventData::DomainRestoreCancelled(from_value(data)?),
+            "domain.restore:started" => EventData::DomainRestoreStarted(from_value(data)?),
+            "domain.transfer" => EventData::DomainTransfer(from_value(data)?),
+            "domain.transfer:cancelled" => EventData::DomainTransferCancelled(from_value(data)?),
+            "domain.transfer:started" => EventData::DomainTransferStarted(from_value(data)?),
+            "domain.transfer_lock_disable" => {
+                EventData::DomainTransferLockDisable(from_value(data)?)
+            }
+            "domain.transfer_lock_enable" => EventData::DomainTransferLockEnable(from_value(data)?),
+            "email_forward.activate" => EventData::EmailForwardActivate(from_value(data)?),
+            "email_forward.create" => EventData::EmailForwardCreate(from_value(data)?),
+            "email_forward.deactivate" => EventData::EmailForwardDeactivate(from_value(data)?),
+            "email_forward.delete" => EventData::EmailForwardDelete(from_value(data)?),
+            "email_forward.update" => EventData::EmailForwardUpdate(from_value(data)?),
+            "subscription.renew" => EventData::SubscriptionRenew(from_value(data)?),
+            "subscription.renew:failed" => EventData::SubscriptionRenewFailed(from_value(data)?),
+            "webhook.create" => EventData::WebhookCreate(from_value(data)?),
+            "webhook.delete" => EventData::WebhookDelete(from_value(data)?),
+            "whois_privacy.disable" => EventData::WhoisPrivacyDisable(from_value(data)?),
+            "whois_privacy.enable" => EventData::WhoisPrivacyEnable(from_value(data)?),
+            "whois_privacy.purchase" => EventData::WhoisPrivacyPurchase(from_value(data)?),
+            "whois_privacy.renew" => EventData::WhoisPrivacyRenew(from_value(data)?),
+            "zone.create" => EventData::ZoneCreate(from_value(data)?),
+            "zone.delete" => EventData::ZoneDelete(from_value(data)?),
+            "zone_record.create" => EventData::ZoneRecordCreate(from_value(data)?),
+            "zone_record.delete" => EventData::ZoneRecordDelete(from_value(data)?),
+            "zone_record.update" => EventData::ZoneRecordUpdate(from_value(data)?),
+            _ => EventData::Unknown(data),
+        };
+
+        Ok(event_data)
+    }
+}
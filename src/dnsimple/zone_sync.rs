@@ -0,0 +1,304 @@
+//! Declarative synchronization of the records of a zone.
+//!
+//! A `ZoneSync` compares a desired set of records (i.e. read from a configuration file or
+//! with `zonefile::parse`) with the records of the zone, produces a `Plan` of the records
+//! to create, update and delete, and applies it with a single batch change.
+
+use crate::dnsimple::Client;
+use crate::dnsimple::ids::AccountId;
+use crate::dnsimple::zones::Zones;
+use crate::dnsimple::zones_records::{
+    RecordType, ZoneRecord, ZoneRecordBatchChange, ZoneRecordBatchDeletePayload,
+    ZoneRecordBatchPayload, ZoneRecordBatchUpdatePayload, ZoneRecordPayload,
+};
+use crate::errors::DNSimpleError;
+use futures_util::{TryStreamExt, future};
+use std::collections::HashMap;
+
+/// The identity of a record: its name (in lowercase), type and content.
+type RecordKey = (String, RecordType, String);
+
+fn key(name: &str, record_type: &RecordType, content: &str) -> RecordKey {
+    (
+        name.to_ascii_lowercase(),
+        record_type.clone(),
+        String::from(content),
+    )
+}
+
+/// Represents an update of a record, part of a `Plan`
+#[derive(Clone, Debug)]
+pub struct PlannedUpdate {
+    /// The record as it currently is in the zone.
+    pub record: ZoneRecord,
+    /// The changed attributes (TTL, priority or regions).
+    pub changes: ZoneRecordBatchUpdatePayload,
+}
+
+/// The changes needed to bring the records of a zone to the desired state
+///
+/// The records are identified by their name, type and content: a record whose content
+/// changes is deleted and created again, while a change of TTL, priority or regions is an
+/// update. The system records (i.e. SOA and the DNSimple NS records) are never changed.
+#[derive(Clone, Debug, Default)]
+pub struct Plan {
+    /// The desired records missing from the zone.
+    pub creates: Vec<ZoneRecordPayload>,
+    /// The records of the zone with a different TTL, priority or regions.
+    pub updates: Vec<PlannedUpdate>,
+    /// The records of the zone that are not desired.
+    pub deletes: Vec<ZoneRecord>,
+}
+
+impl Plan {
+    /// Computes the plan from the desired records and the current records of the zone.
+    ///
+    /// The system records in `current` are ignored. In the desired records, a `None` TTL,
+    /// priority or regions keeps the value of the current record.
+    ///
+    /// # Arguments
+    ///
+    /// `desired`: The records the zone should have
+    /// `current`: The records the zone has
+    ///
+    /// # Errors
+    ///
+    /// `DNSimpleError::Validation` if a desired record is invalid or appears twice.
+    pub fn new(
+        desired: Vec<ZoneRecordPayload>,
+        current: Vec<ZoneRecord>,
+    ) -> Result<Plan, DNSimpleError> {
+        let mut wanted: HashMap<RecordKey, ZoneRecordPayload> = HashMap::new();
+        let mut order = Vec::new();
+        for (index, record) in desired.into_iter().enumerate() {
+            record.validate().map_err(|error| match error {
+                DNSimpleError::Validation(message) => {
+                    DNSimpleError::Validation(format!("desired[{}]: {}", index, message))
+                }
+                error => error,
+            })?;
+            let record_key = key(&record.name, &record.record_type, &record.content);
+            if wanted.contains_key(&record_key) {
+                return Err(DNSimpleError::Validation(format!(
+                    "desired[{}]: duplicate {} record `{}` with content `{}`",
+                    index, record.record_type, record.name, record.content
+                )));
+            }
+            order.push(record_key.clone());
+            wanted.insert(record_key, record);
+        }
+
+        let mut plan = Plan::default();
+        for record in current.into_iter().filter(|r| !r.system_record) {
+            let record_key = key(&record.name, &record.record_type, &record.content);
+            match wanted.remove(&record_key) {
+                Some(desired) => {
+                    if let Some(changes) = changes(&record, &desired) {
+                        plan.updates.push(PlannedUpdate { record, changes });
+                    }
+                }
+                None => plan.deletes.push(record),
+            }
+        }
+        plan.creates = order
+            .into_iter()
+            .filter_map(|record_key| wanted.remove(&record_key))
+            .collect();
+
+        Ok(plan)
+    }
+
+    /// Returns true if the zone is already in the desired state.
+    pub fn is_empty(&self) -> bool {
+        self.creates.is_empty() && self.updates.is_empty() && self.deletes.is_empty()
+    }
+
+    /// Returns the payload of the batch change applying the plan.
+    pub fn to_batch_payload(&self) -> ZoneRecordBatchPayload {
+        ZoneRecordBatchPayload {
+            creates: (!self.creates.is_empty()).then(|| self.creates.clone()),
+            updates: (!self.updates.is_empty())
+                .then(|| self.updates.iter().map(|u| u.changes.clone()).collect()),
+            deletes: (!self.deletes.is_empty()).then(|| {
+                self.deletes
+                    .iter()
+                    .map(|record| ZoneRecordBatchDeletePayload { id: record.id })
+                    .collect()
+            }),
+        }
+    }
+}
+
+/// Returns the update of the attributes that differ, if any.
+fn changes(
+    record: &ZoneRecord,
+    desired: &ZoneRecordPayload,
+) -> Option<ZoneRecordBatchUpdatePayload> {
+    let ttl = desired.ttl.filter(|ttl| *ttl != record.ttl);
+    let priority = desired
+        .priority
+        .filter(|priority| Some(*priority) != record.priority);
+    let regions = desired
+        .regions
+        .clone()
+        .filter(|regions| Some(regions) != record.regions.as_ref());
+
+    if ttl.is_none() && priority.is_none() && regions.is_none() {
+        return None;
+    }
+    Some(ZoneRecordBatchUpdatePayload {
+        id: record.id,
+        name: None,
+        content: None,
+        ttl,
+        priority,
+        regions,
+    })
+}
+
+/// The result of `ZoneSync::apply`
+#[derive(Debug)]
+pub enum SyncOutcome {
+    /// The zone was already in the desired state, nothing was sent.
+    Unchanged,
+    /// The sync is in dry-run mode, nothing was sent.
+    DryRun,
+    /// The plan was applied, with the records created, updated and deleted.
+    Applied(ZoneRecordBatchChange),
+}
+
+/// Synchronizes the records of a zone with a desired set of records
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::zonefile;
+/// use dnsimple::dnsimple::zone_sync::SyncOutcome;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+///     let desired = zonefile::parse("example.com", "www 3600 IN A 192.0.2.1\n").unwrap();
+///     let sync = client.zones().zone_sync(1234, "example.com").max_deletions(10);
+///
+///     let plan = sync.plan(desired.into_iter().map(Into::into).collect()).await.unwrap();
+///     println!("{} creates, {} updates, {} deletes", plan.creates.len(), plan.updates.len(), plan.deletes.len());
+///     match sync.apply(&plan).await.unwrap() {
+///         SyncOutcome::Applied(changes) => println!("{} records created", changes.creates.len()),
+///         _ => println!("nothing to do"),
+///     }
+/// }
+/// ```
+#[derive(Clone)]
+pub struct ZoneSync {
+    client: Client,
+    account_id: AccountId,
+    zone: String,
+    dry_run: bool,
+    max_deletions: Option<usize>,
+}
+
+impl ZoneSync {
+    /// Creates the sync of the zone, applying the plans without any limit of deletions.
+    ///
+    /// # Arguments
+    ///
+    /// `client`: The client to call the API with
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    pub fn new(client: Client, account_id: impl Into<AccountId>, zone: &str) -> Self {
+        ZoneSync {
+            client,
+            account_id: account_id.into(),
+            zone: String::from(zone),
+            dry_run: false,
+            max_deletions: None,
+        }
+    }
+
+    /// In dry-run mode, `apply` checks the plan without changing the zone.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Refuses to apply the plans deleting more than `max_deletions` records, to protect
+    /// against an incomplete set of desired records.
+    pub fn max_deletions(mut self, max_deletions: usize) -> Self {
+        self.max_deletions = Some(max_deletions);
+        self
+    }
+
+    /// Fetches the records of the zone and computes the plan to reach the desired records.
+    ///
+    /// See `Plan::new`.
+    ///
+    /// # Arguments
+    ///
+    /// `desired`: The records the zone should have (without the system records)
+    pub async fn plan(&self, desired: Vec<ZoneRecordPayload>) -> Result<Plan, DNSimpleError> {
+        let current: Vec<ZoneRecord> = self
+            .client
+            .zones()
+            .list_zone_records_stream(self.account_id, &self.zone, None)
+            .try_filter(|record| future::ready(!record.system_record))
+            .try_collect()
+            .await?;
+
+        Plan::new(desired, current)
+    }
+
+    /// Applies the plan in a single batch change, so either all or none of the changes are made.
+    ///
+    /// # Arguments
+    ///
+    /// `plan`: The plan returned by `plan`
+    ///
+    /// # Errors
+    ///
+    /// `DNSimpleError::Validation` if the plan deletes more records than allowed by
+    /// `max_deletions` (checked in dry-run mode too), or the error of the batch change.
+    pub async fn apply(&self, plan: &Plan) -> Result<SyncOutcome, DNSimpleError> {
+        if let Some(max_deletions) = self.max_deletions {
+            if plan.deletes.len() > max_deletions {
+                return Err(DNSimpleError::Validation(format!(
+                    "the plan deletes {} records from {}, more than the maximum of {}",
+                    plan.deletes.len(),
+                    self.zone,
+                    max_deletions
+                )));
+            }
+        }
+        if plan.is_empty() {
+            return Ok(SyncOutcome::Unchanged);
+        }
+        if self.dry_run {
+            return Ok(SyncOutcome::DryRun);
+        }
+
+        let response = self
+            .client
+            .zones()
+            .batch_change_zone_records(self.account_id, &self.zone, plan.to_batch_payload())
+            .await?;
+        match response.data {
+            Some(changes) => Ok(SyncOutcome::Applied(changes)),
+            None => Err(DNSimpleError::Deserialization(String::from(
+                "Missing batch change in the response",
+            ))),
+        }
+    }
+}
+
+impl Zones {
+    /// Creates a `ZoneSync` to synchronize the records of the zone with a desired set of records
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    pub fn zone_sync(&self, account_id: impl Into<AccountId>, zone: &str) -> ZoneSync {
+        ZoneSync::new(self.client.clone(), account_id, zone)
+    }
+}
@@ -0,0 +1,620 @@
+//! Parsing and rendering of RFC 1035 zone files.
+//!
+//! `parse` reads a zone file (i.e. a legacy BIND zone) into `ZoneFileRecord`s, which convert
+//! into the `ZoneRecordPayload` used to create the records in DNSimple. `render` writes the
+//! records of a zone as a canonical zone file, in the format of `Zones::get_zone_file`.
+//!
+//! The contents follow the DNSimple conventions: the names in the contents are absolute,
+//! without the trailing dot, and the priority of the `MX` and `SRV` records is kept apart.
+
+use crate::dnsimple::zones_records::{RecordType, ZoneRecord, ZoneRecordPayload};
+use crate::errors::DNSimpleError;
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// The maximum length, in bytes, of a character-string in a TXT record.
+const TXT_STRING_MAX_LENGTH: usize = 255;
+
+/// Represents a record parsed from a zone file
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ZoneFileRecord {
+    /// The record name, relative to the zone (`""` for the apex).
+    pub name: String,
+    /// The TTL of the record, or the `$TTL` in effect (if any).
+    pub ttl: Option<u64>,
+    /// The type of record.
+    pub record_type: RecordType,
+    /// The record content, in the DNSimple format (i.e. `"10 5060 sip.example.com"` for a SRV record).
+    pub content: String,
+    /// The priority of the `MX` and `SRV` records.
+    pub priority: Option<u64>,
+}
+
+impl From<ZoneFileRecord> for ZoneRecordPayload {
+    fn from(record: ZoneFileRecord) -> Self {
+        ZoneRecordPayload {
+            name: record.name,
+            record_type: record.record_type,
+            content: record.content,
+            ttl: record.ttl,
+            priority: record.priority,
+            regions: None,
+        }
+    }
+}
+
+/// Parses a zone file into its records
+///
+/// Supports the `$ORIGIN` and `$TTL` directives, relative names (and `@`), omitted owners,
+/// records spanning several lines within parentheses (i.e. the SOA record), comments and
+/// quoted strings. The class is optional, and must be `IN` when present.
+///
+/// # Examples
+///
+/// ```
+/// use dnsimple::dnsimple::zonefile;
+/// use dnsimple::dnsimple::zones_records::RecordType;
+///
+/// let records = zonefile::parse("example.com", "$TTL 3600\nwww IN A 192.0.2.1\n@ MX 10 mail\n").unwrap();
+///
+/// assert_eq!("www", records[0].name);
+/// assert_eq!(Some(3600), records[0].ttl);
+/// assert_eq!(RecordType::Mx, records[1].record_type);
+/// assert_eq!("mail.example.com", records[1].content);
+/// assert_eq!(Some(10), records[1].priority);
+/// ```
+///
+/// # Arguments
+///
+/// `zone`: The zone name, used as the initial origin and to make the record names relative
+/// `text`: The content of the zone file
+///
+/// # Errors
+///
+/// `DNSimpleError::ZoneFile` with the line of the first invalid entry, i.e. for a malformed
+/// record, an unsupported directive (`$INCLUDE`, `$GENERATE`) or a name outside of the zone.
+pub fn parse(zone: &str, text: &str) -> Result<Vec<ZoneFileRecord>, DNSimpleError> {
+    let zone = normalize_name(zone);
+    let mut origin = zone.clone();
+    let mut default_ttl = None;
+    let mut owner: Option<String> = None;
+    let mut records = Vec::new();
+
+    for entry in tokenize(text)? {
+        let invalid = |message: String| DNSimpleError::ZoneFile {
+            line: entry.line,
+            message,
+        };
+        let mut tokens = entry.tokens.iter().peekable();
+
+        if !entry.owner_omitted {
+            let first = tokens
+                .next()
+                .ok_or_else(|| invalid(String::from("empty entry")))?;
+            if !first.quoted && first.text.starts_with('$') {
+                let argument = tokens.next().ok_or_else(|| {
+                    invalid(format!("the {} directive requires an argument", first.text))
+                })?;
+                match first.text.to_ascii_uppercase().as_str() {
+                    "$ORIGIN" => origin = absolute_name(&argument.text, &origin),
+                    "$TTL" => {
+                        let ttl = parse_ttl(&argument.text)
+                            .ok_or_else(|| invalid(format!("invalid TTL `{}`", argument.text)))?;
+                        default_ttl = Some(ttl);
+                    }
+                    directive => {
+                        return Err(invalid(format!("unsupported directive {}", directive)));
+                    }
+                }
+                continue;
+            }
+            owner = Some(absolute_name(&first.text, &origin));
+        }
+        let name = match &owner {
+            Some(owner) => relative_name(owner, &zone).ok_or_else(|| {
+                invalid(format!(
+                    "the name `{}` is outside of the zone {}",
+                    owner, zone
+                ))
+            })?,
+            None => return Err(invalid(String::from("the first record has no owner"))),
+        };
+
+        let mut ttl = None;
+        let mut class = false;
+        while let Some(token) = tokens.peek() {
+            if ttl.is_none() && !token.quoted {
+                if let Some(value) = parse_ttl(&token.text) {
+                    ttl = Some(value);
+                    tokens.next();
+                    continue;
+                }
+            }
+            if !class && !token.quoted {
+                match token.text.to_ascii_uppercase().as_str() {
+                    "IN" => {
+                        class = true;
+                        tokens.next();
+                        continue;
+                    }
+                    "CH" | "HS" => {
+                        return Err(invalid(format!("unsupported class {}", token.text)));
+                    }
+                    _ => {}
+                }
+            }
+            break;
+        }
+
+        let record_type = match tokens.next() {
+            Some(token) => RecordType::from(token.text.as_str()),
+            None => return Err(invalid(String::from("missing record type"))),
+        };
+        let rdata: Vec<&Token> = tokens.collect();
+        let (content, priority) = parse_rdata(&record_type, &rdata, &origin).map_err(invalid)?;
+
+        records.push(ZoneFileRecord {
+            name,
+            ttl: ttl.or(default_ttl),
+            record_type,
+            content,
+            priority,
+        });
+    }
+
+    Ok(records)
+}
+
+/// Renders the records of a zone as a canonical zone file
+///
+/// The file starts with the `$ORIGIN` and `$TTL` directives, followed by one line per record
+/// with its absolute name, TTL, class, type and data. The SOA record comes first, then the
+/// records are sorted by name, type, priority and content, so the same records always render
+/// to the same file, whatever the order they are given in.
+///
+/// The `ALIAS`, `POOL` and `URL` records are resolved by DNSimple and have no equivalent in
+/// the other DNS servers, so they are rendered as comments (starting with `;`).
+///
+/// # Examples
+///
+/// ```no_run
+/// use dnsimple::dnsimple::new_client;
+/// use dnsimple::dnsimple::zonefile;
+///
+/// #[tokio::main(flavor = "current_thread")]
+/// async fn main() {
+///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+///     let records = client.zones().list_zone_records(1234, "example.com", None).await.unwrap().data.unwrap();
+///
+///     println!("{}", zonefile::render("example.com", &records));
+/// }
+/// ```
+///
+/// # Arguments
+///
+/// `zone`: The zone name
+/// `records`: The records of the zone
+pub fn render(zone: &str, records: &[ZoneRecord]) -> String {
+    let zone = normalize_name(zone);
+    let mut sorted: Vec<&ZoneRecord> = records.iter().collect();
+    sorted.sort_by(|a, b| {
+        let key = |record: &ZoneRecord| {
+            (
+                record.record_type != RecordType::Soa,
+                record.name.to_ascii_lowercase(),
+                record.record_type.to_string(),
+                record.priority,
+                record.content.clone(),
+            )
+        };
+        key(a).cmp(&key(b))
+    });
+
+    let mut file = format!("$ORIGIN {}.\n$TTL 1h\n", zone);
+    for record in sorted {
+        let owner = match record.name.as_str() {
+            "" => format!("{}.", zone),
+            name => format!("{}.{}.", name.to_ascii_lowercase(), zone),
+        };
+        let comment = match is_dnsimple_only(&record.record_type) {
+            true => "; ",
+            false => "",
+        };
+        let _ = writeln!(
+            file,
+            "{}{} {} IN {} {}",
+            comment,
+            owner,
+            record.ttl,
+            record.record_type,
+            render_rdata(record)
+        );
+    }
+    file
+}
+
+/// Returns true for the pseudo record types only served by DNSimple.
+fn is_dnsimple_only(record_type: &RecordType) -> bool {
+    matches!(
+        record_type,
+        RecordType::Alias | RecordType::Pool | RecordType::Url
+    )
+}
+
+/// A word or quoted string of a zone file.
+#[derive(Debug)]
+struct Token {
+    /// The text, without the quotes and escapes of a quoted string.
+    text: String,
+    /// True if the token is a quoted string.
+    quoted: bool,
+}
+
+impl Token {
+    /// Returns the token as written in the zone file.
+    fn to_rdata(&self) -> String {
+        match self.quoted {
+            true => quote(&self.text),
+            false => self.text.clone(),
+        }
+    }
+}
+
+/// The tokens of an entry (a record or a directive), which can span several lines.
+#[derive(Debug)]
+struct Entry {
+    /// The line the entry starts at.
+    line: usize,
+    /// True if the entry starts with a blank, so the owner of the previous record applies.
+    owner_omitted: bool,
+    tokens: Vec<Token>,
+}
+
+/// Splits a zone file into entries, dropping the comments and joining the lines
+/// within parentheses.
+fn tokenize(text: &str) -> Result<Vec<Entry>, DNSimpleError> {
+    let mut entries = Vec::new();
+    let mut entry = Entry {
+        line: 1,
+        owner_omitted: false,
+        tokens: Vec::new(),
+    };
+    let mut line = 1;
+    let mut line_start = true;
+    let mut depth = 0;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\n' => {
+                if depth == 0 {
+                    let next = Entry {
+                        line: line + 1,
+                        owner_omitted: false,
+                        tokens: Vec::new(),
+                    };
+                    let done = std::mem::replace(&mut entry, next);
+                    if !done.tokens.is_empty() {
+                        entries.push(done);
+                    }
+                    line_start = true;
+                }
+                line += 1;
+            }
+            c if c.is_whitespace() => {
+                if line_start && entry.tokens.is_empty() {
+                    entry.owner_omitted = true;
+                }
+            }
+            ';' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '(' => {
+                depth += 1;
+                line_start = false;
+            }
+            ')' => {
+                if depth == 0 {
+                    return Err(DNSimpleError::ZoneFile {
+                        line,
+                        message: String::from("unbalanced parenthesis"),
+                    });
+                }
+                depth -= 1;
+            }
+            '"' => {
+                let start = line;
+                // The octets of the string, as a `\DDD` escape can be any octet.
+                let mut bytes = Vec::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') => match chars.next() {
+                            Some(digit) if digit.is_ascii_digit() => {
+                                let octet = decimal_escape(digit, &mut chars).ok_or_else(|| {
+                                    DNSimpleError::ZoneFile {
+                                        line,
+                                        message: String::from(
+                                            "invalid escape, expected \\DDD with DDD up to 255",
+                                        ),
+                                    }
+                                })?;
+                                bytes.push(octet);
+                            }
+                            Some(escaped) => {
+                                if escaped == '\n' {
+                                    line += 1;
+                                }
+                                bytes
+                                    .extend_from_slice(escaped.encode_utf8(&mut [0; 4]).as_bytes());
+                            }
+                            None => {}
+                        },
+                        Some(c) => {
+                            if c == '\n' {
+                                line += 1;
+                            }
+                            bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                        }
+                        None => {
+                            return Err(DNSimpleError::ZoneFile {
+                                line: start,
+                                message: String::from("unterminated quoted string"),
+                            });
+                        }
+                    }
+                }
+                let text = String::from_utf8(bytes).map_err(|_| DNSimpleError::ZoneFile {
+                    line: start,
+                    message: String::from("quoted string is not valid UTF-8"),
+                })?;
+                entry.tokens.push(Token { text, quoted: true });
+                line_start = false;
+            }
+            c => {
+                let mut text = String::from(c);
+                while let Some(c) =
+                    chars.next_if(|&c| !c.is_whitespace() && !matches!(c, ';' | '(' | ')' | '"'))
+                {
+                    text.push(c);
+                }
+                entry.tokens.push(Token {
+                    text,
+                    quoted: false,
+                });
+                line_start = false;
+            }
+        }
+    }
+
+    if depth > 0 {
+        return Err(DNSimpleError::ZoneFile {
+            line: entry.line,
+            message: String::from("unbalanced parenthesis"),
+        });
+    }
+    if !entry.tokens.is_empty() {
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Converts the data of a record into the DNSimple content and priority.
+fn parse_rdata(
+    record_type: &RecordType,
+    rdata: &[&Token],
+    origin: &str,
+) -> Result<(String, Option<u64>), String> {
+    let required = |count: usize, fields: &str| match rdata.len() >= count {
+        true => Ok(()),
+        false => Err(format!("{} record requires {}", record_type, fields)),
+    };
+    let number = |token: &Token, field: &str| {
+        token
+            .text
+            .parse::<u64>()
+            .map_err(|_| format!("invalid {} `{}`", field, token.text))
+    };
+
+    match record_type {
+        RecordType::Mx => {
+            required(2, "a preference and an exchange")?;
+            let priority = number(rdata[0], "preference")?;
+            Ok((absolute_name(&rdata[1].text, origin), Some(priority)))
+        }
+        RecordType::Srv => {
+            required(4, "a priority, a weight, a port and a target")?;
+            let priority = number(rdata[0], "priority")?;
+            let weight = number(rdata[1], "weight")?;
+            let port = number(rdata[2], "port")?;
+            let target = absolute_name(&rdata[3].text, origin);
+            Ok((format!("{} {} {}", weight, port, target), Some(priority)))
+        }
+        RecordType::Cname | RecordType::Ns | RecordType::Ptr | RecordType::Alias => {
+            required(1, "a name")?;
+            Ok((absolute_name(&rdata[0].text, origin), None))
+        }
+        RecordType::Soa => {
+            required(7, "a name server, a mailbox, a serial and 4 timers")?;
+            let mut fields = vec![
+                absolute_name(&rdata[0].text, origin),
+                absolute_name(&rdata[1].text, origin),
+            ];
+            for token in &rdata[2..7] {
+                fields.push(number(token, "SOA field")?.to_string());
+            }
+            Ok((fields.join(" "), None))
+        }
+        RecordType::Txt | RecordType::Spf => {
+            required(1, "a string")?;
+            if let [string] = rdata {
+                return Ok((string.text.clone(), None));
+            }
+            let strings: Vec<String> = rdata.iter().map(|token| quote(&token.text)).collect();
+            Ok((strings.join(" "), None))
+        }
+        _ => {
+            required(1, "data")?;
+            let fields: Vec<String> = rdata.iter().map(|token| token.to_rdata()).collect();
+            Ok((fields.join(" "), None))
+        }
+    }
+}
+
+/// Converts the content and priority of a record into the data of a zone file.
+fn render_rdata(record: &ZoneRecord) -> String {
+    let content = record.content.as_str();
+    match record.record_type {
+        RecordType::Mx => format!("{} {}", record.priority.unwrap_or(0), fqdn(content)),
+        RecordType::Srv => {
+            let fields: Vec<&str> = content.split_whitespace().collect();
+            match fields.as_slice() {
+                [weight, port, target] => format!(
+                    "{} {} {} {}",
+                    record.priority.unwrap_or(0),
+                    weight,
+                    port,
+                    fqdn(target)
+                ),
+                _ => format!("{} {}", record.priority.unwrap_or(0), content),
+            }
+        }
+        RecordType::Cname | RecordType::Ns | RecordType::Ptr | RecordType::Alias => fqdn(content),
+        RecordType::Soa => {
+            let mut fields: Vec<String> = content.split_whitespace().map(String::from).collect();
+            for field in fields.iter_mut().take(2) {
+                *field = fqdn(field);
+            }
+            fields.join(" ")
+        }
+        RecordType::Txt | RecordType::Spf if !content.starts_with('"') => {
+            let mut strings = Vec::new();
+            let mut string = String::new();
+            for c in content.chars() {
+                if string.len() + c.len_utf8() > TXT_STRING_MAX_LENGTH {
+                    strings.push(quote(&string));
+                    string.clear();
+                }
+                string.push(c);
+            }
+            strings.push(quote(&string));
+            strings.join(" ")
+        }
+        _ => String::from(content),
+    }
+}
+
+/// Returns the name in lowercase, without the trailing dot.
+fn normalize_name(name: &str) -> String {
+    name.trim_end_matches('.').to_ascii_lowercase()
+}
+
+/// Returns the absolute name (without the trailing dot) of a name relative to the origin.
+fn absolute_name(name: &str, origin: &str) -> String {
+    if name == "@" {
+        String::from(origin)
+    } else if name.ends_with('.') || origin.is_empty() {
+        normalize_name(name)
+    } else {
+        format!("{}.{}", name.to_ascii_lowercase(), origin)
+    }
+}
+
+/// Returns the name relative to the zone (`""` for the apex), or `None` if the name is
+/// outside of the zone. The names are compared ignoring the case and the trailing dot.
+pub(crate) fn relative_name(name: &str, zone: &str) -> Option<String> {
+    let (name, zone) = (normalize_name(name), normalize_name(zone));
+    if name == zone {
+        return Some(String::new());
+    }
+    name.strip_suffix(&zone)?
+        .strip_suffix('.')
+        .filter(|relative| !relative.is_empty())
+        .map(String::from)
+}
+
+/// Returns the name with a trailing dot.
+fn fqdn(name: &str) -> String {
+    match name.ends_with('.') || name.is_empty() {
+        true => String::from(name),
+        false => format!("{}.", name),
+    }
+}
+
+/// Parses a TTL in seconds (`3600`) or with units (`1h`, `1h30m`, `1w`).
+fn parse_ttl(text: &str) -> Option<u64> {
+    if let Ok(seconds) = text.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let mut total = 0u64;
+    let mut value: Option<u64> = None;
+    for c in text.chars() {
+        if let Some(digit) = c.to_digit(10) {
+            value = Some(
+                value
+                    .unwrap_or(0)
+                    .checked_mul(10)?
+                    .checked_add(u64::from(digit))?,
+            );
+            continue;
+        }
+        let unit = match c.to_ascii_lowercase() {
+            's' => 1,
+            'm' => 60,
+            'h' => 3600,
+            'd' => 86400,
+            'w' => 604800,
+            _ => return None,
+        };
+        total = total.checked_add(value.take()?.checked_mul(unit)?)?;
+    }
+    match value {
+        Some(_) => None,
+        None if total > 0 || text.starts_with('0') => Some(total),
+        None => None,
+    }
+}
+
+/// Reads the two last digits of a `\DDD` escape (RFC 1035), returning the octet it stands for.
+fn decimal_escape(first: char, chars: &mut Peekable<Chars>) -> Option<u8> {
+    let mut value = first.to_digit(10)?;
+    for _ in 0..2 {
+        value = value * 10 + chars.next_if(char::is_ascii_digit)?.to_digit(10)?;
+    }
+    u8::try_from(value).ok()
+}
+
+/// Quotes a character-string, escaping the quotes and backslashes.
+fn quote(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dnsimple::zonefile::{parse_ttl, relative_name};
+
+    #[test]
+    fn parse_ttl_supports_units() {
+        assert_eq!(Some(3600), parse_ttl("3600"));
+        assert_eq!(Some(3600), parse_ttl("1h"));
+        assert_eq!(Some(5400), parse_ttl("1H30m"));
+        assert_eq!(Some(604800), parse_ttl("1w"));
+        assert_eq!(None, parse_ttl("IN"));
+        assert_eq!(None, parse_ttl("1h30"));
+        assert_eq!(None, parse_ttl("h"));
+    }
+
+    #[test]
+    fn relative_name_ignores_the_case_and_the_trailing_dot() {
+        assert_eq!(
+            Some(String::new()),
+            relative_name("example.com", "Example.COM.")
+        );
+        assert_eq!(
+            Some(String::from("www")),
+            relative_name("WWW.example.com.", "example.com")
+        );
+        assert_eq!(None, relative_name("example.com", "www.example.com"));
+        assert_eq!(None, relative_name("badexample.com", "example.com"));
+    }
+}
@@ -1,5 +1,10 @@
+use crate::dnsimple::ids::{AccountId, NameServerSetId, ZoneId};
+use crate::dnsimple::list_options::list_options;
+use crate::dnsimple::zones_records::ZoneRecord;
 use crate::dnsimple::{Client, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -7,19 +12,21 @@ use serde_json::Value;
 #[derive(Debug, Deserialize, Serialize)]
 pub struct Zone {
     /// The zone ID in DNSimple.
-    pub id: u64,
+    pub id: ZoneId,
     ///  The associated account ID.
-    pub account_id: u64,
+    pub account_id: AccountId,
     /// The zone name.
     pub name: String,
     /// True if the zone is a reverse zone.
     pub reverse: bool,
     /// True if the zone is a secondary zone.
+    #[serde(default)]
     pub secondary: bool,
     /// Last time the zone was transferred.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_transferred_at: Option<String>,
     /// True if the zone is active.
+    #[serde(default)]
     pub active: bool,
     ///  When the zone was created in DNSimple.
     pub created_at: String,
@@ -28,6 +35,8 @@ pub struct Zone {
 }
 
 /// Represents a zone file in DNSimple
+///
+/// The records of the file can be read with `zonefile::parse`.
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ZoneFile {
     /// The zone file contents.
@@ -41,6 +50,39 @@ pub struct ZoneDistribution {
     pub distributed: bool,
 }
 
+/// Represents the payload to be send to update the NS records of a zone
+///
+/// The name servers can be given explicitly with `ns_names`, or through the IDs
+/// of name server sets with `ns_set_ids` (or both).
+#[derive(Debug, Default, Deserialize, Serialize)]
+pub struct ZoneNsRecordsPayload {
+    /// The name server names.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ns_names: Option<Vec<String>>,
+    /// The IDs of the name server sets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ns_set_ids: Option<Vec<NameServerSetId>>,
+}
+
+list_options! {
+    /// The options to list the zones
+    ZoneListOptions;
+    /// The fields the zones can be sorted by
+    ZoneSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by name.
+        Name => "name",
+    }
+}
+
+impl ZoneListOptions {
+    /// Only lists the zones whose name contains `name`.
+    pub fn name_like(self, name: &str) -> Self {
+        self.filter("name_like", name)
+    }
+}
+
 struct ListZonesEndpoint;
 
 impl Endpoint for ListZonesEndpoint {
@@ -71,6 +113,12 @@ impl Endpoint for ZoneFileEndpoint {
     type Output = ZoneFile;
 }
 
+struct ZoneNsRecordsEndpoint;
+
+impl Endpoint for ZoneNsRecordsEndpoint {
+    type Output = Vec<ZoneRecord>;
+}
+
 pub(crate) struct DistributionEndpoint;
 
 impl Endpoint for DistributionEndpoint {
@@ -80,11 +128,11 @@ impl Endpoint for DistributionEndpoint {
 /// The Zones Service handles the zone distribution of the DNSimple API.
 ///
 /// See [API Documentation: zones](https://developer.dnsimple.com/v2/zones/)
-pub struct Zones<'a> {
-    pub client: &'a Client,
+pub struct Zones {
+    pub client: Client,
 }
 
-impl Zones<'_> {
+impl Zones {
     /// Activates DNS resolution for the zone in the account.
     ///
     /// # Arguments
@@ -93,9 +141,10 @@ impl Zones<'_> {
     /// `zone_name`: The zone name
     pub async fn activate_dns(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone_name: &str,
     ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/activation", account_id, zone_name);
 
         self.client
@@ -111,9 +160,10 @@ impl Zones<'_> {
     /// `zone_name`: The zone name
     pub async fn deactivate_dns(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone_name: &str,
     ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/activation", account_id, zone_name);
 
         self.client
@@ -126,16 +176,39 @@ impl Zones<'_> {
     /// # Arguments
     ///
     /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `ZoneListOptions`
     pub async fn list_zones(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<Zone>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones", account_id);
 
         self.client.get::<ListZonesEndpoint>(&path, options).await
     }
 
+    /// Lists all the zones in the account, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `RequestOptions`, i.e. built with `ZoneListOptions`
+    pub fn list_zones_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<Zone, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/zones", account_id);
+
+        self.client
+            .paginate::<ListZonesEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Retrieve a zone
     ///
     /// # Arguments
@@ -144,9 +217,10 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub async fn get_zone(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
     ) -> Result<DNSimpleResponse<Zone>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}", account_id, zone);
 
         self.client.get::<ZoneEndpoint>(&path, None).await
@@ -160,9 +234,10 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub async fn get_zone_file(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
     ) -> Result<DNSimpleResponse<ZoneFile>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/file", account_id, zone);
 
         self.client.get::<ZoneFileEndpoint>(&path, None).await
@@ -176,11 +251,54 @@ impl Zones<'_> {
     /// `zone`: The zone name
     pub async fn check_zone_distribution(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
     ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/distribution", account_id, zone);
 
         self.client.get::<DistributionEndpoint>(&path, None).await
     }
+
+    /// Update the zone NS records
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::ids::NameServerSetId;
+    /// use dnsimple::dnsimple::zones::ZoneNsRecordsPayload;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let payload = ZoneNsRecordsPayload {
+    ///         ns_names: Some(vec![String::from("ns1.example.com"), String::from("ns2.example.com")]),
+    ///         ns_set_ids: Some(vec![NameServerSetId(1), NameServerSetId(2)]),
+    ///     };
+    ///     let ns_records = client.zones().update_zone_ns_records(1234, "example.com", payload).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `payload`: The `ZoneNsRecordsPayload` with the name servers and/or name server sets
+    pub async fn update_zone_ns_records(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: &str,
+        payload: ZoneNsRecordsPayload,
+    ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/zones/{}/ns_records", account_id, zone);
+
+        match serde_json::to_value(payload) {
+            Ok(json) => self.client.put::<ZoneNsRecordsEndpoint>(&path, json).await,
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
 }
@@ -0,0 +1,228 @@
+use crate::dnsimple::ids::AccountId;
+use crate::dnsimple::list_options::{SortCriteria, sort_fields};
+use crate::dnsimple::zones::Zones;
+use crate::dnsimple::{DNSimpleResponse, Endpoint, Filters, Paginate, RequestOptions, Sort};
+use crate::errors::DNSimpleError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+/// The columns the DNS analytics data can be grouped by
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DnsAnalyticsGrouping {
+    /// Group the query volume by zone name.
+    ZoneName,
+    /// Group the query volume by date.
+    Date,
+}
+
+impl fmt::Display for DnsAnalyticsGrouping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DnsAnalyticsGrouping::ZoneName => write!(f, "zone_name"),
+            DnsAnalyticsGrouping::Date => write!(f, "date"),
+        }
+    }
+}
+
+sort_fields! {
+    /// The columns the DNS analytics data can be sorted by
+    DnsAnalyticsSortField {
+        /// Sort by zone name.
+        ZoneName => "zone_name",
+        /// Sort by date.
+        Date => "date",
+        /// Sort by query volume.
+        Volume => "volume",
+    }
+}
+
+/// The options to query the DNS analytics data
+///
+/// # Examples
+///
+/// ```
+/// use dnsimple::dnsimple::zones_analytics::{DnsAnalyticsGrouping, DnsAnalyticsOptions, DnsAnalyticsSortField};
+///
+/// let options = DnsAnalyticsOptions {
+///     groupings: vec![DnsAnalyticsGrouping::ZoneName, DnsAnalyticsGrouping::Date],
+///     start_date: Some(String::from("2023-12-08")),
+///     end_date: Some(String::from("2024-01-08")),
+///     sort: vec![DnsAnalyticsSortField::Volume.desc()],
+///     paginate: None,
+/// };
+/// ```
+#[derive(Debug, Default)]
+pub struct DnsAnalyticsOptions {
+    /// The columns to group the query volume by.
+    pub groupings: Vec<DnsAnalyticsGrouping>,
+    /// Only include data from this date (in the `YYYY-MM-DD` format).
+    pub start_date: Option<String>,
+    /// Only include data until this date (in the `YYYY-MM-DD` format).
+    pub end_date: Option<String>,
+    /// The sorting criteria, in order of priority.
+    pub sort: Vec<SortCriteria<DnsAnalyticsSortField>>,
+    /// Pagination options
+    pub paginate: Option<Paginate>,
+}
+
+impl From<DnsAnalyticsOptions> for RequestOptions {
+    fn from(options: DnsAnalyticsOptions) -> Self {
+        let mut filters = HashMap::new();
+
+        if !options.groupings.is_empty() {
+            let groupings: Vec<String> = options.groupings.iter().map(|g| g.to_string()).collect();
+            filters.insert(String::from("groupings"), groupings.join(","));
+        }
+        if let Some(start_date) = options.start_date {
+            filters.insert(String::from("start_date"), start_date);
+        }
+        if let Some(end_date) = options.end_date {
+            filters.insert(String::from("end_date"), end_date);
+        }
+
+        let sort = match options.sort.is_empty() {
+            true => None,
+            false => {
+                let sort: Vec<String> = options.sort.iter().map(|s| s.to_string()).collect();
+                Some(Sort::new(sort.join(",")))
+            }
+        };
+        let filters = match filters.is_empty() {
+            true => None,
+            false => Some(Filters::new(filters)),
+        };
+
+        RequestOptions {
+            filters,
+            sort,
+            paginate: options.paginate,
+        }
+    }
+}
+
+/// Represents a row of DNS analytics data
+///
+/// The columns that were not part of the requested groupings are `None`.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DnsAnalytics {
+    /// The zone name.
+    pub zone_name: Option<String>,
+    /// The date (in the `YYYY-MM-DD` format).
+    pub date: Option<String>,
+    /// The number of queries.
+    pub volume: u64,
+}
+
+/// Represents the query the DNS analytics data was calculated with
+#[derive(Debug, Deserialize, Serialize)]
+pub struct DnsAnalyticsQuery {
+    /// The account ID.
+    pub account_id: AccountId,
+    /// The start date of the query.
+    pub start_date: Option<String>,
+    /// The end date of the query.
+    pub end_date: Option<String>,
+    /// The sorting criteria.
+    pub sort: Option<String>,
+    /// The page number.
+    pub page: u64,
+    /// The number of rows per page.
+    pub per_page: u64,
+    /// The groupings.
+    pub groupings: Option<String>,
+}
+
+/// Represents the result of a DNS analytics query
+#[derive(Debug, Deserialize)]
+#[serde(try_from = "DnsAnalyticsTable")]
+pub struct DnsAnalyticsData {
+    /// The rows of DNS analytics data.
+    pub rows: Vec<DnsAnalytics>,
+    /// The query the data was calculated with.
+    pub query: Option<DnsAnalyticsQuery>,
+}
+
+#[derive(Deserialize)]
+struct DnsAnalyticsTable {
+    headers: Vec<String>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl TryFrom<DnsAnalyticsTable> for DnsAnalyticsData {
+    type Error = serde_json::Error;
+
+    fn try_from(table: DnsAnalyticsTable) -> Result<Self, Self::Error> {
+        let rows = table
+            .rows
+            .into_iter()
+            .map(|row| {
+                let columns: Map<String, Value> = table.headers.iter().cloned().zip(row).collect();
+                serde_json::from_value(Value::Object(columns))
+            })
+            .collect::<Result<Vec<DnsAnalytics>, _>>()?;
+
+        Ok(DnsAnalyticsData { rows, query: None })
+    }
+}
+
+struct DnsAnalyticsEndpoint;
+
+impl Endpoint for DnsAnalyticsEndpoint {
+    type Output = DnsAnalyticsData;
+}
+
+impl Zones {
+    /// Query the DNS analytics data (query volume) of the account
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::zones_analytics::{DnsAnalyticsGrouping, DnsAnalyticsOptions};
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let options = DnsAnalyticsOptions {
+    ///         groupings: vec![DnsAnalyticsGrouping::ZoneName],
+    ///         ..Default::default()
+    ///     };
+    ///     let analytics = client.zones().dns_analytics(1234, Some(options)).await.unwrap().data.unwrap();
+    ///
+    ///     for row in analytics.rows {
+    ///         println!("{:?}: {}", row.zone_name, row.volume);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `options`: The `DnsAnalyticsOptions` (groupings, date range, sorting and pagination)
+    pub async fn dns_analytics(
+        &self,
+        account_id: impl Into<AccountId>,
+        options: Option<DnsAnalyticsOptions>,
+    ) -> Result<DNSimpleResponse<DnsAnalyticsData>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/dns_analytics", account_id);
+
+        let mut response = self
+            .client
+            .get::<DnsAnalyticsEndpoint>(&path, options.map(RequestOptions::from))
+            .await?;
+
+        if let (Some(data), Some(body)) = (response.data.as_mut(), response.body.as_ref()) {
+            data.query = body
+                .get("query")
+                .cloned()
+                .map(serde_json::from_value)
+                .transpose()
+                .map_err(|e| DNSimpleError::Deserialization(e.to_string()))?;
+        }
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,240 @@
+use crate::dnsimple::DNSimpleResponse;
+use crate::dnsimple::ids::{AccountId, ZoneRecordId};
+use crate::dnsimple::zones::{ZoneDistribution, Zones};
+use crate::errors::DNSimpleError;
+use futures_util::future;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// How to poll the distribution of a zone or of records
+///
+/// The first check is made right away. The interval between checks starts at `interval`,
+/// is multiplied by `backoff` after every check, and is capped at `max_interval`.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use dnsimple::dnsimple::zones_distribution::DistributionPolling;
+///
+/// let polling = DistributionPolling {
+///     timeout: Duration::from_secs(60),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct DistributionPolling {
+    /// The time to wait after the first check (default: 2s).
+    pub interval: Duration,
+    /// The factor the interval is multiplied by after every check (default: 1.5).
+    pub backoff: f64,
+    /// The maximum time between two checks (default: 30s).
+    pub max_interval: Duration,
+    /// The time after which to stop waiting (default: 5 minutes).
+    pub timeout: Duration,
+}
+
+impl Default for DistributionPolling {
+    fn default() -> Self {
+        DistributionPolling {
+            interval: Duration::from_secs(2),
+            backoff: 1.5,
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl DistributionPolling {
+    /// Returns the interval following `interval`.
+    fn next_interval(&self, interval: Duration) -> Duration {
+        Duration::try_from_secs_f64(interval.as_secs_f64() * self.backoff.max(1.0))
+            .unwrap_or(self.max_interval)
+            .min(self.max_interval)
+    }
+}
+
+/// The outcome of waiting for the distribution of a zone or of records
+#[derive(Debug)]
+pub enum DistributionOutcome {
+    /// The zone or all the records are distributed.
+    Distributed {
+        /// The number of checks made.
+        checks: u32,
+        /// The time it took.
+        elapsed: Duration,
+    },
+    /// The timeout was reached before the distribution completed.
+    TimedOut {
+        /// The records still not distributed (empty when waiting for a zone).
+        pending: Vec<ZoneRecordId>,
+        /// The time waited.
+        elapsed: Duration,
+    },
+    /// A check failed with an error that is not retryable.
+    Failed(DNSimpleError),
+}
+
+impl DistributionOutcome {
+    /// Returns true if the zone or all the records are distributed.
+    pub fn is_distributed(&self) -> bool {
+        matches!(self, DistributionOutcome::Distributed { .. })
+    }
+}
+
+/// The result of a single check: `Some(distributed)`, or `None` if the check timed out on
+/// the DNSimple side (`DNSimpleError::GatewayTimeout`) and must be made again.
+fn checked(
+    result: Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>,
+) -> Result<Option<bool>, DNSimpleError> {
+    match result {
+        Ok(response) => Ok(Some(
+            response
+                .data
+                .is_some_and(|distribution| distribution.distributed),
+        )),
+        Err(DNSimpleError::GatewayTimeout(_)) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+impl Zones {
+    /// Waits until the zone is distributed across all the DNSimple name servers
+    ///
+    /// Polls `check_zone_distribution` according to the `DistributionPolling`. A
+    /// `DNSimpleError::GatewayTimeout` (the API could not query the name servers in time)
+    /// counts as not distributed yet, any other error stops the wait.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::zones_distribution::DistributionPolling;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let outcome = client
+    ///         .zones()
+    ///         .wait_for_zone_distribution(1234, "example.com", &DistributionPolling::default())
+    ///         .await;
+    ///
+    ///     assert!(outcome.is_distributed());
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `polling`: The interval, backoff and timeout of the checks
+    pub async fn wait_for_zone_distribution(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: &str,
+        polling: &DistributionPolling,
+    ) -> DistributionOutcome {
+        let account_id = account_id.into();
+
+        self.wait_for_distribution(polling, vec![None], |_| {
+            self.check_zone_distribution(account_id, zone)
+        })
+        .await
+    }
+
+    /// Waits until all the given records are distributed across all the DNSimple name servers
+    ///
+    /// Polls `check_zone_record_distribution` for the records not distributed yet,
+    /// according to the `DistributionPolling`. A `DNSimpleError::GatewayTimeout` counts as
+    /// not distributed yet, any other error stops the wait.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::ids::ZoneRecordId;
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let records = [ZoneRecordId(1), ZoneRecordId(2)];
+    ///     let outcome = client
+    ///         .zones()
+    ///         .wait_for_record_distribution(1234, "example.com", records, &DistributionPolling::default())
+    ///         .await;
+    ///
+    ///     if let DistributionOutcome::TimedOut { pending, .. } = outcome {
+    ///         println!("not distributed yet: {:?}", pending);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `records`: The IDs of the records to wait for
+    /// `polling`: The interval, backoff and timeout of the checks
+    pub async fn wait_for_record_distribution(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: &str,
+        records: impl IntoIterator<Item = impl Into<ZoneRecordId>>,
+        polling: &DistributionPolling,
+    ) -> DistributionOutcome {
+        let account_id = account_id.into();
+        let mut pending: Vec<ZoneRecordId> = records.into_iter().map(Into::into).collect();
+        pending.sort();
+        pending.dedup();
+
+        self.wait_for_distribution(polling, pending.into_iter().map(Some).collect(), |record| {
+            self.check_zone_record_distribution(account_id, zone, record.unwrap_or_default())
+        })
+        .await
+    }
+
+    /// Polls `check` for every pending record (`None` for the zone) until they are all distributed.
+    async fn wait_for_distribution<F, Fut>(
+        &self,
+        polling: &DistributionPolling,
+        mut pending: Vec<Option<ZoneRecordId>>,
+        check: F,
+    ) -> DistributionOutcome
+    where
+        F: Fn(Option<ZoneRecordId>) -> Fut,
+        Fut: Future<Output = Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError>>,
+    {
+        let start = Instant::now();
+        let mut interval = polling.interval;
+        let mut checks = 0;
+
+        loop {
+            checks += 1;
+            let results = future::join_all(pending.iter().map(|item| check(*item))).await;
+            let mut still_pending = Vec::new();
+            for (item, result) in pending.into_iter().zip(results) {
+                match checked(result) {
+                    Ok(Some(true)) => {}
+                    Ok(_) => still_pending.push(item),
+                    Err(error) => return DistributionOutcome::Failed(error),
+                }
+            }
+            pending = still_pending;
+
+            let elapsed = start.elapsed();
+            if pending.is_empty() {
+                return DistributionOutcome::Distributed { checks, elapsed };
+            }
+            if elapsed >= polling.timeout {
+                return DistributionOutcome::TimedOut {
+                    pending: pending.into_iter().flatten().collect(),
+                    elapsed,
+                };
+            }
+
+            tokio::time::sleep(interval.min(polling.timeout - elapsed)).await;
+            interval = polling.next_interval(interval);
+        }
+    }
+}
@@ -1,14 +1,256 @@
+use crate::dnsimple::ids::{AccountId, ZoneRecordId};
+use crate::dnsimple::list_options::list_options;
 use crate::dnsimple::zones::DistributionEndpoint;
 use crate::dnsimple::zones::{ZoneDistribution, Zones};
 use crate::dnsimple::{DNSimpleEmptyResponse, DNSimpleResponse, Endpoint, RequestOptions};
 use crate::errors::DNSimpleError;
-use serde::{Deserialize, Serialize};
+use futures_util::StreamExt;
+use futures_util::stream::BoxStream;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The maximum length, in bytes, of a character-string in a TXT record.
+const TXT_STRING_MAX_LENGTH: usize = 255;
+
+/// The maximum length of the tag of a CAA record.
+const CAA_TAG_MAX_LENGTH: usize = 15;
+
+/// The type of a zone record
+///
+/// Covers the record types supported by DNSimple, including the ALIAS, URL and POOL
+/// pseudo-types. Any other type is kept as `Other`, so new types sent by the API don't
+/// break the deserialization.
+///
+/// The types are (de)serialized as their uppercase name (i.e. `"AAAA"`), and can be
+/// compared with a string.
+///
+/// # Examples
+///
+/// ```
+/// use dnsimple::dnsimple::zones_records::RecordType;
+///
+/// assert_eq!(RecordType::Aaaa, RecordType::from("aaaa"));
+/// assert_eq!(RecordType::Other(String::from("LOC")), RecordType::from("LOC"));
+/// assert_eq!("MX", RecordType::Mx.to_string());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum RecordType {
+    /// An IPv4 address.
+    A,
+    /// An IPv6 address.
+    Aaaa,
+    /// A CNAME-like record at the apex of the zone, resolved by DNSimple.
+    Alias,
+    /// The certificate authorities allowed to issue certificates.
+    Caa,
+    /// An alias of another name.
+    Cname,
+    /// A DNSSEC public key.
+    Dnskey,
+    /// A DNSSEC delegation signer.
+    Ds,
+    /// The host information.
+    Hinfo,
+    /// The HTTPS service binding.
+    Https,
+    /// A mail exchange, with a priority.
+    Mx,
+    /// A naming authority pointer.
+    Naptr,
+    /// A name server.
+    Ns,
+    /// A pool of CNAME records, answered in turn by DNSimple.
+    Pool,
+    /// A pointer, for reverse DNS.
+    Ptr,
+    /// The start of authority of the zone.
+    Soa,
+    /// A sender policy framework record.
+    Spf,
+    /// A service location, with a priority.
+    Srv,
+    /// An SSH public key fingerprint.
+    Sshfp,
+    /// A general service binding.
+    Svcb,
+    /// A TLS certificate association.
+    Tlsa,
+    /// A text record.
+    Txt,
+    /// A redirect to an URL, served by DNSimple.
+    Url,
+    /// A type not covered by the other variants, in uppercase.
+    Other(String),
+}
+
+impl RecordType {
+    /// Returns the uppercase name of the type (i.e. `"AAAA"`).
+    pub fn as_str(&self) -> &str {
+        match self {
+            RecordType::A => "A",
+            RecordType::Aaaa => "AAAA",
+            RecordType::Alias => "ALIAS",
+            RecordType::Caa => "CAA",
+            RecordType::Cname => "CNAME",
+            RecordType::Dnskey => "DNSKEY",
+            RecordType::Ds => "DS",
+            RecordType::Hinfo => "HINFO",
+            RecordType::Https => "HTTPS",
+            RecordType::Mx => "MX",
+            RecordType::Naptr => "NAPTR",
+            RecordType::Ns => "NS",
+            RecordType::Pool => "POOL",
+            RecordType::Ptr => "PTR",
+            RecordType::Soa => "SOA",
+            RecordType::Spf => "SPF",
+            RecordType::Srv => "SRV",
+            RecordType::Sshfp => "SSHFP",
+            RecordType::Svcb => "SVCB",
+            RecordType::Tlsa => "TLSA",
+            RecordType::Txt => "TXT",
+            RecordType::Url => "URL",
+            RecordType::Other(name) => name,
+        }
+    }
+}
+
+impl FromStr for RecordType {
+    type Err = std::convert::Infallible;
+
+    /// Parses a type from its name, ignoring the case.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let name = name.to_ascii_uppercase();
+        Ok(match name.as_str() {
+            "A" => RecordType::A,
+            "AAAA" => RecordType::Aaaa,
+            "ALIAS" => RecordType::Alias,
+            "CAA" => RecordType::Caa,
+            "CNAME" => RecordType::Cname,
+            "DNSKEY" => RecordType::Dnskey,
+            "DS" => RecordType::Ds,
+            "HINFO" => RecordType::Hinfo,
+            "HTTPS" => RecordType::Https,
+            "MX" => RecordType::Mx,
+            "NAPTR" => RecordType::Naptr,
+            "NS" => RecordType::Ns,
+            "POOL" => RecordType::Pool,
+            "PTR" => RecordType::Ptr,
+            "SOA" => RecordType::Soa,
+            "SPF" => RecordType::Spf,
+            "SRV" => RecordType::Srv,
+            "SSHFP" => RecordType::Sshfp,
+            "SVCB" => RecordType::Svcb,
+            "TLSA" => RecordType::Tlsa,
+            "TXT" => RecordType::Txt,
+            "URL" => RecordType::Url,
+            _ => RecordType::Other(name),
+        })
+    }
+}
+
+impl From<&str> for RecordType {
+    fn from(name: &str) -> Self {
+        match name.parse() {
+            Ok(record_type) => record_type,
+            Err(never) => match never {},
+        }
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for RecordType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for RecordType {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<RecordType> for &str {
+    fn eq(&self, other: &RecordType) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl Serialize for RecordType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Ok(RecordType::from(name.as_str()))
+    }
+}
+
+list_options! {
+    /// The options to list the records of a zone
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::zones_records::{RecordType, ZoneRecordListOptions, ZoneRecordSort};
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let options = ZoneRecordListOptions::new()
+    ///         .name_like("www")
+    ///         .record_type(RecordType::A)
+    ///         .sort(ZoneRecordSort::Name.asc());
+    ///     let records = client.zones().list_zone_records(1234, "example.com", options.into()).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ZoneRecordListOptions;
+    /// The fields the zone records can be sorted by
+    ZoneRecordSort {
+        /// Sort by ID.
+        Id => "id",
+        /// Sort by name.
+        Name => "name",
+        /// Sort by content.
+        Content => "content",
+        /// Sort by type.
+        Type => "type",
+    }
+}
+
+impl ZoneRecordListOptions {
+    /// Only lists the records whose name contains `name`.
+    pub fn name_like(self, name: &str) -> Self {
+        self.filter("name_like", name)
+    }
+
+    /// Only lists the records named exactly `name` (`""` for the apex).
+    pub fn name(self, name: &str) -> Self {
+        self.filter("name", name)
+    }
+
+    /// Only lists the records of the given type.
+    pub fn record_type(self, record_type: RecordType) -> Self {
+        self.filter("type", record_type)
+    }
+}
 
 /// Represents a zone record in DNSimple
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ZoneRecord {
     /// The record ID in DNSimple.
-    pub id: u64,
+    pub id: ZoneRecordId,
     /// The associated zone ID.
     pub zone_id: String,
     /// The ID of the parent record, if this record is dependent on another record.
@@ -21,9 +263,9 @@ pub struct ZoneRecord {
     pub ttl: u64,
     /// The priority value, if the type of record accepts a priority.
     pub priority: Option<u64>,
-    /// The type of record, in uppercase.
+    /// The type of record.
     #[serde(rename = "type")]
-    pub record_type: String,
+    pub record_type: RecordType,
     /// The regions where the record is propagated. This is optional.
     pub regions: Option<Vec<String>>,
     /// True if this is a system record created by DNSimple. System records are read-only.
@@ -35,13 +277,13 @@ pub struct ZoneRecord {
 }
 
 /// Represents the payload to be send to create a zone record
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ZoneRecordPayload {
     /// The record name (without the domain name).
     pub name: String,
-    /// The type of record, in uppercase.
+    /// The type of record.
     #[serde(rename = "type")]
-    pub record_type: String,
+    pub record_type: RecordType,
     /// The plain-text record content.
     pub content: String,
     /// The TTL value.
@@ -53,6 +295,125 @@ pub struct ZoneRecordPayload {
     pub regions: Option<Vec<String>>,
 }
 
+impl ZoneRecordPayload {
+    /// Validates the content of the record for its type, as done before sending the
+    /// payload to the API
+    ///
+    /// The checks cover the most common mistakes, the API remains the source of truth:
+    ///
+    /// - the content of an `A` record is an IPv4 address, and of an `AAAA` record an IPv6 address
+    /// - `MX` and `SRV` records have a priority
+    /// - the content of a `CAA` record is `flags tag value`, i.e. `0 issue "letsencrypt.org"`
+    /// - the quoted strings of a `TXT` record are at most 255 bytes long (longer unquoted
+    ///   content is split by DNSimple)
+    ///
+    /// # Errors
+    ///
+    /// `DNSimpleError::Validation` describing the first problem found.
+    pub fn validate(&self) -> Result<(), DNSimpleError> {
+        let invalid = |reason: String| {
+            Err(DNSimpleError::Validation(format!(
+                "{} record `{}`: {}",
+                self.record_type, self.name, reason
+            )))
+        };
+
+        match self.record_type {
+            RecordType::A if self.content.parse::<Ipv4Addr>().is_err() => {
+                invalid(format!("`{}` is not an IPv4 address", self.content))
+            }
+            RecordType::Aaaa if self.content.parse::<Ipv6Addr>().is_err() => {
+                invalid(format!("`{}` is not an IPv6 address", self.content))
+            }
+            RecordType::Mx | RecordType::Srv if self.priority.is_none() => {
+                invalid(String::from("a priority is required"))
+            }
+            RecordType::Caa => match validate_caa(&self.content) {
+                Err(reason) => invalid(reason),
+                Ok(()) => Ok(()),
+            },
+            RecordType::Txt => match validate_txt(&self.content) {
+                Err(reason) => invalid(reason),
+                Ok(()) => Ok(()),
+            },
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks the content of a CAA record is `flags tag value`.
+fn validate_caa(content: &str) -> Result<(), String> {
+    let mut parts = content.trim().splitn(3, char::is_whitespace);
+    let (flags, tag, value) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(flags), Some(tag), Some(value)) => (flags, tag, value.trim()),
+        _ => return Err(format!("`{}` is not `flags tag value`", content)),
+    };
+
+    if flags.parse::<u8>().is_err() {
+        return Err(format!(
+            "the flags `{}` are not a number from 0 to 255",
+            flags
+        ));
+    }
+    if tag.is_empty()
+        || tag.len() > CAA_TAG_MAX_LENGTH
+        || !tag.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(format!(
+            "the tag `{}` is not 1 to {} letters or digits",
+            tag, CAA_TAG_MAX_LENGTH
+        ));
+    }
+    let unbalanced = value.starts_with('"') != value.ends_with('"') || value == "\"";
+    if value.is_empty() || unbalanced {
+        return Err(format!(
+            "the value `{}` is not a valid (quoted) string",
+            value
+        ));
+    }
+    Ok(())
+}
+
+/// Checks the quoted strings of a TXT record fit in a character-string.
+///
+/// An escaped character counts as one character, and a `\DDD` escape (RFC 1035) as the
+/// single octet of decimal value `DDD`.
+fn validate_txt(content: &str) -> Result<(), String> {
+    if !content.starts_with('"') {
+        return Ok(());
+    }
+
+    let mut length = None;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        match length {
+            None if c == '"' => length = Some(0),
+            None => {}
+            Some(l) if c == '\\' => {
+                let escaped = match chars.next() {
+                    Some(digit) if digit.is_ascii_digit() => {
+                        chars.next_if(char::is_ascii_digit);
+                        chars.next_if(char::is_ascii_digit);
+                        1
+                    }
+                    Some(escaped) => escaped.len_utf8(),
+                    None => 0,
+                };
+                length = Some(l + escaped);
+            }
+            Some(_) if c == '"' => length = None,
+            Some(l) => length = Some(l + c.len_utf8()),
+        }
+        if length.is_some_and(|l| l > TXT_STRING_MAX_LENGTH) {
+            return Err(format!(
+                "a quoted string is longer than {} bytes",
+                TXT_STRING_MAX_LENGTH
+            ));
+        }
+    }
+    Ok(())
+}
+
 /// Represents the payload to be send to update a zone record
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ZoneRecordUpdatePayload {
@@ -69,6 +430,70 @@ pub struct ZoneRecordUpdatePayload {
     pub regions: Option<Vec<String>>,
 }
 
+/// Represents a zone record update within a batch change
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ZoneRecordBatchUpdatePayload {
+    /// The ID of the record to update.
+    pub id: ZoneRecordId,
+    /// The record name (without the domain name).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// The plain-text record content.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    /// The TTL value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// The priority value, if the type of record accepts a priority.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u64>,
+    /// The regions where the record is propagated. This is optional.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regions: Option<Vec<String>>,
+}
+
+/// Represents a zone record deletion within a batch change
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ZoneRecordBatchDeletePayload {
+    /// The ID of the record to delete.
+    pub id: ZoneRecordId,
+}
+
+/// Represents the payload to be send to apply a batch of changes to the zone records
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct ZoneRecordBatchPayload {
+    /// The records to create.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creates: Option<Vec<ZoneRecordPayload>>,
+    /// The records to update.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updates: Option<Vec<ZoneRecordBatchUpdatePayload>>,
+    /// The records to delete.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletes: Option<Vec<ZoneRecordBatchDeletePayload>>,
+}
+
+/// Represents a zone record deleted as part of a batch change
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeletedZoneRecord {
+    /// The ID of the deleted record.
+    pub id: ZoneRecordId,
+}
+
+/// Represents the result of a batch change to the zone records
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ZoneRecordBatchChange {
+    /// The records that were created.
+    #[serde(default)]
+    pub creates: Vec<ZoneRecord>,
+    /// The records that were updated.
+    #[serde(default)]
+    pub updates: Vec<ZoneRecord>,
+    /// The records that were deleted.
+    #[serde(default)]
+    pub deletes: Vec<DeletedZoneRecord>,
+}
+
 struct ZoneRecordsEndpoint;
 
 impl Endpoint for ZoneRecordsEndpoint {
@@ -81,26 +506,60 @@ impl Endpoint for ZoneRecordEndpoint {
     type Output = ZoneRecord;
 }
 
-impl Zones<'_> {
+struct ZoneRecordBatchChangeEndpoint;
+
+impl Endpoint for ZoneRecordBatchChangeEndpoint {
+    type Output = ZoneRecordBatchChange;
+}
+
+impl Zones {
     /// List zone records
     ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
     /// `zone`: The zone name
+    /// `options`: The `RequestOptions`, i.e. built with `ZoneRecordListOptions`
     pub async fn list_zone_records(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
         options: Option<RequestOptions>,
     ) -> Result<DNSimpleResponse<Vec<ZoneRecord>>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/records", account_id, zone);
 
         self.client.get::<ZoneRecordsEndpoint>(&path, options).await
     }
 
+    /// Lists all the records of a zone, fetching the pages lazily.
+    ///
+    /// The items are returned as a `Stream`. See `Client::paginate`.
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `options`: The `RequestOptions`, i.e. built with `ZoneRecordListOptions`
+    pub fn list_zone_records_stream(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: &str,
+        options: Option<RequestOptions>,
+    ) -> BoxStream<'static, Result<ZoneRecord, DNSimpleError>> {
+        let account_id = account_id.into();
+        let path = format!("/{}/zones/{}/records", account_id, zone);
+
+        self.client
+            .paginate::<ZoneRecordsEndpoint, _>(path, options)
+            .boxed()
+    }
+
     /// Create a zone record
     ///
+    /// The payload is checked with `ZoneRecordPayload::validate` before being sent, so
+    /// invalid content fails with `DNSimpleError::Validation` without calling the API.
+    ///
     /// # Arguments
     ///
     /// `account_id`: The account ID
@@ -108,11 +567,13 @@ impl Zones<'_> {
     /// `payload`: The `ZoneRecordPayload` with the information to create the zone record
     pub async fn create_zone_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
         payload: ZoneRecordPayload,
     ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
+        let account_id = account_id.into();
         let path = format!("/{}/zones/{}/records", account_id, zone);
+        payload.validate()?;
 
         match serde_json::to_value(payload) {
             Ok(json) => self.client.post::<ZoneRecordEndpoint>(&path, json).await,
@@ -131,10 +592,12 @@ impl Zones<'_> {
     /// `record`: The record id
     pub async fn get_zone_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
-        record: u64,
+        record: impl Into<ZoneRecordId>,
     ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
 
         self.client.get::<ZoneRecordEndpoint>(&path, None).await
@@ -150,11 +613,13 @@ impl Zones<'_> {
     /// `payload`: The `ZoneRecordUpdatePayload` with the information to create the zone record
     pub async fn update_zone_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
-        record: u64,
+        record: impl Into<ZoneRecordId>,
         payload: ZoneRecordUpdatePayload,
     ) -> Result<DNSimpleResponse<ZoneRecord>, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
 
         match serde_json::to_value(payload) {
@@ -174,10 +639,12 @@ impl Zones<'_> {
     /// `record`: The record id
     pub async fn delete_zone_record(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
-        record: u64,
+        record: impl Into<ZoneRecordId>,
     ) -> Result<DNSimpleEmptyResponse, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!("/{}/zones/{}/records/{}", account_id, zone, record);
 
         self.client.delete(&path).await
@@ -192,10 +659,12 @@ impl Zones<'_> {
     /// `record`: The record id
     pub async fn check_zone_record_distribution(
         &self,
-        account_id: u64,
+        account_id: impl Into<AccountId>,
         zone: &str,
-        record: u64,
+        record: impl Into<ZoneRecordId>,
     ) -> Result<DNSimpleResponse<ZoneDistribution>, DNSimpleError> {
+        let account_id = account_id.into();
+        let record = record.into();
         let path = format!(
             "/{}/zones/{}/records/{}/distribution",
             account_id, zone, record
@@ -203,4 +672,69 @@ impl Zones<'_> {
 
         self.client.get::<DistributionEndpoint>(&path, None).await
     }
+
+    /// Create, update and delete multiple zone records in a single request
+    ///
+    /// The records to create are checked with `ZoneRecordPayload::validate` before the
+    /// request is sent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use dnsimple::dnsimple::new_client;
+    /// use dnsimple::dnsimple::ids::ZoneRecordId;
+    /// use dnsimple::dnsimple::zones_records::{RecordType, ZoneRecordBatchDeletePayload, ZoneRecordBatchPayload, ZoneRecordPayload};
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let client = new_client(true, String::from("AUTH_TOKEN")).unwrap();
+    ///     let payload = ZoneRecordBatchPayload {
+    ///         creates: Some(vec![ZoneRecordPayload {
+    ///             name: String::from("www"),
+    ///             record_type: RecordType::A,
+    ///             content: String::from("127.0.0.1"),
+    ///             ttl: None,
+    ///             priority: None,
+    ///             regions: None,
+    ///         }]),
+    ///         updates: None,
+    ///         deletes: Some(vec![ZoneRecordBatchDeletePayload { id: ZoneRecordId(42) }]),
+    ///     };
+    ///     let changes = client.zones().batch_change_zone_records(1234, "example.com", payload).await.unwrap().data.unwrap();
+    /// }
+    /// ```
+    ///
+    /// # Arguments
+    ///
+    /// `account_id`: The account ID
+    /// `zone`: The zone name
+    /// `payload`: The `ZoneRecordBatchPayload` with the records to create, update and delete
+    pub async fn batch_change_zone_records(
+        &self,
+        account_id: impl Into<AccountId>,
+        zone: &str,
+        payload: ZoneRecordBatchPayload,
+    ) -> Result<DNSimpleResponse<ZoneRecordBatchChange>, DNSimpleError> {
+        let account_id = account_id.into();
+        let path = format!("/{}/zones/{}/batch", account_id, zone);
+        for (index, create) in payload.creates.iter().flatten().enumerate() {
+            create.validate().map_err(|error| match error {
+                DNSimpleError::Validation(message) => {
+                    DNSimpleError::Validation(format!("creates[{}]: {}", index, message))
+                }
+                error => error,
+            })?;
+        }
+
+        match serde_json::to_value(payload) {
+            Ok(json) => {
+                self.client
+                    .post::<ZoneRecordBatchChangeEndpoint>(&path, json)
+                    .await
+            }
+            Err(_) => Err(DNSimpleError::Deserialization(String::from(
+                "Cannot deserialize json payload",
+            ))),
+        }
+    }
 }
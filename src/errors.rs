@@ -3,6 +3,7 @@ use thiserror::Error;
 
 /// Represents the possible errors thrown while interacting with the DNSimple API
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum DNSimpleError {
     #[error("Authentication failed")]
     Unauthorized,
@@ -35,11 +36,21 @@ pub enum DNSimpleError {
     Network(String),
     #[error("Deserialization Error {0}")]
     Deserialization(String),
+    #[error("Invalid configuration: {0}")]
+    Configuration(String),
+    #[error("Validation failed: {0}")]
+    Validation(String),
+    #[error("Invalid zone file at line {line}: {message}")]
+    ZoneFile { line: usize, message: String },
+    #[error("Certificate issuance failed: {0}")]
+    CertificateIssuance(String),
+    #[error("DNS-01 challenge failed: {0}")]
+    Dns01Challenge(String),
 }
 
 impl DNSimpleError {
     pub(crate) fn from_reqwest(error: reqwest::Error) -> DNSimpleError {
-        if error.is_decode() {
+        if error.is_decode() && !error.is_timeout() {
             Self::Deserialization(error.to_string())
         } else {
             Self::Network(error.to_string())
@@ -0,0 +1,198 @@
+use crate::common::{fixture_body, setup_mock_for};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::certificates::LetsEncryptPurchasePayload;
+use dnsimple::dnsimple::certificates_letsencrypt::IssuancePolling;
+use dnsimple::dnsimple::certificates_renewal::RenewalAction;
+use dnsimple::dnsimple::zones_distribution::DistributionPolling;
+use dnsimple::errors::DNSimpleError;
+use futures_util::TryStreamExt;
+use mockito::Matcher;
+use std::time::{Duration, UNIX_EPOCH};
+mod common;
+
+#[tokio::test]
+async fn binds_the_account_to_the_services() {
+    let setup = setup_mock_for("/1010/zones/example-alpha.com", "getZone/success", "GET").await;
+    let client = setup.0;
+    let account = client.account(1010);
+
+    let zone = account
+        .zones()
+        .get_zone("example-alpha.com")
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(1010, account.account_id());
+    assert_eq!(1010, account.zones().account_id());
+    assert_eq!("example-alpha.com", zone.name);
+}
+
+#[tokio::test]
+async fn binds_the_account_to_the_streams() {
+    let setup = setup_mock_for(
+        "/1385/domains?page=1&per_page=100",
+        "listDomains/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let domains: Vec<_> = client
+        .account(1385)
+        .domains()
+        .list_domains_stream(None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(2, domains.len());
+}
+
+#[tokio::test]
+async fn resolves_the_account_of_an_account_token() {
+    let (client, mut server) = setup_mock_for("/whoami", "whoami/success-account", "GET").await;
+    let zone_mock = server
+        .mock("GET", "/v2/1/zones/example-alpha.com")
+        .with_body(fixture_body("getZone/success"))
+        .create_async()
+        .await;
+
+    let account = client.auto_account().await.unwrap();
+    account.zones().get_zone("example-alpha.com").await.unwrap();
+
+    assert_eq!(1, account.account_id());
+    zone_mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn cannot_resolve_the_account_of_a_user_token() {
+    let setup = setup_mock_for("/whoami", "whoami/success-user", "GET").await;
+    let client = setup.0;
+
+    let result = client.auto_account().await;
+
+    assert_matches!(result.err(), Some(DNSimpleError::Configuration(_)));
+}
+
+#[tokio::test]
+async fn binds_the_account_to_the_zone_sync() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/records?page=1&per_page=100",
+        "listZoneRecords/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let plan = client
+        .account(1010)
+        .zones()
+        .zone_sync("example.com")
+        .plan(vec![])
+        .await
+        .unwrap();
+
+    assert!(plan.is_empty());
+}
+
+#[tokio::test]
+async fn binds_the_account_to_the_distribution_waits() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/distribution",
+        "checkZoneDistribution/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let outcome = client
+        .account(1010)
+        .zones()
+        .wait_for_zone_distribution("example.com", &DistributionPolling::default())
+        .await;
+
+    assert!(outcome.is_distributed());
+}
+
+#[tokio::test]
+async fn binds_the_account_to_the_certificate_issuance() {
+    let setup = setup_mock_for(
+        "/1010/domains/bingo.pizza/certificates/letsencrypt",
+        "purchaseLetsencryptCertificate/success",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let mut server = setup.1;
+    let failed =
+        fixture_body("issueLetsencryptCertificate/success").replace("\"requesting\"", "\"failed\"");
+    server
+        .mock(
+            "POST",
+            "/v2/1010/domains/bingo.pizza/certificates/letsencrypt/101967/issue",
+        )
+        .with_status(202)
+        .with_body(failed)
+        .create_async()
+        .await;
+    let payload = LetsEncryptPurchasePayload {
+        auto_renew: false,
+        name: String::from("www"),
+        alternate_names: vec![],
+        signature_algorithm: None,
+    };
+
+    let result = client
+        .account(1010)
+        .certificates()
+        .obtain_letsencrypt_certificate("bingo.pizza", payload, &IssuancePolling::default())
+        .await;
+
+    assert_matches!(result, Err(DNSimpleError::CertificateIssuance(message)) => {
+        assert_eq!("certificate 101967 is failed", message);
+    });
+}
+
+#[tokio::test]
+async fn binds_the_account_to_the_renewal_manager() {
+    let setup = setup_mock_for(
+        "/1010/domains?page=1&per_page=100",
+        "listDomains/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let mut server = setup.1;
+    for domain in ["181984", "181985"] {
+        server
+            .mock(
+                "GET",
+                format!("/v2/1010/domains/{}/certificates", domain).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_body(fixture_body("listCertificates/success"))
+            .create_async()
+            .await;
+    }
+
+    let report = client
+        .account(1010)
+        .certificates()
+        .renewal_manager()
+        .dry_run(true)
+        .window(Duration::from_secs(16 * 24 * 3600))
+        .run_at(UNIX_EPOCH + Duration::from_secs(1598918400))
+        .await
+        .unwrap();
+
+    assert_eq!(4, report.checked);
+    assert_eq!(4, report.entries.len());
+    assert!(
+        report
+            .entries
+            .iter()
+            .all(|entry| matches!(entry.action, RenewalAction::WouldRenew))
+    );
+}
@@ -0,0 +1,211 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::acme::{
+    ChallengeRecord, Dns01Provider, Dns01Solver, challenge_name, with_dns01_challenge,
+};
+use dnsimple::dnsimple::ids::ZoneRecordId;
+use dnsimple::dnsimple::new_client;
+use dnsimple::dnsimple::zones_distribution::DistributionPolling;
+use dnsimple::errors::DNSimpleError;
+use futures_util::FutureExt;
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use serde_json::json;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::time::Duration;
+mod common;
+
+const RECORDS: &str = "/v2/1010/zones/example-alpha.com/records";
+
+/// Creates a solver for the zones of `listZones/success` (`example-alpha.com` and
+/// `example-beta.com`).
+async fn setup_solver(server: &mut ServerGuard) -> Dns01Solver {
+    server
+        .mock("GET", "/v2/1010/zones")
+        .match_query(Matcher::Any)
+        .with_body(fixture_body("listZones/success"))
+        .create_async()
+        .await;
+
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    Dns01Solver::new(client, 1010).polling(DistributionPolling {
+        interval: Duration::from_millis(1),
+        backoff: 1.0,
+        max_interval: Duration::from_millis(1),
+        timeout: Duration::from_millis(20),
+    })
+}
+
+async fn mock_create(server: &mut ServerGuard, name: &str) -> Mock {
+    server
+        .mock("POST", RECORDS)
+        .match_body(Matcher::PartialJson(json!({
+            "name": name,
+            "type": "TXT",
+            "content": "challenge-value",
+            "ttl": 60
+        })))
+        .with_status(201)
+        .with_body(fixture_body("createZoneRecord/created"))
+        .expect(1)
+        .create_async()
+        .await
+}
+
+async fn mock_distribution(server: &mut ServerGuard, fixture: &str) -> Mock {
+    server
+        .mock("GET", format!("{}/1/distribution", RECORDS).as_str())
+        .with_body(fixture_body(fixture))
+        .expect_at_least(1)
+        .create_async()
+        .await
+}
+
+async fn mock_delete(server: &mut ServerGuard) -> Mock {
+    server
+        .mock("DELETE", format!("{}/1", RECORDS).as_str())
+        .with_status(204)
+        .expect(1)
+        .create_async()
+        .await
+}
+
+#[test]
+fn challenge_name_test() {
+    assert_eq!(
+        "_acme-challenge.www.example.com",
+        challenge_name("WWW.example.com.")
+    );
+    assert_eq!(
+        "_acme-challenge.example.com",
+        challenge_name("*.example.com")
+    );
+}
+
+#[tokio::test]
+async fn with_dns01_challenge_creates_and_deletes_the_record_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    let create = mock_create(&mut server, "_acme-challenge.www").await;
+    mock_distribution(&mut server, "checkZoneRecordDistribution/success").await;
+    let delete = mock_delete(&mut server).await;
+
+    let result = with_dns01_challenge(
+        &solver,
+        "www.example-alpha.com",
+        "challenge-value",
+        || async { Ok::<_, DNSimpleError>("valid") },
+    )
+    .await;
+
+    assert_matches!(result, Ok("valid"));
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn with_dns01_challenge_deletes_the_record_when_the_validation_fails_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    let create = mock_create(&mut server, "_acme-challenge").await;
+    mock_distribution(&mut server, "checkZoneRecordDistribution/success").await;
+    let delete = mock_delete(&mut server).await;
+
+    let result: Result<(), DNSimpleError> = with_dns01_challenge(
+        &solver,
+        "*.example-alpha.com",
+        "challenge-value",
+        || async { Err(DNSimpleError::Validation(String::from("invalid challenge"))) },
+    )
+    .await;
+
+    assert_matches!(result, Err(DNSimpleError::Validation(message)) => {
+        assert_eq!("invalid challenge", message);
+    });
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn with_dns01_challenge_deletes_the_record_when_the_validation_panics_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    let create = mock_create(&mut server, "_acme-challenge.www").await;
+    mock_distribution(&mut server, "checkZoneRecordDistribution/success").await;
+    let delete = mock_delete(&mut server).await;
+
+    let result = AssertUnwindSafe(with_dns01_challenge(
+        &solver,
+        "www.example-alpha.com",
+        "challenge-value",
+        || async { panic!("the ACME client panicked") },
+    ))
+    .catch_unwind()
+    .await;
+
+    let panic: Box<dyn Any + Send> = result.map(|_: Result<(), DNSimpleError>| ()).unwrap_err();
+    assert_eq!(
+        Some(&"the ACME client panicked"),
+        panic.downcast_ref::<&str>()
+    );
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn present_deletes_the_record_when_it_is_not_distributed_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    let create = mock_create(&mut server, "_acme-challenge.www").await;
+    mock_distribution(&mut server, "checkZoneRecordDistribution/failure").await;
+    let delete = mock_delete(&mut server).await;
+
+    let result = solver
+        .present("www.example-alpha.com", "challenge-value")
+        .await;
+
+    assert_matches!(result, Err(DNSimpleError::Dns01Challenge(message)) => {
+        assert!(message.starts_with("the record 1 of example-alpha.com is not distributed"), "{}", message);
+    });
+    create.assert_async().await;
+    delete.assert_async().await;
+}
+
+#[tokio::test]
+async fn present_fails_without_a_zone_owning_the_name_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    let create = server
+        .mock("POST", Matcher::Any)
+        .expect(0)
+        .create_async()
+        .await;
+
+    let result = solver.present("www.example.com", "challenge-value").await;
+
+    assert_matches!(result, Err(DNSimpleError::Dns01Challenge(message)) => {
+        assert_eq!("no zone of the account owns _acme-challenge.www.example.com", message);
+    });
+    create.assert_async().await;
+}
+
+#[tokio::test]
+async fn cleanup_ignores_the_records_already_deleted_test() {
+    let mut server = Server::new_async().await;
+    let solver = setup_solver(&mut server).await;
+    server
+        .mock("DELETE", format!("{}/1", RECORDS).as_str())
+        .with_status(404)
+        .with_body(fixture_body("notfound-record"))
+        .create_async()
+        .await;
+    let record = ChallengeRecord {
+        zone: String::from("example-alpha.com"),
+        id: ZoneRecordId(1),
+        name: String::from("_acme-challenge.www"),
+        value: String::from("challenge-value"),
+    };
+
+    assert_matches!(solver.cleanup(&record).await, Ok(()));
+}
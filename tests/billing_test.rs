@@ -0,0 +1,119 @@
+use crate::common::setup_mock_for;
+use dnsimple::dnsimple::billing::Decimal;
+use dnsimple::dnsimple::{Filters, RequestOptions};
+use std::collections::HashMap;
+use std::str::FromStr;
+mod common;
+
+#[tokio::test]
+async fn list_charges_test() {
+    let setup = setup_mock_for("/1010/billing/charges", "listCharges/success", "GET").await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let response = client
+        .billing()
+        .list_charges(account_id, None)
+        .await
+        .unwrap();
+    let charges = response.data.unwrap();
+
+    assert_eq!(4, charges.len());
+    assert_eq!(4, response.pagination.unwrap().total_entries);
+
+    let charge = charges.first().unwrap();
+
+    assert_eq!("2023-08-17T05:53:36Z", charge.invoiced_at);
+    assert_eq!(Decimal::from_str("14.50").unwrap(), charge.total_amount);
+    assert_eq!(Decimal::ZERO, charge.balance_amount);
+    assert_eq!("1-2", charge.reference);
+    assert_eq!("collected", charge.state);
+    assert_eq!(1, charge.items.len());
+
+    let item = charge.items.first().unwrap();
+
+    assert_eq!("Register bubble-registered.com", item.description);
+    assert_eq!(Decimal::from_str("14.50").unwrap(), item.amount);
+    assert_eq!(Some(1), item.product_id);
+    assert_eq!("domain-registration", item.product_type);
+    assert_eq!(
+        Some("bubble-registered.com".to_string()),
+        item.product_reference
+    );
+}
+
+#[tokio::test]
+async fn list_charges_keeps_exact_amounts_test() {
+    let setup = setup_mock_for("/1010/billing/charges", "listCharges/success", "GET").await;
+    let client = setup.0;
+
+    let charges = client
+        .billing()
+        .list_charges(1010, None)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    let charge = &charges[2];
+    let items_total: Decimal = charge.items.iter().map(|item| item.amount).sum();
+
+    assert_eq!(
+        Decimal::from_str("1099999.99").unwrap(),
+        charge.total_amount
+    );
+    assert_eq!(charge.total_amount, items_total);
+    assert_eq!(None, charge.items[0].product_id);
+    assert_eq!(None, charge.items[0].product_reference);
+    assert_eq!("manual", charge.items[0].product_type);
+
+    let certificate_item = &charges[3].items[0];
+    assert_eq!("certificate-purchase", certificate_item.product_type);
+    assert_eq!(Some("42".to_string()), certificate_item.product_reference);
+}
+
+#[tokio::test]
+async fn list_charges_with_date_range_test() {
+    let setup = setup_mock_for(
+        "/1010/billing/charges?start_date=2023-01-01",
+        "listCharges/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let mut filters = HashMap::new();
+    filters.insert("start_date".to_string(), "2023-01-01".to_string());
+    let options = RequestOptions {
+        filters: Some(Filters::new(filters)),
+        sort: None,
+        paginate: None,
+    };
+
+    let charges = client
+        .billing()
+        .list_charges(1010, Some(options))
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(4, charges.len());
+}
+
+#[tokio::test]
+async fn list_charges_bad_filter_test() {
+    let setup = setup_mock_for(
+        "/1010/billing/charges",
+        "listCharges/fail-400-bad-filter",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let error = client.billing().list_charges(1010, None).await.unwrap_err();
+
+    assert_eq!(
+        "Invalid date format must be ISO8601 (YYYY-MM-DD)",
+        error.to_string()
+    );
+}
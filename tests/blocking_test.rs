@@ -0,0 +1,220 @@
+#![cfg(feature = "blocking")]
+
+use crate::common::{fixture_body, fixture_mock};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::blocking::{Client, new_client};
+use dnsimple::dnsimple::certificates::LetsEncryptPurchasePayload;
+use dnsimple::dnsimple::certificates_letsencrypt::IssuancePolling;
+use dnsimple::dnsimple::zone_sync::SyncOutcome;
+use dnsimple::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+use dnsimple::dnsimple::zones_records::{RecordType, ZoneRecordPayload};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Server, ServerGuard};
+use std::time::{Duration, UNIX_EPOCH};
+mod common;
+
+/// Same as `common::setup_mock_for`, but with a blocking client (the mock server
+/// runs on its own thread).
+fn setup_blocking_mock_for(path: &str, fixture: &str, method: &str) -> (Client, ServerGuard) {
+    let mut server = Server::new();
+    fixture_mock(&mut server, method, path, fixture)
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .create();
+
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    (client, server)
+}
+
+#[test]
+fn whoami_test() {
+    let setup = setup_blocking_mock_for("/whoami", "whoami/success", "GET");
+    let client = setup.0;
+
+    let response = client.identity().whoami().unwrap();
+    let account = response.data.unwrap().account.unwrap();
+
+    assert_eq!(200, response.status);
+    assert_eq!(3991, response.rate_limit.unwrap().remaining);
+    assert_eq!(1, account.id);
+    assert_eq!(Some(3991), client.last_rate_limit().map(|r| r.remaining));
+}
+
+#[test]
+fn get_zone_not_found_test() {
+    let setup = setup_blocking_mock_for("/1010/zones/0", "notfound-zone", "GET");
+    let client = setup.0;
+
+    let error = client.zones().get_zone(1010, "0").unwrap_err();
+
+    assert_matches!(error, DNSimpleError::NotFound(_));
+}
+
+#[test]
+fn delete_zone_record_test() {
+    let setup = setup_blocking_mock_for(
+        "/1010/zones/example.com/records/5",
+        "deleteZoneRecord/success",
+        "DELETE",
+    );
+    let client = setup.0;
+
+    let response = client
+        .zones()
+        .delete_zone_record(1010, "example.com", 5)
+        .unwrap();
+
+    assert_eq!(204, response.status);
+}
+
+#[test]
+fn list_domains_iter_test() {
+    let setup = setup_blocking_mock_for(
+        "/1385/domains?page=1&per_page=100",
+        "listDomains/success",
+        "GET",
+    );
+    let client = setup.0;
+
+    let domains: Vec<_> = client
+        .domains()
+        .list_domains_iter(1385, None)
+        .collect::<Result<_, _>>()
+        .unwrap();
+
+    assert_eq!(2, domains.len());
+    assert_eq!("example-alpha.com", domains[0].name);
+}
+
+#[test]
+fn services_can_be_used_from_other_threads_test() {
+    let setup = setup_blocking_mock_for("/1010/zones/example-alpha.com", "getZone/success", "GET");
+    let client = setup.0;
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let zones = client.zones();
+            std::thread::spawn(move || zones.get_zone(1010, "example-alpha.com"))
+        })
+        .collect();
+
+    for handle in handles {
+        let zone = handle.join().unwrap().unwrap().data.unwrap();
+        assert_eq!("example-alpha.com", zone.name);
+    }
+}
+
+#[test]
+fn zone_sync_test() {
+    let setup = setup_blocking_mock_for(
+        "/1010/zones/example.com/records?page=1&per_page=100",
+        "listZoneRecords/success",
+        "GET",
+    );
+    let client = setup.0;
+    let desired = vec![ZoneRecordPayload {
+        name: String::from("www"),
+        record_type: RecordType::A,
+        content: String::from("192.0.2.1"),
+        ttl: None,
+        priority: None,
+        regions: None,
+    }];
+    let sync = client.zones().zone_sync(1010, "example.com").dry_run(true);
+
+    let plan = sync.plan(desired).unwrap();
+
+    assert_eq!(1, plan.creates.len());
+    assert_matches!(sync.apply(&plan), Ok(SyncOutcome::DryRun));
+}
+
+#[test]
+fn wait_for_record_distribution_test() {
+    let setup = setup_blocking_mock_for(
+        "/1010/zones/example.com/records/5/distribution",
+        "checkZoneRecordDistribution/success",
+        "GET",
+    );
+    let client = setup.0;
+
+    let outcome = client.zones().wait_for_record_distribution(
+        1010,
+        "example.com",
+        [5],
+        &DistributionPolling::default(),
+    );
+
+    assert_matches!(outcome, DistributionOutcome::Distributed { checks: 1, .. });
+}
+
+#[test]
+fn obtain_letsencrypt_certificate_test() {
+    let setup = setup_blocking_mock_for(
+        "/1010/domains/bingo.pizza/certificates/letsencrypt",
+        "purchaseLetsencryptCertificate/success",
+        "POST",
+    );
+    let client = setup.0;
+    let mut server = setup.1;
+    let body = fixture_body("issueLetsencryptCertificate/success");
+    server
+        .mock(
+            "POST",
+            "/v2/1010/domains/bingo.pizza/certificates/letsencrypt/101967/issue",
+        )
+        .with_status(202)
+        .with_body(body.replace("\"requesting\"", "\"cancelled\""))
+        .create();
+    let payload = LetsEncryptPurchasePayload {
+        auto_renew: false,
+        name: String::from("www"),
+        alternate_names: vec![],
+        signature_algorithm: None,
+    };
+
+    let result = client.certificates().obtain_letsencrypt_certificate(
+        1010,
+        "bingo.pizza",
+        payload,
+        &IssuancePolling::default(),
+    );
+
+    assert_matches!(result, Err(DNSimpleError::CertificateIssuance(message)) => {
+        assert_eq!("certificate 101967 is cancelled", message);
+    });
+}
+
+#[test]
+fn renewal_manager_test() {
+    let setup = setup_blocking_mock_for(
+        "/1010/domains?page=1&per_page=100",
+        "listDomains/success",
+        "GET",
+    );
+    let client = setup.0;
+    let mut server = setup.1;
+    let body = fixture_body("listCertificates/success");
+    for domain in ["181984", "181985"] {
+        server
+            .mock(
+                "GET",
+                format!("/v2/1010/domains/{}/certificates", domain).as_str(),
+            )
+            .match_query(Matcher::Any)
+            .with_body(body.clone())
+            .create();
+    }
+
+    let report = client
+        .certificates()
+        .renewal_manager(1010)
+        .dry_run(true)
+        .run_at(UNIX_EPOCH + Duration::from_secs(1598918400))
+        .unwrap();
+
+    assert_eq!(4, report.checked);
+    assert_eq!(4, report.entries.len());
+    assert_eq!(0, report.renewed().count());
+}
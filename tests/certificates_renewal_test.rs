@@ -0,0 +1,342 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::certificates_renewal::RenewalAction;
+use dnsimple::dnsimple::ids::CertificateId;
+use dnsimple::dnsimple::{Client, new_client};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::{Value, json};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+mod common;
+
+/// 2020-09-01T00:00:00Z
+fn now() -> SystemTime {
+    UNIX_EPOCH + Duration::from_secs(1598918400)
+}
+
+fn certificate(
+    id: u64,
+    common_name: &str,
+    auto_renew: bool,
+    authority: &str,
+    created_at: &str,
+    expires_at: Option<&str>,
+) -> Value {
+    json!({
+        "id": id,
+        "domain_id": 181984,
+        "contact_id": 2511,
+        "name": common_name.split('.').next().unwrap(),
+        "common_name": common_name,
+        "years": 1,
+        "csr": null,
+        "state": if expires_at.is_some() { "issued" } else { "requesting" },
+        "auto_renew": auto_renew,
+        "alternate_names": [],
+        "authority_identifier": authority,
+        "created_at": created_at,
+        "updated_at": created_at,
+        "expires_at": expires_at,
+        "expires_on": expires_at.map(|expires_at| &expires_at[..10])
+    })
+}
+
+async fn mock_get(server: &mut ServerGuard, path: &str, body: String) {
+    server
+        .mock("GET", format!("/v2/1010{}", path).as_str())
+        .match_query(Matcher::Any)
+        .with_body(body)
+        .create_async()
+        .await;
+}
+
+/// Mocks the two domains of `listDomains/success`: the certificates of `example-alpha.com`
+/// cover every case, `example-beta.com` has the ones of `listCertificates/success`.
+async fn setup_certificates(server: &mut ServerGuard) -> Client {
+    let alpha = vec![
+        certificate(
+            1,
+            "www.example-alpha.com",
+            false,
+            "letsencrypt",
+            "2020-06-18T20:15:09Z",
+            Some("2020-09-16T19:30:07Z"),
+        ),
+        certificate(
+            2,
+            "api.example-alpha.com",
+            true,
+            "letsencrypt",
+            "2020-06-18T20:15:09Z",
+            Some("2020-09-16T19:30:07Z"),
+        ),
+        certificate(
+            3,
+            "docs.example-alpha.com",
+            false,
+            "letsencrypt",
+            "2020-08-18T20:15:09Z",
+            Some("2020-11-16T19:30:07Z"),
+        ),
+        certificate(
+            4,
+            "old.example-alpha.com",
+            false,
+            "letsencrypt",
+            "2020-06-18T20:15:09Z",
+            Some("2020-09-10T19:30:07Z"),
+        ),
+        certificate(
+            5,
+            "old.example-alpha.com",
+            false,
+            "letsencrypt",
+            "2020-08-12T20:15:09Z",
+            Some("2020-11-10T19:30:07Z"),
+        ),
+        certificate(
+            6,
+            "shop.example-alpha.com",
+            false,
+            "comodo",
+            "2019-09-18T20:15:09Z",
+            Some("2020-09-18T23:59:59Z"),
+        ),
+        certificate(
+            7,
+            "new.example-alpha.com",
+            false,
+            "letsencrypt",
+            "2020-08-31T20:15:09Z",
+            None,
+        ),
+    ];
+    let pagination =
+        json!({"current_page": 1, "per_page": 100, "total_entries": alpha.len(), "total_pages": 1});
+
+    mock_get(server, "/domains", fixture_body("listDomains/success")).await;
+    mock_get(
+        server,
+        "/domains/181984/certificates",
+        json!({"data": alpha, "pagination": pagination}).to_string(),
+    )
+    .await;
+    mock_get(
+        server,
+        "/domains/181985/certificates",
+        fixture_body("listCertificates/success"),
+    )
+    .await;
+
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    client
+}
+
+async fn mock_post(server: &mut ServerGuard, path: &str, status: usize, body: String) {
+    server
+        .mock("POST", format!("/v2/1010{}", path).as_str())
+        .with_status(status)
+        .with_body(body)
+        .expect(1)
+        .create_async()
+        .await;
+}
+
+#[tokio::test]
+async fn renews_the_expiring_certificates_test() {
+    let mut server = Server::new_async().await;
+    let client = setup_certificates(&mut server).await;
+    let renewal = fixture_body("purchaseRenewalLetsencryptCertificate/success");
+    let issued = fixture_body("issueRenewalLetsencryptCertificate/success");
+    mock_post(
+        &mut server,
+        "/domains/181984/certificates/letsencrypt/1/renewals",
+        201,
+        renewal.clone(),
+    )
+    .await;
+    mock_post(
+        &mut server,
+        "/domains/181984/certificates/letsencrypt/1/renewals/65082/issue",
+        202,
+        issued.clone(),
+    )
+    .await;
+    mock_post(
+        &mut server,
+        "/domains/181985/certificates/letsencrypt/101973/renewals",
+        201,
+        renewal,
+    )
+    .await;
+    mock_post(
+        &mut server,
+        "/domains/181985/certificates/letsencrypt/101973/renewals/65082/issue",
+        202,
+        issued,
+    )
+    .await;
+    mock_post(
+        &mut server,
+        "/domains/181985/certificates/letsencrypt/101969/renewals",
+        402,
+        String::from("{\"message\":\"Your account is not subscribed\"}"),
+    )
+    .await;
+
+    let report = client
+        .certificates()
+        .renewal_manager(1010)
+        .run_at(now())
+        .await
+        .unwrap();
+
+    assert_eq!(9, report.checked);
+    let actions: Vec<(&str, &RenewalAction)> = report
+        .entries
+        .iter()
+        .map(|entry| (entry.certificate.common_name.as_str(), &entry.action))
+        .collect();
+    assert_eq!(6, actions.len());
+    assert_matches!(actions[0], ("www.example-alpha.com", RenewalAction::Renewed { renewal, certificate }) => {
+        assert_eq!(65082, renewal.id);
+        assert_eq!("requesting", certificate.state);
+    });
+    assert_matches!(
+        actions[1],
+        ("api.example-alpha.com", RenewalAction::AutoRenewing)
+    );
+    assert_matches!(
+        actions[2],
+        (
+            "old.example-alpha.com",
+            RenewalAction::Superseded(CertificateId(5))
+        )
+    );
+    assert_matches!(
+        actions[3],
+        ("shop.example-alpha.com", RenewalAction::NotLetsEncrypt)
+    );
+    assert_matches!(
+        actions[4],
+        ("www2.dnsimple.us", RenewalAction::Renewed { .. })
+    );
+    assert_matches!(
+        actions[5],
+        (
+            "www.dnsimple.us",
+            RenewalAction::Failed(DNSimpleError::PaymentRequired)
+        )
+    );
+    assert_eq!("example-beta.com", report.entries[5].domain);
+    assert_eq!(2, report.renewed().count());
+    assert_eq!(1, report.failed().count());
+}
+
+#[tokio::test]
+async fn dry_run_does_not_renew_test() {
+    let mut server = Server::new_async().await;
+    let client = setup_certificates(&mut server).await;
+
+    let report = client
+        .certificates()
+        .renewal_manager(1010)
+        .dry_run(true)
+        .window(Duration::from_secs(16 * 24 * 3600))
+        .run_at(now())
+        .await
+        .unwrap();
+
+    let renewals: Vec<&str> = report
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.action, RenewalAction::WouldRenew))
+        .map(|entry| entry.certificate.common_name.as_str())
+        .collect();
+    assert_eq!(
+        vec![
+            "www.example-alpha.com",
+            "www2.dnsimple.us",
+            "www.dnsimple.us"
+        ],
+        renewals
+    );
+    assert_eq!(5, report.entries.len());
+    assert_eq!(0, report.renewed().count());
+}
+
+#[tokio::test]
+async fn reports_the_unknown_expirations_test() {
+    let mut server = Server::new_async().await;
+    let mut invalid = certificate(
+        1,
+        "www.example-alpha.com",
+        false,
+        "letsencrypt",
+        "2020-06-18T20:15:09Z",
+        Some("2020-09-16T19:30:07Z"),
+    );
+    invalid["expires_at"] = json!("16/09/2020 19:30");
+    invalid["expires_on"] = Value::Null;
+    let mut missing = certificate(
+        2,
+        "api.example-alpha.com",
+        true,
+        "letsencrypt",
+        "2020-06-18T20:15:09Z",
+        None,
+    );
+    missing["state"] = json!("issued");
+    let requesting = certificate(
+        3,
+        "new.example-alpha.com",
+        false,
+        "letsencrypt",
+        "2020-08-31T20:15:09Z",
+        None,
+    );
+    let certificates = vec![invalid, missing, requesting];
+    let pagination =
+        json!({"current_page": 1, "per_page": 100, "total_entries": 3, "total_pages": 1});
+    mock_get(&mut server, "/domains", fixture_body("listDomains/success")).await;
+    mock_get(
+        &mut server,
+        "/domains/181984/certificates",
+        json!({"data": certificates, "pagination": pagination}).to_string(),
+    )
+    .await;
+    mock_get(
+        &mut server,
+        "/domains/181985/certificates",
+        fixture_body("listCertificates/success"),
+    )
+    .await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let report = client
+        .certificates()
+        .renewal_manager(1010)
+        .dry_run(true)
+        .window(Duration::from_secs(24 * 3600))
+        .run_at(now())
+        .await
+        .unwrap();
+
+    assert_eq!(5, report.checked);
+    let unknown: Vec<(&str, Option<SystemTime>)> = report
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry.action, RenewalAction::UnknownExpiration))
+        .map(|entry| (entry.certificate.common_name.as_str(), entry.expires_at))
+        .collect();
+    assert_eq!(
+        vec![
+            ("www.example-alpha.com", None),
+            ("api.example-alpha.com", None)
+        ],
+        unknown
+    );
+    assert_eq!(2, report.entries.len());
+}
@@ -0,0 +1,152 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::Client;
+use dnsimple::dnsimple::client_builder::Environment;
+use dnsimple::errors::DNSimpleError;
+use mockito::{Matcher, Server};
+use reqwest::Proxy;
+use reqwest::header::{HeaderMap, HeaderValue};
+use std::thread;
+use std::time::Duration;
+mod common;
+
+#[test]
+fn environment_base_urls() {
+    assert_eq!(
+        "https://api.dnsimple.com",
+        Environment::Production.base_url()
+    );
+    assert_eq!(
+        "https://api.sandbox.dnsimple.com",
+        Environment::Sandbox.base_url()
+    );
+    assert_eq!(
+        "https://dnsimple.example.com",
+        Environment::Custom(String::from("https://dnsimple.example.com/")).base_url()
+    );
+}
+
+#[test]
+fn builds_a_client_for_the_environment() {
+    let client = Client::builder(String::from("some-token")).build().unwrap();
+    assert_eq!("https://api.dnsimple.com/v2", client.versioned_url());
+
+    let client = Client::builder(String::from("some-token"))
+        .environment(Environment::Sandbox)
+        .build()
+        .unwrap();
+    assert_eq!(
+        "https://api.sandbox.dnsimple.com/v2",
+        client.versioned_url()
+    );
+}
+
+#[tokio::test]
+async fn sends_the_default_headers_and_user_agent() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .match_header("X-Team", "platform")
+        .match_header("Authorization", "Bearer some-token")
+        .match_header(
+            "User-Agent",
+            Matcher::Regex(String::from("^my-app/1.0 dnsimple-rust/")),
+        )
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Team", HeaderValue::from_static("platform"));
+    headers.insert(
+        "Authorization",
+        HeaderValue::from_static("Bearer other-token"),
+    );
+    let client = Client::builder(String::from("some-token"))
+        .environment(Environment::Custom(server.url()))
+        .user_agent("my-app/1.0")
+        .default_headers(headers)
+        .build()
+        .unwrap();
+
+    client.identity().whoami().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn uses_the_injected_http_client() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .match_header("X-Shared-Pool", "yes")
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Shared-Pool", HeaderValue::from_static("yes"));
+    let http_client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .unwrap();
+    let client = Client::builder(String::from("some-token"))
+        .environment(Environment::Custom(server.url()))
+        .http_client(http_client)
+        .build()
+        .unwrap();
+
+    client.identity().whoami().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[test]
+fn connection_settings_cannot_be_combined_with_an_injected_http_client() {
+    let result = Client::builder(String::from("some-token"))
+        .http_client(reqwest::Client::new())
+        .connect_timeout(Duration::from_secs(1))
+        .build();
+
+    assert_matches!(result.err(), Some(DNSimpleError::Configuration(_)));
+}
+
+#[tokio::test]
+async fn sends_the_requests_through_the_proxy() {
+    let mut proxy = Server::new_async().await;
+    let mock = proxy
+        .mock("GET", "/v2/whoami")
+        .match_header("Host", "dnsimple.test")
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let client = Client::builder(String::from("some-token"))
+        .environment(Environment::Custom(String::from("http://dnsimple.test")))
+        .proxy(Proxy::http(proxy.url()).unwrap())
+        .build()
+        .unwrap();
+
+    client.identity().whoami().await.unwrap();
+
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn times_out_slow_requests() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v2/whoami")
+        .with_chunked_body(|writer| {
+            thread::sleep(Duration::from_millis(500));
+            writer.write_all(b"{}")
+        })
+        .create_async()
+        .await;
+    let client = Client::builder(String::from("some-token"))
+        .environment(Environment::Custom(server.url()))
+        .timeout(Duration::from_millis(50))
+        .build()
+        .unwrap();
+
+    let error = client.identity().whoami().await.unwrap_err();
+
+    assert_matches!(error, DNSimpleError::Network(_));
+}
@@ -0,0 +1,69 @@
+use crate::common::setup_mock_for;
+use dnsimple::dnsimple::account_client::{AccountClient, AccountZones};
+use dnsimple::dnsimple::domains::Domains;
+use dnsimple::dnsimple::zones::Zones;
+use dnsimple::dnsimple::{Client, new_client};
+mod common;
+
+fn assert_send_sync_static<T: Send + Sync + 'static>() {}
+
+#[test]
+fn client_and_services_are_send_sync_and_static() {
+    assert_send_sync_static::<Client>();
+    assert_send_sync_static::<Zones>();
+    assert_send_sync_static::<Domains>();
+    assert_send_sync_static::<AccountClient>();
+    assert_send_sync_static::<AccountZones>();
+}
+
+#[test]
+fn clones_can_be_configured_independently() {
+    let client = new_client(true, String::from("some-token")).unwrap();
+    let mut clone = client.clone();
+
+    clone.set_base_url("https://example.com");
+
+    assert_eq!(
+        "https://api.sandbox.dnsimple.com/v2",
+        client.versioned_url()
+    );
+    assert_eq!("https://example.com/v2", clone.versioned_url());
+}
+
+#[tokio::test]
+async fn services_can_be_moved_into_spawned_tasks() {
+    let setup = setup_mock_for("/1010/zones/example-alpha.com", "getZone/success", "GET").await;
+    let client = setup.0;
+
+    let handles: Vec<_> = (0..3)
+        .map(|_| {
+            let zones = client.zones();
+            tokio::spawn(async move { zones.get_zone(1010, "example-alpha.com").await })
+        })
+        .collect();
+
+    for handle in handles {
+        let zone = handle.await.unwrap().unwrap().data.unwrap();
+        assert_eq!("example-alpha.com", zone.name);
+    }
+}
+
+#[tokio::test]
+async fn streams_do_not_borrow_the_client() {
+    let setup = setup_mock_for(
+        "/1385/domains?page=1&per_page=100",
+        "listDomains/success",
+        "GET",
+    )
+    .await;
+    let stream = {
+        let client = setup.0;
+        client.domains().list_domains_stream(1385, None)
+    };
+
+    let domains: Vec<_> = futures_util::TryStreamExt::try_collect(stream)
+        .await
+        .unwrap();
+
+    assert_eq!(2, domains.len());
+}
@@ -1,5 +1,7 @@
+#![allow(dead_code)]
+
 use dnsimple::dnsimple::{Client, new_client};
-use mockito::{Server, ServerGuard};
+use mockito::{Matcher, Mock, Server, ServerGuard};
 use std::fs;
 
 /// Creates a mockserver and a client (changing the url of the client
@@ -14,28 +16,77 @@ use std::fs;
 /// `method`: the HTTP method we are going to use (GET, POST, DELETE, ...)
 ///
 pub async fn setup_mock_for(path: &str, fixture: &str, method: &str) -> (Client, ServerGuard) {
-    let path = format!("/v2{}", path);
-    let fixture = format!("./tests/fixtures/v2/api/{}.http", fixture);
-
-    let content =
-        fs::read_to_string(fixture.as_str()).expect("Something went wrong: Couldn't read the file");
-
-    let lines = content.trim_end().lines();
-    let status = &content[9..12];
-    let body = lines.last();
+    setup_mock_with_query_for(path, Matcher::Any, fixture, method).await
+}
 
+/// Same as `setup_mock_for`, but only matching the requests with a query
+/// string satisfying the `query` matcher.
+///
+/// # Arguments
+///
+/// `path`: the path in the server, without the query string (i.e. `/whoami`)
+/// `query`: the matcher for the query string
+/// `fixture`: the path to the fixture inside the `api` directory
+/// `method`: the HTTP method we are going to use (GET, POST, DELETE, ...)
+///
+pub async fn setup_mock_with_query_for(
+    path: &str,
+    query: Matcher,
+    fixture: &str,
+    method: &str,
+) -> (Client, ServerGuard) {
     let mut server = Server::new_async().await;
-    server
-        .mock(method, path.as_str())
+    let mock = fixture_mock(&mut server, method, path, fixture)
         .with_header("X-RateLimit-Limit", "2")
         .with_header("X-RateLimit-Remaining", "2")
-        .with_header("X-RateLimit-Reset", "never")
-        .with_status(status.parse().unwrap())
-        .with_body(body.unwrap())
-        .create_async()
-        .await;
+        .with_header("X-RateLimit-Reset", "never");
+
+    let mock = match query {
+        Matcher::Any => mock,
+        query => mock.match_query(query),
+    };
+    mock.create_async().await;
 
     let mut client = new_client(true, String::from("some-token")).unwrap();
     client.set_base_url(&server.url());
     (client, server)
 }
+
+/// Builds a mock answering with the status and the body of the fixture, to be
+/// completed (i.e. with `expect`) and created by the caller.
+///
+/// # Arguments
+///
+/// `server`: the mockserver
+/// `method`: the HTTP method we are going to use (GET, POST, DELETE, ...)
+/// `path`: the path in the server, without the `/v2` prefix (i.e. `/whoami`)
+/// `fixture`: the path to the fixture inside the `api` directory
+///
+pub fn fixture_mock(server: &mut ServerGuard, method: &str, path: &str, fixture: &str) -> Mock {
+    let content = read_fixture(fixture);
+
+    server
+        .mock(method, format!("/v2{}", path).as_str())
+        .with_status(content[9..12].parse().unwrap())
+        .with_body(content.trim_end().lines().last().unwrap())
+}
+
+/// Returns the body of the fixture.
+///
+/// # Arguments
+///
+/// `fixture`: the path to the fixture inside the `api` directory
+///
+pub fn fixture_body(fixture: &str) -> String {
+    read_fixture(fixture)
+        .trim_end()
+        .lines()
+        .last()
+        .unwrap()
+        .to_string()
+}
+
+fn read_fixture(fixture: &str) -> String {
+    let fixture = format!("./tests/fixtures/v2/api/{}.http", fixture);
+    fs::read_to_string(fixture.as_str()).expect("Something went wrong: Couldn't read the file")
+}
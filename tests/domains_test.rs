@@ -1,4 +1,7 @@
-use crate::common::setup_mock_for;
+use crate::common::{setup_mock_for, setup_mock_with_query_for};
+use dnsimple::dnsimple::domains::{DomainListOptions, DomainSort};
+use dnsimple::dnsimple::ids::{ContactId, DomainId};
+use mockito::Matcher;
 mod common;
 
 #[tokio::test]
@@ -34,6 +37,40 @@ async fn list_domains_test() {
     assert_eq!("2020-06-04T19:15:21Z", first_domain.updated_at);
 }
 
+#[tokio::test]
+async fn list_domains_with_options_test() {
+    let setup = setup_mock_with_query_for(
+        "/1385/domains",
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("name_like".into(), "example".into()),
+            Matcher::UrlEncoded("registrant_id".into(), "2715".into()),
+            Matcher::UrlEncoded("sort".into(), "expiration:asc,name:desc".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+            Matcher::UrlEncoded("per_page".into(), "10".into()),
+        ]),
+        "listDomains/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let options = DomainListOptions::new()
+        .name_like("example")
+        .registrant_id(ContactId(2715))
+        .sort(DomainSort::Expiration.asc())
+        .sort(DomainSort::Name.desc())
+        .paginate(2, 10);
+
+    let domains = client
+        .domains()
+        .list_domains(1385, options.into())
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(2, domains.len());
+}
+
 #[tokio::test]
 async fn create_domain_test() {
     let setup = setup_mock_for("/1385/domains", "createDomain/created", "POST").await;
@@ -68,7 +105,7 @@ async fn test_get_domain() {
     let setup = setup_mock_for("/1385/domains/181984", "getDomain/success", "GET").await;
     let client = setup.0;
     let account_id = 1385_u64;
-    let domain_id = 181984_u64;
+    let domain_id = DomainId(181984);
 
     let domain = client
         .domains()
@@ -98,7 +135,7 @@ async fn test_delete_domain() {
     let setup = setup_mock_for("/1385/domains/181984", "deleteDomain/success", "DELETE").await;
     let client = setup.0;
     let account_id = 1385_u64;
-    let domain_id = 181984_u64;
+    let domain_id = DomainId(181984);
 
     let response = client.domains().delete_domain(account_id, domain_id).await;
 
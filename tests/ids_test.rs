@@ -0,0 +1,80 @@
+use crate::common::setup_mock_for;
+use dnsimple::dnsimple::domains::Domain;
+use dnsimple::dnsimple::ids::{AccountId, ContactId, DomainId, DomainRef};
+mod common;
+
+#[tokio::test]
+async fn get_domain_by_id_test() {
+    let setup = setup_mock_for("/1385/domains/181984", "getDomain/success", "GET").await;
+    let client = setup.0;
+
+    let domain = client
+        .domains()
+        .get_domain(AccountId(1385), DomainId(181984))
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(DomainId(181984), domain.id);
+}
+
+#[tokio::test]
+async fn get_domain_by_name_test() {
+    let setup = setup_mock_for(
+        "/1385/domains/example-alpha.com",
+        "getDomain/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let domain = client
+        .domains()
+        .get_domain(1385, "example-alpha.com")
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(DomainId(181984), domain.id);
+    assert_eq!(AccountId(1385), domain.account_id);
+    assert_eq!(Some(ContactId(2715)), domain.registrant_id);
+}
+
+#[test]
+fn ids_are_serialized_as_numbers_test() {
+    let domain: Domain = serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "account_id": 2,
+        "registrant_id": null,
+        "name": "example.com",
+        "unicode_name": "example.com",
+        "state": "hosted",
+        "auto_renew": false,
+        "private_whois": false,
+        "trustee": false,
+        "expires_on": null,
+        "expires_at": null,
+        "created_at": "2020-06-04T19:15:14Z",
+        "updated_at": "2020-06-04T19:15:21Z"
+    }))
+    .unwrap();
+
+    assert_eq!(DomainId(1), domain.id);
+    assert_eq!(AccountId(2), domain.account_id);
+    assert_eq!(None, domain.registrant_id);
+    assert_eq!("1", serde_json::to_string(&domain.id).unwrap());
+}
+
+#[test]
+fn domain_ref_conversions_test() {
+    assert_eq!(DomainRef::Id(DomainId(1)), DomainRef::from(DomainId(1)));
+    assert_eq!(
+        DomainRef::Name(String::from("example.com")),
+        DomainRef::from(String::from("example.com"))
+    );
+    assert_eq!("example.com", DomainRef::from("example.com").to_string());
+    assert_eq!("42", DomainRef::from(DomainId(42)).to_string());
+    assert_eq!(42_u64, u64::from(DomainId(42)));
+}
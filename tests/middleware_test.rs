@@ -0,0 +1,170 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::middleware::{Middleware, RequestInfo};
+use dnsimple::dnsimple::retry::RetryPolicy;
+use dnsimple::dnsimple::{Client, new_client};
+use dnsimple::errors::DNSimpleError;
+use mockito::Server;
+use reqwest::header::HeaderValue;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+mod common;
+
+/// What a `Recorder` saw for a request: the method, path, attempt and status (if any).
+type Seen = (String, String, u32, Option<u16>);
+
+#[derive(Clone, Default)]
+struct Recorder {
+    name: &'static str,
+    requests: Arc<Mutex<Vec<String>>>,
+    responses: Arc<Mutex<Vec<Seen>>>,
+}
+
+impl Middleware for Recorder {
+    fn on_request(&self, request: &mut reqwest::Request) {
+        self.requests.lock().unwrap().push(String::from(self.name));
+        request
+            .headers_mut()
+            .insert("X-Correlation-Id", HeaderValue::from_static("abc123"));
+    }
+
+    fn on_response(
+        &self,
+        request: &RequestInfo,
+        result: Result<&reqwest::Response, &reqwest::Error>,
+        elapsed: Duration,
+    ) {
+        assert!(elapsed > Duration::ZERO);
+        self.responses.lock().unwrap().push((
+            request.method.to_string(),
+            request.url.path().to_string(),
+            request.attempt,
+            result.ok().map(|response| response.status().as_u16()),
+        ));
+    }
+}
+
+fn client_for(url: &str, middleware: Recorder) -> Client {
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(url);
+    client.add_middleware(middleware);
+    client
+}
+
+#[tokio::test]
+async fn middleware_can_modify_the_request_test() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/v2/whoami")
+        .match_header("X-Correlation-Id", "abc123")
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let client = client_for(&server.url(), Recorder::default());
+
+    let response = client.identity().whoami().await;
+
+    assert_matches!(response, Ok(_));
+    mock.assert_async().await;
+}
+
+#[tokio::test]
+async fn middleware_sees_every_response_test() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("DELETE", "/v2/1010/contacts/1")
+        .with_status(204)
+        .create_async()
+        .await;
+    let recorder = Recorder::default();
+    let client = client_for(&server.url(), recorder.clone());
+
+    client.contacts().delete_contact(1010, 1).await.unwrap();
+
+    assert_eq!(
+        vec![(
+            String::from("DELETE"),
+            String::from("/v2/1010/contacts/1"),
+            1,
+            Some(204)
+        )],
+        *recorder.responses.lock().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn middleware_sees_every_attempt_test() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v2/whoami")
+        .with_status(503)
+        .expect(1)
+        .create_async()
+        .await;
+    server
+        .mock("GET", "/v2/whoami")
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let recorder = Recorder::default();
+    let mut client = client_for(&server.url(), recorder.clone());
+    client.set_retry_policy(RetryPolicy {
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    });
+
+    client.identity().whoami().await.unwrap();
+
+    let attempts: Vec<_> = recorder
+        .responses
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|seen| (seen.2, seen.3))
+        .collect();
+    assert_eq!(vec![(1, Some(503)), (2, Some(200))], attempts);
+}
+
+#[tokio::test]
+async fn middleware_sees_network_errors_test() {
+    let recorder = Recorder::default();
+    let client = client_for("http://127.0.0.1:1", recorder.clone());
+
+    let response = client.identity().whoami().await;
+
+    assert_matches!(response, Err(DNSimpleError::Network(_)));
+    let responses = recorder.responses.lock().unwrap();
+    assert_eq!(1, responses.len());
+    assert_eq!(None, responses[0].3);
+}
+
+#[tokio::test]
+async fn middlewares_are_called_in_order_test() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v2/whoami")
+        .with_body(fixture_body("whoami/success"))
+        .create_async()
+        .await;
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let first = Recorder {
+        name: "first",
+        requests: requests.clone(),
+        ..Default::default()
+    };
+    let second = Recorder {
+        name: "second",
+        requests: requests.clone(),
+        ..Default::default()
+    };
+    let mut client = Client::builder(String::from("some-token"))
+        .middleware(first)
+        .build()
+        .unwrap();
+    client.set_base_url(&server.url());
+    client.add_middleware(second);
+
+    client.identity().whoami().await.unwrap();
+
+    assert_eq!(vec!["first", "second"], *requests.lock().unwrap());
+}
@@ -0,0 +1,133 @@
+use crate::common::{fixture_mock, setup_mock_with_query_for};
+use dnsimple::dnsimple::domains::Domain;
+use dnsimple::dnsimple::{Endpoint, Paginate, RequestOptions, new_client};
+use dnsimple::errors::DNSimpleError;
+use futures_util::{StreamExt, TryStreamExt};
+use mockito::{Matcher, Mock, Server, ServerGuard};
+use serde::Deserialize;
+mod common;
+
+#[derive(Debug, Deserialize)]
+struct Id {
+    pub id: u64,
+}
+
+struct IdsEndpoint;
+
+impl Endpoint for IdsEndpoint {
+    type Output = Vec<Id>;
+}
+
+async fn mock_page(server: &mut ServerGuard, path: &str, page: &str, fixture: &str) -> Mock {
+    fixture_mock(server, "GET", path, fixture)
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), page.into()),
+            Matcher::UrlEncoded("per_page".into(), "2".into()),
+        ]))
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "never")
+        .create_async()
+        .await
+}
+
+fn per_page(per_page: u32) -> Option<RequestOptions> {
+    Some(RequestOptions {
+        filters: None,
+        sort: None,
+        paginate: Some(Paginate { per_page, page: 1 }),
+    })
+}
+
+#[tokio::test]
+async fn streams_all_the_pages() {
+    let mut server = Server::new_async().await;
+    let page1 = mock_page(&mut server, "/1010/domains", "1", "pages-1of3").await;
+    let page2 = mock_page(&mut server, "/1010/domains", "2", "pages-2of3").await;
+    let page3 = mock_page(&mut server, "/1010/domains", "3", "pages-3of3").await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let ids: Vec<u64> = client
+        .paginate::<IdsEndpoint, _>(String::from("/1010/domains"), per_page(2))
+        .map_ok(|item| item.id)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(vec![1, 2, 3, 4, 5], ids);
+    page1.assert_async().await;
+    page2.assert_async().await;
+    page3.assert_async().await;
+}
+
+#[tokio::test]
+async fn fetches_the_pages_lazily() {
+    let mut server = Server::new_async().await;
+    let page1 = mock_page(&mut server, "/1010/domains", "1", "pages-1of3").await;
+    let page2 = mock_page(&mut server, "/1010/domains", "2", "pages-2of3")
+        .await
+        .expect(0);
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let ids: Vec<u64> = client
+        .paginate::<IdsEndpoint, _>(String::from("/1010/domains"), per_page(2))
+        .take(2)
+        .map_ok(|item| item.id)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(vec![1, 2], ids);
+    page1.assert_async().await;
+    page2.assert_async().await;
+}
+
+#[tokio::test]
+async fn stops_at_the_first_error() {
+    let mut server = Server::new_async().await;
+    mock_page(&mut server, "/1010/domains", "1", "pages-1of3").await;
+    mock_page(&mut server, "/1010/domains", "2", "badgateway").await;
+    let page3 = mock_page(&mut server, "/1010/domains", "3", "pages-3of3")
+        .await
+        .expect(0);
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let results: Vec<Result<Id, DNSimpleError>> = client
+        .paginate::<IdsEndpoint, _>(String::from("/1010/domains"), per_page(2))
+        .collect()
+        .await;
+
+    assert_eq!(3, results.len());
+    assert_eq!(2, results[1].as_ref().unwrap().id);
+    assert!(results[2].is_err());
+    page3.assert_async().await;
+}
+
+#[tokio::test]
+async fn list_domains_stream_test() {
+    let setup = setup_mock_with_query_for(
+        "/1385/domains",
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("page".into(), "1".into()),
+            Matcher::UrlEncoded("per_page".into(), "100".into()),
+        ]),
+        "listDomains/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let domains: Vec<Domain> = client
+        .domains()
+        .list_domains_stream(1385, None)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(2, domains.len());
+    assert_eq!(181984, domains[0].id);
+    assert_eq!(181985, domains[1].id);
+}
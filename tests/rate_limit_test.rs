@@ -0,0 +1,118 @@
+use crate::common::{fixture_body, setup_mock_for};
+use dnsimple::dnsimple::{RateLimit, new_client};
+use mockito::{Server, ServerGuard};
+use std::time::{Duration, UNIX_EPOCH};
+mod common;
+
+async fn setup_with_headers(
+    method: &str,
+    path: &str,
+    status: usize,
+    body: &str,
+    headers: &[(&str, &str)],
+) -> ServerGuard {
+    let mut server = Server::new_async().await;
+    let mut mock = server
+        .mock(method, format!("/v2{}", path).as_str())
+        .with_status(status)
+        .with_body(body);
+    for (name, value) in headers {
+        mock = mock.with_header(*name, value);
+    }
+    mock.create_async().await;
+    server
+}
+
+const RATE_LIMIT_HEADERS: [(&str, &str); 3] = [
+    ("X-RateLimit-Limit", "4000"),
+    ("X-RateLimit-Remaining", "3991"),
+    ("X-RateLimit-Reset", "1450451976"),
+];
+
+#[tokio::test]
+async fn parses_the_rate_limit_headers() {
+    let server = setup_with_headers(
+        "GET",
+        "/whoami",
+        200,
+        &fixture_body("whoami/success"),
+        &RATE_LIMIT_HEADERS,
+    )
+    .await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let rate_limit = client
+        .identity()
+        .whoami()
+        .await
+        .unwrap()
+        .rate_limit
+        .unwrap();
+
+    assert_eq!(4000, rate_limit.limit);
+    assert_eq!(3991, rate_limit.remaining);
+    assert_eq!(1450451976, rate_limit.reset);
+    assert_eq!(
+        UNIX_EPOCH + Duration::from_secs(1450451976),
+        rate_limit.reset_at()
+    );
+    assert_eq!(Duration::ZERO, rate_limit.reset_in());
+}
+
+#[tokio::test]
+async fn parses_the_rate_limit_headers_of_empty_responses() {
+    let server =
+        setup_with_headers("DELETE", "/1010/contacts/1", 204, "", &RATE_LIMIT_HEADERS).await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let response = client.contacts().delete_contact(1010, 1).await.unwrap();
+
+    assert_eq!(4000, response.rate_limit.unwrap().limit);
+}
+
+#[tokio::test]
+async fn missing_rate_limit_headers_are_not_an_error() {
+    let server =
+        setup_with_headers("GET", "/whoami", 200, &fixture_body("whoami/success"), &[]).await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    let response = client.identity().whoami().await.unwrap();
+
+    assert_eq!(None, response.rate_limit);
+    assert!(response.data.is_some());
+    assert_eq!(None, client.last_rate_limit());
+}
+
+#[tokio::test]
+async fn malformed_rate_limit_headers_are_not_an_error() {
+    // The mocks send `X-RateLimit-Reset: never`
+    let setup = setup_mock_for("/whoami", "whoami/success", "GET").await;
+    let client = setup.0;
+
+    let response = client.identity().whoami().await.unwrap();
+
+    assert_eq!(None, response.rate_limit);
+}
+
+#[tokio::test]
+async fn remembers_the_last_rate_limit() {
+    let server = setup_with_headers("GET", "/whoami", 429, "", &RATE_LIMIT_HEADERS).await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    assert_eq!(None, client.last_rate_limit());
+
+    client.identity().whoami().await.unwrap_err();
+
+    assert_eq!(
+        Some(RateLimit {
+            limit: 4000,
+            remaining: 3991,
+            reset: 1450451976,
+        }),
+        client.last_rate_limit()
+    );
+}
@@ -1,4 +1,5 @@
 use crate::common::setup_mock_for;
+use dnsimple::dnsimple::ids::{ContactId, DomainId};
 use dnsimple::dnsimple::registrar_registrant_changes::{
     RegistrantChangeCheckPayload, RegistrantChangePayload,
 };
@@ -50,8 +51,8 @@ async fn check_registrant_change_test() {
     .await;
     let client = setup.0;
     let account_id = 101;
-    let domain_id = 101;
-    let contact_id = 101;
+    let domain_id = DomainId(101);
+    let contact_id = ContactId(101);
     let payload = RegistrantChangeCheckPayload {
         domain_id,
         contact_id,
@@ -80,8 +81,8 @@ async fn create_registrant_change_test() {
     .await;
     let client = setup.0;
     let account_id = 101;
-    let domain_id = 101;
-    let contact_id = 101;
+    let domain_id = DomainId(101);
+    let contact_id = ContactId(101);
     let payload = RegistrantChangePayload {
         domain_id,
         contact_id,
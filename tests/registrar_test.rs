@@ -1,6 +1,7 @@
 use crate::common::setup_mock_for;
+use dnsimple::dnsimple::ids::ContactId;
 use dnsimple::dnsimple::registrar::{
-    DomainRegistrationPayload, DomainRenewalPayload, DomainTransferPayload,
+    DomainRegistrationPayload, DomainRenewalPayload, DomainRestorePayload, DomainTransferPayload,
 };
 mod common;
 
@@ -150,7 +151,7 @@ async fn test_register_domain() {
     let account_id = 1010;
     let domain = "example.com";
     let payload = DomainRegistrationPayload {
-        registrant_id: 2,
+        registrant_id: ContactId(2),
         whois_privacy: None,
         trustee: None,
         auto_renew: None,
@@ -189,7 +190,7 @@ async fn test_transfer_domain() {
     let account_id = 1010;
     let domain = "example.com";
     let payload = DomainTransferPayload {
-        registrant_id: 2,
+        registrant_id: ContactId(2),
         auth_code: String::from("THE_AUTH_CODE"),
         whois_privacy: None,
         trustee: None,
@@ -228,7 +229,7 @@ async fn test_transfer_domain_error_in_dnsimple() {
     let account_id = 1010;
     let domain = "google.com";
     let payload = DomainTransferPayload {
-        registrant_id: 2,
+        registrant_id: ContactId(2),
         auth_code: String::from("THE_AUTH_CODE"),
         whois_privacy: None,
         trustee: None,
@@ -261,7 +262,7 @@ async fn test_transfer_domain_error_missing_auth_code() {
     let account_id = 1010;
     let domain = "google.com";
     let payload = DomainTransferPayload {
-        registrant_id: 2,
+        registrant_id: ContactId(2),
         auth_code: String::from(""),
         whois_privacy: None,
         trustee: None,
@@ -406,6 +407,63 @@ async fn test_renew_a_domain_to_early() {
     );
 }
 
+#[tokio::test]
+async fn test_restore_domain() {
+    let setup = setup_mock_for(
+        "/1010/registrar/domains/example.com/restores",
+        "restoreDomain/success",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+    let domain = "example.com";
+    let payload = DomainRestorePayload {
+        premium_price: None,
+    };
+
+    let response = client
+        .registrar()
+        .restore_domain(account_id, domain, payload)
+        .await
+        .unwrap();
+    let domain_restore = response.data.unwrap();
+
+    assert_eq!(201, response.status);
+    assert_eq!(43, domain_restore.id);
+    assert_eq!(214, domain_restore.domain_id);
+    assert_eq!("new", domain_restore.state);
+    assert_eq!("2024-02-14T14:40:42Z", domain_restore.created_at);
+    assert_eq!("2024-02-14T14:40:42Z", domain_restore.updated_at);
+}
+
+#[tokio::test]
+async fn test_get_domain_restore() {
+    let setup = setup_mock_for(
+        "/1010/registrar/domains/example.com/restores/43",
+        "getDomainRestore/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+    let domain = "example.com";
+    let domain_restore_id = 43;
+
+    let response = client
+        .registrar()
+        .get_domain_restore(account_id, domain, domain_restore_id)
+        .await
+        .unwrap();
+    let domain_restore = response.data.unwrap();
+
+    assert_eq!(domain_restore.id, 43);
+    assert_eq!(domain_restore.domain_id, 214);
+    assert_eq!(domain_restore.state, "new");
+    assert_eq!(domain_restore.created_at, "2024-02-14T14:40:42Z");
+    assert_eq!(domain_restore.updated_at, "2024-02-14T14:40:42Z");
+}
+
 #[tokio::test]
 async fn test_authorize_domain_transfer_out() {
     let setup = setup_mock_for(
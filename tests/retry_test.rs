@@ -0,0 +1,223 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::retry::RetryPolicy;
+use dnsimple::dnsimple::{Client, new_client};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Mock, Server, ServerGuard};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+mod common;
+
+async fn mock_success(server: &mut ServerGuard, method: &str, path: &str, fixture: &str) -> Mock {
+    server
+        .mock(method, format!("/v2{}", path).as_str())
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "2")
+        .with_header("X-RateLimit-Reset", "never")
+        .with_status(200)
+        .with_body(fixture_body(fixture))
+        .create_async()
+        .await
+}
+
+async fn mock_failure(
+    server: &mut ServerGuard,
+    method: &str,
+    path: &str,
+    status: usize,
+    rate_limit_reset: u64,
+) -> Mock {
+    server
+        .mock(method, format!("/v2{}", path).as_str())
+        .with_header("X-RateLimit-Limit", "2")
+        .with_header("X-RateLimit-Remaining", "0")
+        .with_header("X-RateLimit-Reset", &rate_limit_reset.to_string())
+        .with_status(status)
+        .create_async()
+        .await
+}
+
+fn client_for(server: &ServerGuard, policy: Option<RetryPolicy>) -> Client {
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    if let Some(policy) = policy {
+        client.set_retry_policy(policy);
+    }
+    client
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy {
+        initial_backoff: Duration::from_millis(1),
+        max_backoff: Duration::from_millis(5),
+        ..Default::default()
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+#[tokio::test]
+async fn does_not_retry_without_a_policy() {
+    let mut server = Server::new_async().await;
+    let failure = mock_failure(&mut server, "GET", "/whoami", 503, 0).await;
+    let success = mock_success(&mut server, "GET", "/whoami", "whoami/success")
+        .await
+        .expect(0);
+    let client = client_for(&server, None);
+
+    let error = client.identity().whoami().await.unwrap_err();
+
+    assert_matches!(error, DNSimpleError::ServiceUnavailable);
+    failure.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn retries_transient_errors() {
+    let mut server = Server::new_async().await;
+    let bad_gateway = mock_failure(&mut server, "GET", "/whoami", 502, 0).await;
+    let timeout = mock_failure(&mut server, "GET", "/whoami", 504, 0).await;
+    let success = mock_success(&mut server, "GET", "/whoami", "whoami/success").await;
+    let client = client_for(&server, Some(fast_policy()));
+
+    let response = client.identity().whoami().await.unwrap();
+
+    assert_eq!(200, response.status);
+    bad_gateway.assert_async().await;
+    timeout.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn gives_up_after_the_maximum_number_of_attempts() {
+    let mut server = Server::new_async().await;
+    let failure = mock_failure(&mut server, "GET", "/whoami", 503, 0)
+        .await
+        .expect(3);
+    let client = client_for(&server, Some(fast_policy()));
+
+    let error = client.identity().whoami().await.unwrap_err();
+
+    assert_matches!(error, DNSimpleError::ServiceUnavailable);
+    failure.assert_async().await;
+}
+
+#[tokio::test]
+async fn does_not_retry_non_idempotent_requests_by_default() {
+    let mut server = Server::new_async().await;
+    let failure = mock_failure(&mut server, "POST", "/1010/secondary_dns/zones", 503, 0).await;
+    let success = mock_success(
+        &mut server,
+        "POST",
+        "/1010/secondary_dns/zones",
+        "createSecondaryZone/created",
+    )
+    .await
+    .expect(0);
+    let client = client_for(&server, Some(fast_policy()));
+
+    let error = client
+        .secondary_dns()
+        .create_secondary_zone(1010, "secondaryexample.com")
+        .await
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::ServiceUnavailable);
+    failure.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn retries_non_idempotent_requests_when_enabled() {
+    let mut server = Server::new_async().await;
+    let failure = mock_failure(&mut server, "POST", "/1010/secondary_dns/zones", 503, 0).await;
+    let success = mock_success(
+        &mut server,
+        "POST",
+        "/1010/secondary_dns/zones",
+        "createSecondaryZone/created",
+    )
+    .await;
+    let policy = RetryPolicy {
+        retry_non_idempotent: true,
+        ..fast_policy()
+    };
+    let client = client_for(&server, Some(policy));
+
+    let zone = client
+        .secondary_dns()
+        .create_secondary_zone(1010, "secondaryexample.com")
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!("secondaryexample.com", zone.name);
+    failure.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn waits_for_the_rate_limit_reset() {
+    let mut server = Server::new_async().await;
+    let throttled = mock_failure(&mut server, "GET", "/whoami", 429, now() + 3).await;
+    let success = mock_success(&mut server, "GET", "/whoami", "whoami/success").await;
+    let policy = RetryPolicy {
+        // The backoff alone would not be enough to wait for the reset
+        initial_backoff: Duration::ZERO,
+        ..fast_policy()
+    };
+    let client = client_for(&server, Some(policy));
+    let started = SystemTime::now();
+
+    client.identity().whoami().await.unwrap();
+
+    assert!(started.elapsed().unwrap() >= Duration::from_secs(1));
+    throttled.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn waits_for_the_backoff_when_the_rate_limit_reset_is_past() {
+    let mut server = Server::new_async().await;
+    let throttled = mock_failure(&mut server, "GET", "/whoami", 429, now() - 10).await;
+    let success = mock_success(&mut server, "GET", "/whoami", "whoami/success").await;
+    let policy = RetryPolicy {
+        initial_backoff: Duration::from_millis(400),
+        max_backoff: Duration::from_secs(1),
+        ..Default::default()
+    };
+    let client = client_for(&server, Some(policy));
+    let started = SystemTime::now();
+
+    client.identity().whoami().await.unwrap();
+
+    // The jitter never makes the backoff shorter than half of it
+    assert!(started.elapsed().unwrap() >= Duration::from_millis(200));
+    throttled.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn does_not_wait_for_a_rate_limit_reset_too_far_away() {
+    let mut server = Server::new_async().await;
+    let throttled = mock_failure(&mut server, "GET", "/whoami", 429, now() + 600).await;
+    let success = mock_success(&mut server, "GET", "/whoami", "whoami/success")
+        .await
+        .expect(0);
+    let policy = RetryPolicy {
+        max_rate_limit_wait: Duration::from_secs(60),
+        ..fast_policy()
+    };
+    let client = client_for(&server, Some(policy));
+
+    let error = client.identity().whoami().await.unwrap_err();
+
+    assert_matches!(error, DNSimpleError::TooManyRequests);
+    throttled.assert_async().await;
+    success.assert_async().await;
+}
@@ -0,0 +1,198 @@
+use crate::common::setup_mock_for;
+use dnsimple::dnsimple::secondary_dns::PrimaryServerPayload;
+mod common;
+
+#[tokio::test]
+async fn list_primary_servers_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries",
+        "listPrimaryServers/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let response = client
+        .secondary_dns()
+        .list_primary_servers(account_id, None)
+        .await
+        .unwrap();
+    let primary_servers = response.data.unwrap();
+
+    assert_eq!(2, primary_servers.len());
+    assert_eq!(2, response.pagination.unwrap().total_entries);
+
+    let primary_server = primary_servers.first().unwrap();
+
+    assert_eq!(1, primary_server.id);
+    assert_eq!(531, primary_server.account_id);
+    assert_eq!("Primary", primary_server.name);
+    assert_eq!("1.1.1.1", primary_server.ip);
+    assert_eq!(4567, primary_server.port);
+    assert!(primary_server.linked_secondary_zones.is_empty());
+    assert_eq!("2021-03-05T18:02:23Z", primary_server.created_at);
+    assert_eq!("2021-03-05T18:02:23Z", primary_server.updated_at);
+
+    assert_eq!(
+        vec!["secondaryzone.com"],
+        primary_servers[1].linked_secondary_zones
+    );
+}
+
+#[tokio::test]
+async fn create_primary_server_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries",
+        "createPrimaryServer/created",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+    let payload = PrimaryServerPayload {
+        name: String::from("PrimaryProduction"),
+        ip: String::from("1.2.3.4"),
+        port: Some(53),
+    };
+
+    let response = client
+        .secondary_dns()
+        .create_primary_server(account_id, payload)
+        .await
+        .unwrap();
+    let primary_server = response.data.unwrap();
+
+    assert_eq!(201, response.status);
+    assert_eq!(4, primary_server.id);
+    assert_eq!(531, primary_server.account_id);
+    assert_eq!("PrimaryProduction", primary_server.name);
+    assert_eq!("1.2.3.4", primary_server.ip);
+    assert_eq!(53, primary_server.port);
+    assert!(primary_server.linked_secondary_zones.is_empty());
+    assert_eq!("2021-03-17T23:08:42Z", primary_server.created_at);
+    assert_eq!("2021-03-17T23:08:42Z", primary_server.updated_at);
+}
+
+#[tokio::test]
+async fn get_primary_server_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries/4",
+        "getPrimaryServer/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let primary_server = client
+        .secondary_dns()
+        .get_primary_server(account_id, 4)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(4, primary_server.id);
+    assert_eq!("PrimaryProduction", primary_server.name);
+    assert_eq!("1.2.3.4", primary_server.ip);
+    assert_eq!(53, primary_server.port);
+}
+
+#[tokio::test]
+async fn delete_primary_server_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries/4",
+        "deleteZoneRecord/success",
+        "DELETE",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let response = client
+        .secondary_dns()
+        .delete_primary_server(account_id, 4)
+        .await;
+
+    assert!(response.is_ok());
+    assert_eq!(204, response.unwrap().status);
+}
+
+#[tokio::test]
+async fn link_primary_server_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries/4/link",
+        "linkPrimaryServer/success",
+        "PUT",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let primary_server = client
+        .secondary_dns()
+        .link_primary_server(account_id, 4, "secondaryzone.com")
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(4, primary_server.id);
+    assert_eq!(
+        vec!["secondaryzone.com"],
+        primary_server.linked_secondary_zones
+    );
+}
+
+#[tokio::test]
+async fn unlink_primary_server_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/primaries/4/unlink",
+        "unlinkPrimaryServer/success",
+        "PUT",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let primary_server = client
+        .secondary_dns()
+        .unlink_primary_server(account_id, 4, "secondaryzone.com")
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(4, primary_server.id);
+    assert!(primary_server.linked_secondary_zones.is_empty());
+}
+
+#[tokio::test]
+async fn create_secondary_zone_test() {
+    let setup = setup_mock_for(
+        "/1010/secondary_dns/zones",
+        "createSecondaryZone/created",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+
+    let response = client
+        .secondary_dns()
+        .create_secondary_zone(account_id, "secondaryexample.com")
+        .await
+        .unwrap();
+    let zone = response.data.unwrap();
+
+    assert_eq!(201, response.status);
+    assert_eq!(734, zone.id);
+    assert_eq!(531, zone.account_id);
+    assert_eq!("secondaryexample.com", zone.name);
+    assert!(!zone.reverse);
+    assert!(zone.secondary);
+    assert_eq!(None, zone.last_transferred_at);
+    assert_eq!("2021-03-17T23:44:27Z", zone.created_at);
+    assert_eq!("2021-03-17T23:44:27Z", zone.updated_at);
+}
@@ -0,0 +1,138 @@
+#![cfg(feature = "tracing")]
+
+use crate::common::fixture_body;
+use dnsimple::dnsimple::new_client;
+use dnsimple::dnsimple::retry::RetryPolicy;
+use mockito::Server;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+mod common;
+
+type Fields = HashMap<String, String>;
+
+struct FieldsVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldsVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+}
+
+/// Records the fields of the spans (once closed) and of the events of this crate.
+#[derive(Clone, Default)]
+struct Recorder {
+    open: Arc<Mutex<HashMap<Id, Fields>>>,
+    spans: Arc<Mutex<Vec<Fields>>>,
+    events: Arc<Mutex<Vec<Fields>>>,
+}
+
+impl<S: Subscriber + for<'a> LookupSpan<'a>> Layer<S> for Recorder {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        if !attrs.metadata().target().starts_with("dnsimple") {
+            return;
+        }
+        let mut fields = Fields::new();
+        attrs.record(&mut FieldsVisitor(&mut fields));
+        self.open.lock().unwrap().insert(id.clone(), fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        if let Some(fields) = self.open.lock().unwrap().get_mut(id) {
+            values.record(&mut FieldsVisitor(fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        if !event.metadata().target().starts_with("dnsimple") {
+            return;
+        }
+        let mut fields = Fields::new();
+        event.record(&mut FieldsVisitor(&mut fields));
+        self.events.lock().unwrap().push(fields);
+    }
+
+    fn on_close(&self, id: Id, _ctx: Context<'_, S>) {
+        if let Some(fields) = self.open.lock().unwrap().remove(&id) {
+            self.spans.lock().unwrap().push(fields);
+        }
+    }
+}
+
+#[tokio::test]
+async fn records_a_span_for_every_request_test() {
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v2/1010/zones/example.com/records")
+        .with_header("X-RateLimit-Limit", "4000")
+        .with_header("X-RateLimit-Remaining", "3991")
+        .with_header("X-RateLimit-Reset", "1450451976")
+        .with_body(fixture_body("listZoneRecords/success"))
+        .create_async()
+        .await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+
+    client
+        .zones()
+        .list_zone_records(1010, "example.com", None)
+        .await
+        .unwrap();
+
+    let spans = recorder.spans.lock().unwrap();
+    assert_eq!(1, spans.len());
+    let span = &spans[0];
+    assert_eq!("GET", span["http.method"]);
+    assert_eq!("/{account}/zones/{zone}/records", span["http.path"]);
+    assert_eq!("200", span["http.status"]);
+    assert_eq!("3991", span["rate_limit.remaining"]);
+    assert_eq!("1", span["attempt"]);
+    assert!(span.contains_key("latency_ms"));
+    assert!(recorder.events.lock().unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn records_the_errors_as_events_test() {
+    let recorder = Recorder::default();
+    let _guard =
+        tracing::subscriber::set_default(tracing_subscriber::registry().with(recorder.clone()));
+
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/v2/whoami")
+        .with_status(503)
+        .create_async()
+        .await;
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    client.set_retry_policy(RetryPolicy {
+        max_attempts: 2,
+        initial_backoff: Duration::from_millis(1),
+        ..Default::default()
+    });
+
+    assert!(client.identity().whoami().await.is_err());
+
+    let spans = recorder.spans.lock().unwrap();
+    assert_eq!(1, spans.len());
+    assert_eq!("/whoami", spans[0]["http.path"]);
+    assert_eq!("503", spans[0]["http.status"]);
+    assert_eq!("2", spans[0]["attempt"]);
+
+    let events = recorder.events.lock().unwrap();
+    assert_eq!(2, events.len());
+    assert_eq!("503", events[0]["http.status"]);
+    assert_eq!("1", events[0]["attempt"]);
+    assert_eq!("2", events[1]["attempt"]);
+}
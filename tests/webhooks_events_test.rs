@@ -0,0 +1,223 @@
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::webhooks_events::{EventData, WebhookEvent};
+use dnsimple::errors::DNSimpleError;
+use std::fs;
+
+fn read_webhook_fixture(fixture: &str) -> String {
+    let fixture = format!("./tests/fixtures/v2/webhooks/{}.http", fixture);
+    let content =
+        fs::read_to_string(fixture.as_str()).expect("Something went wrong: Couldn't read the file");
+
+    content.trim_end().lines().last().unwrap().to_string()
+}
+
+fn parse_webhook_fixture(fixture: &str) -> WebhookEvent {
+    WebhookEvent::parse(&read_webhook_fixture(fixture)).unwrap()
+}
+
+#[test]
+fn parses_every_webhook_fixture_into_a_known_event() {
+    let fixtures = [
+        "account.billing_settings_update/example",
+        "account.sso_user_add/example",
+        "account.update/example",
+        "account.user_invitation_accept/example",
+        "account.user_invitation_revoke/example",
+        "account.user_invite/example",
+        "account.user_remove/example",
+        "certificate.issue/example",
+        "certificate.remove_private_key/example",
+        "contact.create/example",
+        "contact.delete/example",
+        "contact.update/example",
+        "dnssec.create/example",
+        "dnssec.delete/example",
+        "dnssec.rotation_complete/example",
+        "dnssec.rotation_start/example",
+        "domain.auto_renewal_disable/example",
+        "domain.auto_renewal_enable/example",
+        "domain.create/example",
+        "domain.delegation_change/example",
+        "domain.delete/example",
+        "domain.register/example",
+        "domain.register/status-cancelled",
+        "domain.register/status-started",
+        "domain.registrant_change/example",
+        "domain.registrant_change/status-cancelled",
+        "domain.registrant_change/status-started",
+        "domain.registrant_change_started/example",
+        "domain.renew/example",
+        "domain.renew/status-cancelled",
+        "domain.renew/status-started",
+        "domain.resolution_disable/example",
+        "domain.resolution_enable/example",
+        "domain.restore/example",
+        "domain.restore/status-cancelled",
+        "domain.restore/status-started",
+        "domain.transfer/example",
+        "domain.transfer/status-cancelled",
+        "domain.transfer/status-started",
+        "domain.transfer_lock_disable/example",
+        "domain.transfer_lock_enable/example",
+        "email_forward.activate/example",
+        "email_forward.create/example",
+        "email_forward.deactivate/example",
+        "email_forward.delete/example",
+        "email_forward.update/example",
+        "subscription.renew/example",
+        "subscription.renew/state-failed",
+        "webhook.create/example",
+        "webhook.delete/example",
+        "whois_privacy.disable/example",
+        "whois_privacy.enable/example",
+        "whois_privacy.purchase/example",
+        "whois_privacy.renew/example",
+        "zone.create/example",
+        "zone.delete/example",
+        "zone_record.create/example",
+        "zone_record.delete/example",
+        "zone_record.update/example",
+    ];
+
+    for fixture in fixtures {
+        let event = parse_webhook_fixture(fixture);
+
+        assert!(
+            !matches!(event.data, EventData::Unknown(_)),
+            "{} was parsed as an unknown event",
+            fixture
+        );
+        assert_eq!("v2", event.api_version);
+    }
+}
+
+#[test]
+fn parses_the_event_envelope() {
+    let event = parse_webhook_fixture("zone_record.create/example");
+
+    assert_eq!("zone_record.create", event.name);
+    assert_eq!("v2", event.api_version);
+    assert_eq!(
+        "42285223-dc74-4f03-89bf-171e3c0a17b4",
+        event.request_identifier
+    );
+    assert_eq!("1120", event.actor.id);
+    assert_eq!("user", event.actor.entity);
+    assert_eq!("hello@example.com", event.actor.pretty);
+    assert_eq!(123, event.account.id);
+    assert_eq!("Personal", event.account.display);
+    assert_eq!("foobar", event.account.identifier);
+}
+
+#[test]
+fn parses_a_zone_record_event() {
+    let event = parse_webhook_fixture("zone_record.create/example");
+
+    assert_matches!(event.data, EventData::ZoneRecordCreate(data) => {
+        let zone_record = data.zone_record;
+        assert_eq!(14762434, zone_record.id);
+        assert_eq!("example.zone", zone_record.zone_id);
+        assert_eq!("", zone_record.name);
+        assert_eq!("A", zone_record.record_type);
+        assert_eq!("127.0.0.1", zone_record.content);
+        assert_eq!(3600, zone_record.ttl);
+        assert!(!zone_record.system_record);
+    });
+}
+
+#[test]
+fn parses_a_domain_event_with_a_status() {
+    let event = parse_webhook_fixture("domain.register/status-started");
+
+    assert_eq!("domain.register:started", event.name);
+    assert_matches!(event.data, EventData::DomainRegisterStarted(data) => {
+        assert_eq!(181984, data.domain.id);
+        assert_eq!("example-alpha.com", data.domain.name);
+        assert_eq!("hosted", data.domain.state);
+    });
+}
+
+#[test]
+fn parses_a_domain_renewal_event() {
+    let event = parse_webhook_fixture("domain.renew/example");
+
+    assert_matches!(event.data, EventData::DomainRenew(data) => {
+        assert!(data.auto);
+        assert_eq!(181984, data.domain.id);
+    });
+
+    let event = parse_webhook_fixture("domain.renew/status-started");
+
+    assert_matches!(event.data, EventData::DomainRenewStarted(data) => {
+        assert!(!data.auto);
+    });
+}
+
+#[test]
+fn parses_a_domain_registrant_change_event() {
+    let event = parse_webhook_fixture("domain.registrant_change_started/example");
+
+    assert_matches!(event.data, EventData::DomainRegistrantChangeStarted(data) => {
+        assert_eq!(313083, data.domain.id);
+        assert_eq!(9927, data.registrant.id);
+        assert_eq!("jane.smith@example.com", data.registrant.email_address);
+        assert_eq!(None, data.registrant.label);
+    });
+}
+
+#[test]
+fn parses_a_certificate_event() {
+    let event = parse_webhook_fixture("certificate.issue/example");
+
+    assert_matches!(event.data, EventData::CertificateIssue(data) => {
+        let certificate = data.certificate;
+        assert_eq!(101967, certificate.id);
+        assert_eq!("www.bingo.pizza", certificate.common_name);
+        assert_eq!("issued", certificate.state);
+        assert_eq!("letsencrypt", certificate.authority_identifier);
+    });
+}
+
+#[test]
+fn parses_a_dnssec_rotation_event() {
+    let event = parse_webhook_fixture("dnssec.rotation_start/example");
+
+    assert_matches!(event.data, EventData::DnssecRotationStart(data) => {
+        assert_eq!("example-20230920163010.com", data.zone.name);
+        assert!(data.dnssec.enabled);
+        assert_eq!(None, data.delegation_signer_record.id);
+        assert_eq!("60812", data.delegation_signer_record.keytag);
+    });
+}
+
+#[test]
+fn parses_an_account_invitation_event() {
+    let event = parse_webhook_fixture("account.user_invite/example");
+
+    assert_matches!(event.data, EventData::AccountUserInvite(data) => {
+        assert_eq!(1111, data.account.id);
+        assert_eq!(3523, data.account_invitation.id);
+        assert_eq!(None, data.account_invitation.invitation_accepted_at);
+    });
+}
+
+#[test]
+fn unknown_events_fall_back_to_raw_json() {
+    let body = r#"{"data":{"thing":{"id":1}},"name":"thing.happen","actor":{"id":"1","entity":"user","pretty":"hello@example.com"},"account":{"id":1,"display":"Personal","identifier":"foobar"},"api_version":"v2","request_identifier":"abc"}"#;
+
+    let event = WebhookEvent::parse(body).unwrap();
+
+    assert_eq!("thing.happen", event.name);
+    assert_matches!(event.data, EventData::Unknown(data) => {
+        assert_eq!(1, data["thing"]["id"]);
+    });
+}
+
+#[test]
+fn known_events_with_invalid_data_fail_to_parse() {
+    let body = r#"{"data":{"zone":{"id":"nope"}},"name":"zone.create","actor":{"id":"1","entity":"user","pretty":"hello@example.com"},"account":{"id":1,"display":"Personal","identifier":"foobar"},"api_version":"v2","request_identifier":"abc"}"#;
+
+    let error = WebhookEvent::parse(body).unwrap_err();
+
+    assert_matches!(error, DNSimpleError::Deserialization(_));
+}
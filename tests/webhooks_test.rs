@@ -1,4 +1,5 @@
 use crate::common::setup_mock_for;
+use dnsimple::dnsimple::ids::WebhookId;
 mod common;
 
 #[tokio::test]
@@ -41,7 +42,7 @@ async fn get_webhook_test() {
     let setup = setup_mock_for("/1010/webhooks/1", "getWebhook/success", "GET").await;
     let client = setup.0;
     let account_id = 1010;
-    let webhook_id = String::from("1");
+    let webhook_id = WebhookId(1);
 
     let webhook = client
         .webhooks()
@@ -59,7 +60,7 @@ async fn delete_webhook_test() {
     let setup = setup_mock_for("/1010/webhooks/1", "deleteWebhook/success", "DELETE").await;
     let client = setup.0;
     let account_id = 1010;
-    let webhook_id = String::from("1");
+    let webhook_id = WebhookId(1);
 
     let response = client
         .webhooks()
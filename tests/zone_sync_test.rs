@@ -0,0 +1,206 @@
+use crate::common::setup_mock_for;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::ids::ZoneRecordId;
+use dnsimple::dnsimple::new_client;
+use dnsimple::dnsimple::zone_sync::{Plan, SyncOutcome, ZoneSync};
+use dnsimple::dnsimple::zones_records::{RecordType, ZoneRecord, ZoneRecordPayload};
+use dnsimple::errors::DNSimpleError;
+use serde_json::json;
+mod common;
+
+fn desired(
+    name: &str,
+    record_type: RecordType,
+    content: &str,
+    ttl: Option<u64>,
+) -> ZoneRecordPayload {
+    ZoneRecordPayload {
+        name: name.to_string(),
+        record_type,
+        content: content.to_string(),
+        ttl,
+        priority: None,
+        regions: None,
+    }
+}
+
+fn current(
+    id: u64,
+    name: &str,
+    record_type: &str,
+    content: &str,
+    ttl: u64,
+    system: bool,
+) -> ZoneRecord {
+    serde_json::from_value(json!({
+        "id": id,
+        "zone_id": "example.com",
+        "parent_id": null,
+        "name": name,
+        "content": content,
+        "ttl": ttl,
+        "priority": null,
+        "type": record_type,
+        "regions": ["global"],
+        "system_record": system,
+        "created_at": "2016-03-22T10:20:53Z",
+        "updated_at": "2016-03-22T10:20:53Z"
+    }))
+    .unwrap()
+}
+
+#[test]
+fn plan_creates_updates_and_deletes_test() {
+    let desired_records = vec![
+        desired("www", RecordType::A, "192.0.2.1", Some(3600)),
+        desired("WWW", RecordType::Aaaa, "2001:db8::1", None),
+        desired("api", RecordType::A, "192.0.2.3", Some(300)),
+        desired("new", RecordType::Cname, "www.example.com", None),
+    ];
+    let current_records = vec![
+        current(
+            1,
+            "",
+            "SOA",
+            "ns1.dnsimple.com admin.dnsimple.com 1 2 3 4 5",
+            3600,
+            true,
+        ),
+        current(2, "", "NS", "ns1.dnsimple.com", 3600, true),
+        current(3, "www", "A", "192.0.2.1", 3600, false),
+        current(4, "www", "AAAA", "2001:db8::1", 600, false),
+        current(5, "api", "A", "192.0.2.3", 3600, false),
+        current(6, "api", "A", "192.0.2.2", 3600, false),
+    ];
+
+    let plan = Plan::new(desired_records, current_records).unwrap();
+
+    assert_eq!(1, plan.creates.len());
+    assert_eq!("new", plan.creates[0].name);
+    assert_eq!(1, plan.updates.len());
+    assert_eq!(5, plan.updates[0].record.id);
+    assert_eq!(ZoneRecordId(5), plan.updates[0].changes.id);
+    assert_eq!(Some(300), plan.updates[0].changes.ttl);
+    assert_eq!(None, plan.updates[0].changes.content);
+    assert_eq!(1, plan.deletes.len());
+    assert_eq!(6, plan.deletes[0].id);
+
+    let payload = serde_json::to_value(plan.to_batch_payload()).unwrap();
+    assert_eq!(
+        json!({
+            "creates": [{"name": "new", "type": "CNAME", "content": "www.example.com", "ttl": null, "priority": null}],
+            "updates": [{"id": 5, "ttl": 300}],
+            "deletes": [{"id": 6}]
+        }),
+        payload
+    );
+}
+
+#[test]
+fn plan_rejects_duplicate_and_invalid_records_test() {
+    let duplicates = vec![
+        desired("www", RecordType::A, "192.0.2.1", None),
+        desired("www", RecordType::A, "192.0.2.1", Some(60)),
+    ];
+    let invalid = vec![desired("www", RecordType::A, "not-an-ip", None)];
+
+    assert_matches!(Plan::new(duplicates, vec![]), Err(DNSimpleError::Validation(message)) => {
+        assert!(message.starts_with("desired[1]: duplicate A record"), "{}", message);
+    });
+    assert_matches!(
+        Plan::new(invalid, vec![]),
+        Err(DNSimpleError::Validation(_))
+    );
+}
+
+#[tokio::test]
+async fn plan_ignores_the_system_records_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/records?page=1&per_page=100",
+        "listZoneRecords/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let plan = client
+        .zones()
+        .zone_sync(1010, "example.com")
+        .plan(vec![desired("www", RecordType::A, "192.0.2.1", None)])
+        .await
+        .unwrap();
+
+    assert_eq!(1, plan.creates.len());
+    assert!(plan.updates.is_empty());
+    assert!(plan.deletes.is_empty());
+}
+
+#[tokio::test]
+async fn apply_uses_the_batch_endpoint_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/batch",
+        "batchChangeZoneRecords/success",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let plan = Plan::new(
+        vec![desired("ab", RecordType::A, "3.2.3.4", None)],
+        vec![current(67622509, "old", "A", "192.0.2.1", 3600, false)],
+    )
+    .unwrap();
+
+    let outcome = ZoneSync::new(client, 1010, "example.com")
+        .max_deletions(1)
+        .apply(&plan)
+        .await
+        .unwrap();
+
+    assert_matches!(outcome, SyncOutcome::Applied(changes) => {
+        assert_eq!(67623409, changes.creates[0].id);
+    });
+}
+
+#[tokio::test]
+async fn apply_does_not_send_anything_in_dry_run_test() {
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url("http://127.0.0.1:1");
+    let sync = client.zones().zone_sync(1010, "example.com").dry_run(true);
+    let plan = Plan::new(
+        vec![desired("www", RecordType::A, "192.0.2.1", None)],
+        vec![],
+    )
+    .unwrap();
+
+    assert_matches!(sync.apply(&plan).await, Ok(SyncOutcome::DryRun));
+    assert_matches!(
+        sync.apply(&Plan::default()).await,
+        Ok(SyncOutcome::Unchanged)
+    );
+}
+
+#[tokio::test]
+async fn apply_refuses_too_many_deletions_test() {
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url("http://127.0.0.1:1");
+    let plan = Plan::new(
+        vec![],
+        vec![
+            current(1, "a", "A", "192.0.2.1", 3600, false),
+            current(2, "b", "A", "192.0.2.2", 3600, false),
+        ],
+    )
+    .unwrap();
+
+    let result = client
+        .zones()
+        .zone_sync(1010, "example.com")
+        .max_deletions(1)
+        .dry_run(true)
+        .apply(&plan)
+        .await;
+
+    assert_matches!(result, Err(DNSimpleError::Validation(message)) => {
+        assert_eq!("the plan deletes 2 records from example.com, more than the maximum of 1", message);
+    });
+}
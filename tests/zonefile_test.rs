@@ -0,0 +1,276 @@
+use crate::common::fixture_body;
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::zonefile::{self, ZoneFileRecord};
+use dnsimple::dnsimple::zones_records::{RecordType, ZoneRecord, ZoneRecordPayload};
+use dnsimple::errors::DNSimpleError;
+mod common;
+
+fn record(name: &str, ttl: u64, record_type: RecordType, content: &str) -> ZoneFileRecord {
+    ZoneFileRecord {
+        name: name.to_string(),
+        ttl: Some(ttl),
+        record_type,
+        content: content.to_string(),
+        priority: None,
+    }
+}
+
+fn zone_record(record: &ZoneFileRecord) -> ZoneRecord {
+    serde_json::from_value(serde_json::json!({
+        "id": 1,
+        "zone_id": "example.com",
+        "parent_id": null,
+        "name": record.name,
+        "content": record.content,
+        "ttl": record.ttl.unwrap(),
+        "priority": record.priority,
+        "type": record.record_type,
+        "regions": ["global"],
+        "system_record": false,
+        "created_at": "2016-03-22T10:20:53Z",
+        "updated_at": "2016-03-22T10:20:53Z"
+    }))
+    .unwrap()
+}
+
+const BIND_ZONE: &str = r#"
+$ORIGIN example.com.
+$TTL 1h
+@       IN  SOA ns1.example.com. hostmaster.example.com. (
+                2024010101 ; serial
+                86400      ; refresh
+                7200       ; retry
+                604800     ; expire
+                300 )      ; minimum
+        IN  NS  ns1
+        IN  NS  ns2.example.net.
+@   300 IN  MX  10 mail
+www         A   192.0.2.1 ; the website
+            AAAA 2001:db8::1
+ftp     600 CNAME www
+_sip._tcp   SRV 10 60 5060 sip
+@           TXT "v=spf1 include:_spf.example.com -all"
+dkim        TXT ( "v=DKIM1; k=rsa; "
+                  "p=MIGfMA0GCSqGSIb3" )
+@           CAA 0 issue "letsencrypt.org"
+$ORIGIN dev.example.com.
+api         A   192.0.2.2
+"#;
+
+#[test]
+fn parse_bind_zone_test() {
+    let records = zonefile::parse("example.com", BIND_ZONE).unwrap();
+
+    let mx = ZoneFileRecord {
+        priority: Some(10),
+        ..record("", 300, RecordType::Mx, "mail.example.com")
+    };
+    let srv = ZoneFileRecord {
+        priority: Some(10),
+        ..record(
+            "_sip._tcp",
+            3600,
+            RecordType::Srv,
+            "60 5060 sip.example.com",
+        )
+    };
+    assert_eq!(
+        vec![
+            record(
+                "",
+                3600,
+                RecordType::Soa,
+                "ns1.example.com hostmaster.example.com 2024010101 86400 7200 604800 300"
+            ),
+            record("", 3600, RecordType::Ns, "ns1.example.com"),
+            record("", 3600, RecordType::Ns, "ns2.example.net"),
+            mx,
+            record("www", 3600, RecordType::A, "192.0.2.1"),
+            record("www", 3600, RecordType::Aaaa, "2001:db8::1"),
+            record("ftp", 600, RecordType::Cname, "www.example.com"),
+            srv,
+            record(
+                "",
+                3600,
+                RecordType::Txt,
+                "v=spf1 include:_spf.example.com -all"
+            ),
+            record(
+                "dkim",
+                3600,
+                RecordType::Txt,
+                "\"v=DKIM1; k=rsa; \" \"p=MIGfMA0GCSqGSIb3\""
+            ),
+            record("", 3600, RecordType::Caa, "0 issue \"letsencrypt.org\""),
+            record("api.dev", 3600, RecordType::A, "192.0.2.2"),
+        ],
+        records
+    );
+}
+
+#[test]
+fn parse_exported_zone_file_test() {
+    let body: serde_json::Value =
+        serde_json::from_str(&fixture_body("getZoneFile/success")).unwrap();
+    let text = body["data"]["zone"].as_str().unwrap();
+
+    let records = zonefile::parse("example.com", text).unwrap();
+
+    assert_eq!(5, records.len());
+    assert_eq!(RecordType::Soa, records[0].record_type);
+    assert_eq!(
+        "ns1.dnsimple.com admin.dnsimple.com 1453132552 86400 7200 604800 300",
+        records[0].content
+    );
+    assert!(
+        records
+            .iter()
+            .all(|r| r.name.is_empty() && r.ttl == Some(3600))
+    );
+}
+
+#[test]
+fn parsed_records_convert_into_payloads_test() {
+    let records = zonefile::parse("example.com", "www 600 IN A 192.0.2.1\n").unwrap();
+
+    let payload: ZoneRecordPayload = records[0].clone().into();
+
+    assert_eq!("www", payload.name);
+    assert_eq!(RecordType::A, payload.record_type);
+    assert_eq!("192.0.2.1", payload.content);
+    assert_eq!(Some(600), payload.ttl);
+    assert_matches!(payload.validate(), Ok(()));
+}
+
+#[test]
+fn parse_escapes_test() {
+    let cases = [
+        (r#""a\059b""#, "a;b"),
+        (r#""\"quoted\" \\ and \;""#, r#""quoted" \ and ;"#),
+        (r#""caf\195\169""#, "café"),
+        (r#""\0651""#, "A1"),
+    ];
+
+    for (rdata, expected) in cases {
+        let records =
+            zonefile::parse("example.com", &format!("@ 3600 IN TXT {}\n", rdata)).unwrap();
+        assert_eq!(
+            vec![record("", 3600, RecordType::Txt, expected)],
+            records,
+            "{}",
+            rdata
+        );
+    }
+}
+
+#[test]
+fn parse_errors_test() {
+    let cases = [
+        ("  A 192.0.2.1\n", 1, "the first record has no owner"),
+        (
+            "www A 192.0.2.1\nwww.example.net. A 192.0.2.1\n",
+            2,
+            "outside of the zone",
+        ),
+        (
+            "\n$INCLUDE other.zone\n",
+            2,
+            "unsupported directive $INCLUDE",
+        ),
+        (
+            "@ SOA ns1 hostmaster ( 1 2 3\n",
+            1,
+            "unbalanced parenthesis",
+        ),
+        (
+            "@ MX mail\n",
+            1,
+            "MX record requires a preference and an exchange",
+        ),
+        ("@ TXT \"unterminated\n", 1, "unterminated quoted string"),
+        ("@ TXT \"a\\05b\"\n", 1, "invalid escape"),
+        ("\n@ TXT \"a\\256b\"\n", 2, "invalid escape"),
+        ("@ TXT \"a\\255b\"\n", 1, "not valid UTF-8"),
+        ("www\n", 1, "missing record type"),
+    ];
+
+    for (text, expected_line, expected_message) in cases {
+        let error = zonefile::parse("example.com", text).unwrap_err();
+        assert_matches!(error, DNSimpleError::ZoneFile { line, message } => {
+            assert_eq!(expected_line, line, "{}", text);
+            assert!(message.contains(expected_message), "{}: {}", text, message);
+        });
+    }
+}
+
+#[test]
+fn render_zone_records_test() {
+    let records: Vec<ZoneRecord> = serde_json::from_value(
+        serde_json::from_str::<serde_json::Value>(&fixture_body("listZoneRecords/success"))
+            .unwrap()["data"]
+            .clone(),
+    )
+    .unwrap();
+    let mut shuffled = records;
+    shuffled.reverse();
+
+    let file = zonefile::render("example.com", &shuffled);
+
+    assert_eq!(
+        "$ORIGIN example.com.\n\
+         $TTL 1h\n\
+         example.com. 3600 IN SOA ns1.dnsimple.com. admin.dnsimple.com. 1458642070 86400 7200 604800 300\n\
+         example.com. 3600 IN NS ns1.dnsimple.com.\n\
+         example.com. 3600 IN NS ns2.dnsimple.com.\n\
+         example.com. 3600 IN NS ns3.dnsimple.com.\n\
+         example.com. 3600 IN NS ns4.dnsimple.com.\n",
+        file
+    );
+}
+
+#[test]
+fn render_comments_out_the_dnsimple_only_records_test() {
+    let records: Vec<ZoneRecord> = [
+        record("", 3600, RecordType::Alias, "example.net"),
+        record("pool", 60, RecordType::Pool, "a.example.net"),
+        record("go", 3600, RecordType::Url, "https://example.net/"),
+        record("www", 3600, RecordType::A, "192.0.2.1"),
+    ]
+    .iter()
+    .map(zone_record)
+    .collect();
+
+    let file = zonefile::render("example.com", &records);
+
+    assert_eq!(
+        "$ORIGIN example.com.\n\
+         $TTL 1h\n\
+         ; example.com. 3600 IN ALIAS example.net.\n\
+         ; go.example.com. 3600 IN URL https://example.net/\n\
+         ; pool.example.com. 60 IN POOL a.example.net\n\
+         www.example.com. 3600 IN A 192.0.2.1\n",
+        file
+    );
+    assert_eq!(
+        vec![record("www", 3600, RecordType::A, "192.0.2.1")],
+        zonefile::parse("example.com", &file).unwrap()
+    );
+}
+
+#[test]
+fn render_then_parse_round_trips_test() {
+    let parsed = zonefile::parse("example.com", BIND_ZONE).unwrap();
+    let mut long_txt = record("long", 3600, RecordType::Txt, &"a".repeat(300));
+    long_txt.content.push_str(" \"quoted\"");
+    let mut records: Vec<ZoneRecord> = parsed.iter().map(zone_record).collect();
+    records.push(zone_record(&long_txt));
+
+    let file = zonefile::render("example.com", &records);
+    let reparsed = zonefile::parse("example.com", &file).unwrap();
+
+    assert!(file.contains(&format!("\"{}\" \"{}", "a".repeat(255), "a".repeat(45))));
+    assert_eq!(records.len(), reparsed.len());
+    for record in parsed {
+        assert!(reparsed.contains(&record), "{:?}", record);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::common::{setup_mock_for, setup_mock_with_query_for};
+use dnsimple::dnsimple::zones_analytics::{
+    DnsAnalyticsGrouping, DnsAnalyticsOptions, DnsAnalyticsSortField,
+};
+use dnsimple::dnsimple::{Paginate, RequestOptions};
+use mockito::Matcher;
+mod common;
+
+#[tokio::test]
+async fn dns_analytics_test() {
+    let setup = setup_mock_for("/1/dns_analytics", "dnsAnalytics/success", "GET").await;
+    let client = setup.0;
+    let account_id = 1;
+
+    let response = client
+        .zones()
+        .dns_analytics(account_id, None)
+        .await
+        .unwrap();
+    let analytics = response.data.unwrap();
+
+    assert_eq!(12, analytics.rows.len());
+
+    let row = analytics.rows.first().unwrap();
+
+    assert_eq!(Some("bar.com".to_string()), row.zone_name);
+    assert_eq!(Some("2023-12-08".to_string()), row.date);
+    assert_eq!(1200, row.volume);
+
+    let query = analytics.query.unwrap();
+
+    assert_eq!(1, query.account_id);
+    assert_eq!(Some("2023-12-08".to_string()), query.start_date);
+    assert_eq!(Some("2024-01-08".to_string()), query.end_date);
+    assert_eq!(Some("zone_name:asc,date:asc".to_string()), query.sort);
+    assert_eq!(0, query.page);
+    assert_eq!(100, query.per_page);
+    assert_eq!(Some("zone_name,date".to_string()), query.groupings);
+
+    let pagination = response.pagination.unwrap();
+
+    assert_eq!(0, pagination.current_page);
+    assert_eq!(100, pagination.per_page);
+    assert_eq!(93, pagination.total_entries);
+    assert_eq!(1, pagination.total_pages);
+}
+
+#[tokio::test]
+async fn dns_analytics_with_options_test() {
+    let setup = setup_mock_with_query_for(
+        "/1/dns_analytics",
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("groupings".into(), "zone_name,date".into()),
+            Matcher::UrlEncoded("start_date".into(), "2023-12-08".into()),
+            Matcher::UrlEncoded("end_date".into(), "2024-01-08".into()),
+            Matcher::UrlEncoded("sort".into(), "zone_name:asc,date:asc".into()),
+            Matcher::UrlEncoded("page".into(), "0".into()),
+            Matcher::UrlEncoded("per_page".into(), "100".into()),
+        ]),
+        "dnsAnalytics/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let options = DnsAnalyticsOptions {
+        groupings: vec![DnsAnalyticsGrouping::ZoneName, DnsAnalyticsGrouping::Date],
+        start_date: Some("2023-12-08".to_string()),
+        end_date: Some("2024-01-08".to_string()),
+        sort: vec![
+            DnsAnalyticsSortField::ZoneName.asc(),
+            DnsAnalyticsSortField::Date.asc(),
+        ],
+        paginate: Some(Paginate {
+            per_page: 100,
+            page: 0,
+        }),
+    };
+
+    let analytics = client
+        .zones()
+        .dns_analytics(1, Some(options))
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(12, analytics.rows.len());
+    assert_eq!(
+        Some("foo.com".to_string()),
+        analytics.rows.last().unwrap().zone_name
+    );
+}
+
+#[test]
+fn dns_analytics_options_without_filters_test() {
+    let options = RequestOptions::from(DnsAnalyticsOptions {
+        sort: vec![DnsAnalyticsSortField::Volume.desc()],
+        ..Default::default()
+    });
+
+    assert!(options.filters.is_none());
+    assert!(options.sort.is_some());
+}
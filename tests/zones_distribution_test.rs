@@ -0,0 +1,185 @@
+use crate::common::{fixture_mock, setup_mock_for};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::ids::ZoneRecordId;
+use dnsimple::dnsimple::zones_distribution::{DistributionOutcome, DistributionPolling};
+use dnsimple::dnsimple::{Client, new_client};
+use dnsimple::errors::DNSimpleError;
+use mockito::{Server, ServerGuard};
+use std::time::Duration;
+mod common;
+
+fn polling(timeout: Duration) -> DistributionPolling {
+    DistributionPolling {
+        interval: Duration::from_millis(1),
+        backoff: 2.0,
+        max_interval: Duration::from_millis(5),
+        timeout,
+    }
+}
+
+fn client_for(server: &ServerGuard) -> Client {
+    let mut client = new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url(&server.url());
+    client
+}
+
+#[tokio::test]
+async fn wait_for_zone_distribution_retries_until_distributed_test() {
+    let mut server = Server::new_async().await;
+    let path = "/1010/zones/example.com/distribution";
+    let failure = fixture_mock(&mut server, "GET", path, "checkZoneDistribution/failure")
+        .expect(1)
+        .create_async()
+        .await;
+    let error = fixture_mock(&mut server, "GET", path, "checkZoneDistribution/error")
+        .expect(1)
+        .create_async()
+        .await;
+    let success = fixture_mock(&mut server, "GET", path, "checkZoneDistribution/success")
+        .expect(1)
+        .create_async()
+        .await;
+    let client = client_for(&server);
+
+    let outcome = client
+        .zones()
+        .wait_for_zone_distribution(1010, "example.com", &polling(Duration::from_secs(5)))
+        .await;
+
+    assert_matches!(outcome, DistributionOutcome::Distributed { checks, .. } => {
+        assert_eq!(3, checks);
+    });
+    failure.assert_async().await;
+    error.assert_async().await;
+    success.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_zone_distribution_times_out_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/distribution",
+        "checkZoneDistribution/failure",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let outcome = client
+        .zones()
+        .wait_for_zone_distribution(1010, "example.com", &polling(Duration::from_millis(20)))
+        .await;
+
+    assert_matches!(outcome, DistributionOutcome::TimedOut { pending, elapsed } => {
+        assert!(pending.is_empty());
+        assert!(elapsed >= Duration::from_millis(20));
+    });
+}
+
+#[tokio::test]
+async fn wait_for_zone_distribution_stops_on_errors_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/distribution",
+        "notfound-zone",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+
+    let outcome = client
+        .zones()
+        .wait_for_zone_distribution(1010, "example.com", &polling(Duration::from_secs(5)))
+        .await;
+
+    assert_matches!(
+        outcome,
+        DistributionOutcome::Failed(DNSimpleError::NotFound(_))
+    );
+}
+
+#[tokio::test]
+async fn wait_for_record_distribution_only_checks_the_pending_records_test() {
+    let mut server = Server::new_async().await;
+    let first = fixture_mock(
+        &mut server,
+        "GET",
+        "/1010/zones/example.com/records/1/distribution",
+        "checkZoneRecordDistribution/success",
+    )
+    .expect(1)
+    .create_async()
+    .await;
+    let second_failure = fixture_mock(
+        &mut server,
+        "GET",
+        "/1010/zones/example.com/records/2/distribution",
+        "checkZoneRecordDistribution/error",
+    )
+    .expect(1)
+    .create_async()
+    .await;
+    let second_success = fixture_mock(
+        &mut server,
+        "GET",
+        "/1010/zones/example.com/records/2/distribution",
+        "checkZoneRecordDistribution/success",
+    )
+    .expect(1)
+    .create_async()
+    .await;
+    let client = client_for(&server);
+
+    let outcome = client
+        .zones()
+        .wait_for_record_distribution(
+            1010,
+            "example.com",
+            [ZoneRecordId(2), ZoneRecordId(1), ZoneRecordId(2)],
+            &polling(Duration::from_secs(5)),
+        )
+        .await;
+
+    assert_matches!(outcome, DistributionOutcome::Distributed { checks, .. } => {
+        assert_eq!(2, checks);
+    });
+    first.assert_async().await;
+    second_failure.assert_async().await;
+    second_success.assert_async().await;
+}
+
+#[tokio::test]
+async fn wait_for_record_distribution_reports_the_pending_records_test() {
+    let mut server = Server::new_async().await;
+    fixture_mock(
+        &mut server,
+        "GET",
+        "/1010/zones/example.com/records/1/distribution",
+        "checkZoneRecordDistribution/success",
+    )
+    .expect(1)
+    .create_async()
+    .await;
+    fixture_mock(
+        &mut server,
+        "GET",
+        "/1010/zones/example.com/records/2/distribution",
+        "checkZoneRecordDistribution/failure",
+    )
+    .expect_at_least(1)
+    .create_async()
+    .await;
+    let client = client_for(&server);
+
+    let outcome = client
+        .zones()
+        .wait_for_record_distribution(
+            1010,
+            "example.com",
+            vec![1, 2],
+            &polling(Duration::from_millis(20)),
+        )
+        .await;
+
+    assert_matches!(outcome, DistributionOutcome::TimedOut { pending, .. } => {
+        assert_eq!(vec![ZoneRecordId(2)], pending);
+    });
+}
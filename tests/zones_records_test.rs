@@ -1,5 +1,13 @@
-use crate::common::setup_mock_for;
-use dnsimple::dnsimple::zones_records::{ZoneRecordPayload, ZoneRecordUpdatePayload};
+use crate::common::{setup_mock_for, setup_mock_with_query_for};
+use assert_matches::assert_matches;
+use dnsimple::dnsimple::ids::ZoneRecordId;
+use dnsimple::dnsimple::zones_records::{
+    RecordType, ZoneRecordBatchDeletePayload, ZoneRecordBatchPayload, ZoneRecordBatchUpdatePayload,
+    ZoneRecordListOptions, ZoneRecordPayload, ZoneRecordSort, ZoneRecordUpdatePayload,
+};
+use dnsimple::errors::DNSimpleError;
+use mockito::Matcher;
+use serde_json::json;
 mod common;
 
 #[tokio::test]
@@ -60,7 +68,7 @@ async fn create_zone_record_test() {
     let zone = "example.com";
     let payload = ZoneRecordPayload {
         name: "www".to_string(),
-        record_type: "A".to_string(),
+        record_type: RecordType::A,
         content: "127.0.0.1".to_string(),
         ttl: None,
         priority: None,
@@ -103,7 +111,7 @@ async fn create_apex_zone_record_test() {
     let zone = "example.com";
     let payload = ZoneRecordPayload {
         name: "".to_string(),
-        record_type: "A".to_string(),
+        record_type: RecordType::A,
         content: "127.0.0.1".to_string(),
         ttl: None,
         priority: None,
@@ -310,3 +318,355 @@ async fn check_zone_record_distribution_error() {
         errors.to_string()
     );
 }
+
+#[tokio::test]
+async fn batch_change_zone_records_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/batch",
+        "batchChangeZoneRecords/success",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+    let zone = "example.com";
+    let payload = ZoneRecordBatchPayload {
+        creates: Some(vec![
+            ZoneRecordPayload {
+                name: "ab".to_string(),
+                record_type: RecordType::A,
+                content: "3.2.3.4".to_string(),
+                ttl: None,
+                priority: None,
+                regions: None,
+            },
+            ZoneRecordPayload {
+                name: "ab".to_string(),
+                record_type: RecordType::A,
+                content: "4.2.3.4".to_string(),
+                ttl: None,
+                priority: None,
+                regions: None,
+            },
+        ]),
+        updates: Some(vec![
+            ZoneRecordBatchUpdatePayload {
+                id: ZoneRecordId(67622534),
+                name: None,
+                content: Some("3.2.3.40".to_string()),
+                ttl: None,
+                priority: None,
+                regions: None,
+            },
+            ZoneRecordBatchUpdatePayload {
+                id: ZoneRecordId(67622537),
+                name: None,
+                content: Some("5.2.3.40".to_string()),
+                ttl: None,
+                priority: None,
+                regions: None,
+            },
+        ]),
+        deletes: Some(vec![
+            ZoneRecordBatchDeletePayload {
+                id: ZoneRecordId(67622509),
+            },
+            ZoneRecordBatchDeletePayload {
+                id: ZoneRecordId(67622527),
+            },
+        ]),
+    };
+
+    let changes = client
+        .zones()
+        .batch_change_zone_records(account_id, zone, payload)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(2, changes.creates.len());
+    let created = changes.creates.first().unwrap();
+    assert_eq!(67623409, created.id);
+    assert_eq!("example.com", created.zone_id);
+    assert_eq!("ab", created.name);
+    assert_eq!("3.2.3.4", created.content);
+    assert_eq!(3600, created.ttl);
+    assert_eq!("A", created.record_type);
+    assert_eq!(67623410, changes.creates[1].id);
+
+    assert_eq!(2, changes.updates.len());
+    let updated = changes.updates.first().unwrap();
+    assert_eq!(67622534, updated.id);
+    assert_eq!("update1-1757049890", updated.name);
+    assert_eq!("3.2.3.40", updated.content);
+    assert_eq!(67622537, changes.updates[1].id);
+    assert_eq!("5.2.3.40", changes.updates[1].content);
+
+    assert_eq!(2, changes.deletes.len());
+    assert_eq!(67622509, changes.deletes[0].id);
+    assert_eq!(67622527, changes.deletes[1].id);
+}
+
+#[tokio::test]
+async fn batch_change_zone_records_create_validation_failed_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/batch",
+        "batchChangeZoneRecords/error_400_create_validation_failed",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let payload = ZoneRecordBatchPayload {
+        creates: Some(vec![ZoneRecordPayload {
+            name: "test".to_string(),
+            record_type: RecordType::Spf,
+            content: "v=spf1 -all".to_string(),
+            ttl: None,
+            priority: None,
+            regions: None,
+        }]),
+        ..Default::default()
+    };
+
+    let error = client
+        .zones()
+        .batch_change_zone_records(1010, "example.com", payload)
+        .await
+        .unwrap_err();
+
+    assert_eq!("Validation failed", error.to_string());
+    assert_matches!(error, DNSimpleError::BadRequest { attribute_errors, .. } => {
+        assert_eq!(
+            json!({"creates":[{"index":0,"message":"Validation failed","errors":{"record_type":["unsupported"]}}]}),
+            attribute_errors.unwrap()
+        );
+    });
+}
+
+#[tokio::test]
+async fn batch_change_zone_records_update_validation_failed_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/batch",
+        "batchChangeZoneRecords/error_400_update_validation_failed",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let payload = ZoneRecordBatchPayload {
+        updates: Some(vec![ZoneRecordBatchUpdatePayload {
+            id: ZoneRecordId(99999999),
+            name: None,
+            content: Some("1.2.3.4".to_string()),
+            ttl: None,
+            priority: None,
+            regions: None,
+        }]),
+        ..Default::default()
+    };
+
+    let error = client
+        .zones()
+        .batch_change_zone_records(1010, "example.com", payload)
+        .await
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::BadRequest { attribute_errors, .. } => {
+        assert_eq!(
+            json!({"updates":[{"index":0,"message":"Record not found ID=99999999"}]}),
+            attribute_errors.unwrap()
+        );
+    });
+}
+
+#[tokio::test]
+async fn batch_change_zone_records_delete_validation_failed_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/batch",
+        "batchChangeZoneRecords/error_400_delete_validation_failed",
+        "POST",
+    )
+    .await;
+    let client = setup.0;
+    let payload = ZoneRecordBatchPayload {
+        deletes: Some(vec![ZoneRecordBatchDeletePayload {
+            id: ZoneRecordId(67622509),
+        }]),
+        ..Default::default()
+    };
+
+    let error = client
+        .zones()
+        .batch_change_zone_records(1010, "example.com", payload)
+        .await
+        .unwrap_err();
+
+    assert_matches!(error, DNSimpleError::BadRequest { attribute_errors, .. } => {
+        assert_eq!(
+            json!({"deletes":[{"index":0,"message":"Record not found ID=67622509"}]}),
+            attribute_errors.unwrap()
+        );
+    });
+}
+
+fn payload(record_type: RecordType, content: &str, priority: Option<u64>) -> ZoneRecordPayload {
+    ZoneRecordPayload {
+        name: "www".to_string(),
+        record_type,
+        content: content.to_string(),
+        ttl: None,
+        priority,
+        regions: None,
+    }
+}
+
+#[test]
+fn record_type_deserializes_unknown_types_test() {
+    let types: Vec<RecordType> =
+        serde_json::from_value(json!(["A", "ALIAS", "URL", "POOL", "LOC"])).unwrap();
+
+    assert_eq!(
+        vec![
+            RecordType::A,
+            RecordType::Alias,
+            RecordType::Url,
+            RecordType::Pool,
+            RecordType::Other("LOC".to_string())
+        ],
+        types
+    );
+    assert_eq!(
+        json!("AAAA"),
+        serde_json::to_value(RecordType::Aaaa).unwrap()
+    );
+}
+
+#[test]
+fn validate_accepts_valid_records_test() {
+    let payloads = [
+        payload(RecordType::A, "127.0.0.1", None),
+        payload(RecordType::Aaaa, "2001:db8::1", None),
+        payload(RecordType::Mx, "mx.example.com", Some(10)),
+        payload(RecordType::Srv, "10 5060 sip.example.com", Some(0)),
+        payload(RecordType::Caa, "0 issue \"letsencrypt.org\"", None),
+        payload(
+            RecordType::Caa,
+            "128 iodef mailto:security@example.com",
+            None,
+        ),
+        payload(RecordType::Txt, &"a".repeat(300), None),
+        payload(
+            RecordType::Txt,
+            &format!("\"{}\" \"{}\"", "a".repeat(255), "b".repeat(255)),
+            None,
+        ),
+        payload(
+            RecordType::Txt,
+            &format!("\"{}\\059\\\"\"", "a".repeat(253)),
+            None,
+        ),
+        payload(RecordType::Other("LOC".to_string()), "anything", None),
+    ];
+
+    for payload in payloads {
+        assert_matches!(payload.validate(), Ok(()), "{:?}", payload);
+    }
+}
+
+#[test]
+fn validate_rejects_invalid_records_test() {
+    let payloads = [
+        payload(RecordType::A, "2001:db8::1", None),
+        payload(RecordType::A, "example.com", None),
+        payload(RecordType::Aaaa, "127.0.0.1", None),
+        payload(RecordType::Mx, "mx.example.com", None),
+        payload(RecordType::Srv, "10 5060 sip.example.com", None),
+        payload(RecordType::Caa, "issue letsencrypt.org", None),
+        payload(RecordType::Caa, "256 issue \"letsencrypt.org\"", None),
+        payload(RecordType::Caa, "0 is-sue \"letsencrypt.org\"", None),
+        payload(RecordType::Caa, "0 issue \"letsencrypt.org", None),
+        payload(RecordType::Txt, &format!("\"{}\"", "a".repeat(256)), None),
+        payload(
+            RecordType::Txt,
+            &format!("\"{}\\059\"", "a".repeat(255)),
+            None,
+        ),
+    ];
+
+    for payload in payloads {
+        assert_matches!(
+            payload.validate(),
+            Err(DNSimpleError::Validation(_)),
+            "{:?}",
+            payload
+        );
+    }
+}
+
+#[tokio::test]
+async fn create_zone_record_validates_before_sending_test() {
+    let mut client = dnsimple::dnsimple::new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url("http://127.0.0.1:1");
+
+    let error = client
+        .zones()
+        .create_zone_record(1010, "example.com", payload(RecordType::A, "::1", None))
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        "Validation failed: A record `www`: `::1` is not an IPv4 address",
+        error.to_string()
+    );
+}
+
+#[tokio::test]
+async fn batch_change_zone_records_validates_the_creates_test() {
+    let mut client = dnsimple::dnsimple::new_client(true, String::from("some-token")).unwrap();
+    client.set_base_url("http://127.0.0.1:1");
+    let payload = ZoneRecordBatchPayload {
+        creates: Some(vec![
+            payload(RecordType::A, "127.0.0.1", None),
+            payload(RecordType::Mx, "mx.example.com", None),
+        ]),
+        ..Default::default()
+    };
+
+    let error = client
+        .zones()
+        .batch_change_zone_records(1010, "example.com", payload)
+        .await
+        .unwrap_err();
+
+    assert_eq!(
+        "Validation failed: creates[1]: MX record `www`: a priority is required",
+        error.to_string()
+    );
+}
+
+#[tokio::test]
+async fn list_zone_records_with_options_test() {
+    let setup = setup_mock_with_query_for(
+        "/1010/zones/example.com/records",
+        Matcher::AllOf(vec![
+            Matcher::UrlEncoded("name_like".into(), "www".into()),
+            Matcher::UrlEncoded("type".into(), "A".into()),
+            Matcher::UrlEncoded("sort".into(), "name:asc".into()),
+        ]),
+        "listZoneRecords/success",
+        "GET",
+    )
+    .await;
+    let client = setup.0;
+    let options = ZoneRecordListOptions::new()
+        .name_like("www")
+        .record_type(RecordType::A)
+        .sort(ZoneRecordSort::Name.asc());
+
+    let response = client
+        .zones()
+        .list_zone_records(1010, "example.com", options.into())
+        .await;
+
+    assert_matches!(response, Ok(_));
+}
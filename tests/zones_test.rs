@@ -1,4 +1,6 @@
 use crate::common::setup_mock_for;
+use dnsimple::dnsimple::ids::NameServerSetId;
+use dnsimple::dnsimple::zones::ZoneNsRecordsPayload;
 mod common;
 
 #[tokio::test]
@@ -201,3 +203,44 @@ async fn check_zone_distribution_error() {
         response.unwrap_err().to_string()
     );
 }
+
+#[tokio::test]
+async fn update_zone_ns_records_test() {
+    let setup = setup_mock_for(
+        "/1010/zones/example.com/ns_records",
+        "updateZoneNsRecords/success",
+        "PUT",
+    )
+    .await;
+    let client = setup.0;
+    let account_id = 1010;
+    let zone = "example.com";
+    let payload = ZoneNsRecordsPayload {
+        ns_names: Some(vec![
+            "ns1.example.com".to_string(),
+            "ns2.example.com".to_string(),
+        ]),
+        ns_set_ids: Some(vec![NameServerSetId(1), NameServerSetId(2)]),
+    };
+
+    let ns_records = client
+        .zones()
+        .update_zone_ns_records(account_id, zone, payload)
+        .await
+        .unwrap()
+        .data
+        .unwrap();
+
+    assert_eq!(4, ns_records.len());
+
+    let ns_record = ns_records.first().unwrap();
+
+    assert_eq!(1927, ns_record.id);
+    assert_eq!("example.com", ns_record.zone_id);
+    assert_eq!("", ns_record.name);
+    assert_eq!("ns1.example.com", ns_record.content);
+    assert_eq!(3600, ns_record.ttl);
+    assert_eq!("NS", ns_record.record_type);
+    assert!(ns_record.system_record);
+    assert_eq!("ns2.foo.bar", ns_records.last().unwrap().content);
+}